@@ -4,32 +4,75 @@ use core::fmt::Error as FmtError;
 use core::fmt::Formatter;
 use core::fmt::Result as FmtResult;
 use did_url::DID;
+use serde::de::DeserializeOwned;
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
+use serde::Serializer;
+use serde_json::from_value;
 use serde_json::to_string;
 use serde_json::to_string_pretty;
+use serde_json::to_value;
+use serde_json::value::Serializer as ValueSerializer;
 use url::Url;
 
+use crate::document::resolve_relative;
+use crate::document::restore_relative;
 use crate::document::DocumentBuilder;
+use crate::document::DocumentDiff;
+use crate::document::DocumentUpdate;
+use crate::document::RelativeDocument;
+use crate::document::RelativeIds;
+use crate::document::RemovalMode;
+use crate::document::RotationReport;
 use crate::error::Error;
 use crate::error::Result;
 use crate::lib::*;
 use crate::service::Service;
 use crate::signature::SignatureOptions;
 use crate::utils::DIDKey;
+use crate::utils::IndexedSet;
+use crate::utils::JsonPatch;
 use crate::utils::Object;
-use crate::utils::OrderedSet;
+use crate::utils::Value;
+use crate::verifiable::ResolveDocument;
 use crate::verifiable::ResolveMethod;
 use crate::verification::Method;
+use crate::verification::MethodIndex;
 use crate::verification::MethodQuery;
 use crate::verification::MethodRef;
 use crate::verification::MethodScope;
 use crate::verification::MethodWrap;
+use crate::verification::ScopedMethod;
 
-const ERR_VMNF: &str = "Verification Method Not Found";
 const ERR_MI: &str = "Missing `id`";
+const ERR_ID: &str = "Invalid Document";
+const ERR_IDI: &str = "Invalid Document Id";
+const ERR_IDC: &str = "Invalid Document Controller";
+const ERR_IRM: &str = "Invalid Revoked Methods";
+const ERR_MSF: &str = "Method Serialization Failed";
+const REVOKED: &str = "revoked";
 
-/// A DID Document Service
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+const SCOPES: [MethodScope; 6] = [
+  MethodScope::VerificationMethod,
+  MethodScope::Authentication,
+  MethodScope::AssertionMethod,
+  MethodScope::KeyAgreement,
+  MethodScope::CapabilityDelegation,
+  MethodScope::CapabilityInvocation,
+];
+
+/// A DID Document.
+///
+/// Method, reference, and service ids are deserialized relative to the
+/// `Document` id, and methods without a controller are controlled by the
+/// `Document`; see `Document::as_relative` for the reverse. A deserialized
+/// `Document` keeps the original form of its ids and is serialized in that
+/// form, so proofs over relative ids can be verified.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 #[rustfmt::skip]
 pub struct Document<T = Object, U = Object, V = Object> {
   pub(crate) id: DID,
@@ -37,22 +80,24 @@ pub struct Document<T = Object, U = Object, V = Object> {
   pub(crate) controller: Option<DID>,
   #[serde(default = "Default::default", rename = "alsoKnownAs", skip_serializing_if = "Vec::is_empty")]
   pub(crate) also_known_as: Vec<Url>,
-  #[serde(default = "Default::default", rename = "verificationMethod", skip_serializing_if = "OrderedSet::is_empty")]
-  pub(crate) verification_method: OrderedSet<DIDKey<Method<U>>>,
-  #[serde(default = "Default::default", skip_serializing_if = "OrderedSet::is_empty")]
-  pub(crate) authentication: OrderedSet<DIDKey<MethodRef<U>>>,
-  #[serde(default = "Default::default", rename = "assertionMethod", skip_serializing_if = "OrderedSet::is_empty")]
-  pub(crate) assertion_method: OrderedSet<DIDKey<MethodRef<U>>>,
-  #[serde(default = "Default::default", rename = "keyAgreement", skip_serializing_if = "OrderedSet::is_empty")]
-  pub(crate) key_agreement: OrderedSet<DIDKey<MethodRef<U>>>,
-  #[serde(default = "Default::default", rename = "capabilityDelegation", skip_serializing_if = "OrderedSet::is_empty")]
-  pub(crate) capability_delegation: OrderedSet<DIDKey<MethodRef<U>>>,
-  #[serde(default = "Default::default", rename = "capabilityInvocation", skip_serializing_if = "OrderedSet::is_empty")]
-  pub(crate) capability_invocation: OrderedSet<DIDKey<MethodRef<U>>>,
-  #[serde(default = "Default::default", skip_serializing_if = "OrderedSet::is_empty")]
-  pub(crate) service: OrderedSet<DIDKey<Service<V>>>,
+  #[serde(default = "Default::default", rename = "verificationMethod", skip_serializing_if = "IndexedSet::is_empty")]
+  pub(crate) verification_method: IndexedSet<Method<U>>,
+  #[serde(default = "Default::default", skip_serializing_if = "IndexedSet::is_empty")]
+  pub(crate) authentication: IndexedSet<MethodRef<U>>,
+  #[serde(default = "Default::default", rename = "assertionMethod", skip_serializing_if = "IndexedSet::is_empty")]
+  pub(crate) assertion_method: IndexedSet<MethodRef<U>>,
+  #[serde(default = "Default::default", rename = "keyAgreement", skip_serializing_if = "IndexedSet::is_empty")]
+  pub(crate) key_agreement: IndexedSet<MethodRef<U>>,
+  #[serde(default = "Default::default", rename = "capabilityDelegation", skip_serializing_if = "IndexedSet::is_empty")]
+  pub(crate) capability_delegation: IndexedSet<MethodRef<U>>,
+  #[serde(default = "Default::default", rename = "capabilityInvocation", skip_serializing_if = "IndexedSet::is_empty")]
+  pub(crate) capability_invocation: IndexedSet<MethodRef<U>>,
+  #[serde(default = "Default::default", skip_serializing_if = "IndexedSet::is_empty")]
+  pub(crate) service: IndexedSet<Service<V>>,
   #[serde(flatten)]
   pub(crate) properties: T,
+  #[serde(skip)]
+  pub(crate) relative: RelativeIds,
 }
 
 impl<T, U, V> Document<T, U, V> {
@@ -82,6 +127,7 @@ impl<T, U, V> Document<T, U, V> {
       capability_invocation: builder.capability_invocation.try_into()?,
       service: builder.service.try_into()?,
       properties: builder.properties,
+      relative: RelativeIds::default(),
     })
   }
 
@@ -116,72 +162,72 @@ impl<T, U, V> Document<T, U, V> {
   }
 
   /// Returns a reference to the `Document` verificationMethod set.
-  pub fn verification_method(&self) -> &OrderedSet<DIDKey<Method<U>>> {
+  pub fn verification_method(&self) -> &IndexedSet<Method<U>> {
     &self.verification_method
   }
 
   /// Returns a mutable reference to the `Document` verificationMethod set.
-  pub fn verification_method_mut(&mut self) -> &mut OrderedSet<DIDKey<Method<U>>> {
+  pub fn verification_method_mut(&mut self) -> &mut IndexedSet<Method<U>> {
     &mut self.verification_method
   }
 
   /// Returns a reference to the `Document` authentication set.
-  pub fn authentication(&self) -> &OrderedSet<DIDKey<MethodRef<U>>> {
+  pub fn authentication(&self) -> &IndexedSet<MethodRef<U>> {
     &self.authentication
   }
 
   /// Returns a mutable reference to the `Document` authentication set.
-  pub fn authentication_mut(&mut self) -> &mut OrderedSet<DIDKey<MethodRef<U>>> {
+  pub fn authentication_mut(&mut self) -> &mut IndexedSet<MethodRef<U>> {
     &mut self.authentication
   }
 
   /// Returns a reference to the `Document` assertionMethod set.
-  pub fn assertion_method(&self) -> &OrderedSet<DIDKey<MethodRef<U>>> {
+  pub fn assertion_method(&self) -> &IndexedSet<MethodRef<U>> {
     &self.assertion_method
   }
 
   /// Returns a mutable reference to the `Document` assertionMethod set.
-  pub fn assertion_method_mut(&mut self) -> &mut OrderedSet<DIDKey<MethodRef<U>>> {
+  pub fn assertion_method_mut(&mut self) -> &mut IndexedSet<MethodRef<U>> {
     &mut self.assertion_method
   }
 
   /// Returns a reference to the `Document` keyAgreement set.
-  pub fn key_agreement(&self) -> &OrderedSet<DIDKey<MethodRef<U>>> {
+  pub fn key_agreement(&self) -> &IndexedSet<MethodRef<U>> {
     &self.key_agreement
   }
 
   /// Returns a mutable reference to the `Document` keyAgreement set.
-  pub fn key_agreement_mut(&mut self) -> &mut OrderedSet<DIDKey<MethodRef<U>>> {
+  pub fn key_agreement_mut(&mut self) -> &mut IndexedSet<MethodRef<U>> {
     &mut self.key_agreement
   }
 
   /// Returns a reference to the `Document` capabilityDelegation set.
-  pub fn capability_delegation(&self) -> &OrderedSet<DIDKey<MethodRef<U>>> {
+  pub fn capability_delegation(&self) -> &IndexedSet<MethodRef<U>> {
     &self.capability_delegation
   }
 
   /// Returns a mutable reference to the `Document` capabilityDelegation set.
-  pub fn capability_delegation_mut(&mut self) -> &mut OrderedSet<DIDKey<MethodRef<U>>> {
+  pub fn capability_delegation_mut(&mut self) -> &mut IndexedSet<MethodRef<U>> {
     &mut self.capability_delegation
   }
 
   /// Returns a reference to the `Document` capabilityInvocation set.
-  pub fn capability_invocation(&self) -> &OrderedSet<DIDKey<MethodRef<U>>> {
+  pub fn capability_invocation(&self) -> &IndexedSet<MethodRef<U>> {
     &self.capability_invocation
   }
 
   /// Returns a mutable reference to the `Document` capabilityInvocation set.
-  pub fn capability_invocation_mut(&mut self) -> &mut OrderedSet<DIDKey<MethodRef<U>>> {
+  pub fn capability_invocation_mut(&mut self) -> &mut IndexedSet<MethodRef<U>> {
     &mut self.capability_invocation
   }
 
   /// Returns a reference to the `Document` service set.
-  pub fn service(&self) -> &OrderedSet<DIDKey<Service<V>>> {
+  pub fn service(&self) -> &IndexedSet<Service<V>> {
     &self.service
   }
 
   /// Returns a mutable reference to the `Document` service set.
-  pub fn service_mut(&mut self) -> &mut OrderedSet<DIDKey<Service<V>>> {
+  pub fn service_mut(&mut self) -> &mut IndexedSet<Service<V>> {
     &mut self.service
   }
 
@@ -213,6 +259,7 @@ impl<T, U, V> Document<T, U, V> {
       capability_invocation: self.capability_invocation,
       service: self.service,
       properties: f(self.properties),
+      relative: self.relative,
     }
   }
 
@@ -237,12 +284,13 @@ impl<T, U, V> Document<T, U, V> {
       capability_invocation: self.capability_invocation,
       service: self.service,
       properties: f(self.properties)?,
+      relative: self.relative,
     })
   }
 
   /// Finds and returns the first verification `Method` matching the provided
   ///`MethodQuery`.
-  pub fn resolve<'a, Q>(&self, query: Q) -> Option<MethodWrap<U>>
+  pub fn resolve<'a, Q>(&self, query: Q) -> Option<MethodWrap<'_, U>>
   where
     Q: Into<MethodQuery<'a>>,
   {
@@ -254,12 +302,128 @@ impl<T, U, V> Document<T, U, V> {
   ///
   /// # Errors
   ///
+  /// Fails if no matching verification `Method` is found, or if the matching
+  /// method reference points to a missing method.
+  pub fn try_resolve<'a, Q>(&self, query: Q) -> Result<MethodWrap<'_, U>>
+  where
+    Q: Into<MethodQuery<'a>>,
+  {
+    self.try_resolve_method(query.into())
+  }
+
+  /// Finds and returns the first verification `Method` matching the provided
+  /// `MethodQuery`, resolving references to methods of other DID Documents
+  /// with the given `resolver`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if no matching verification `Method` is found, if the document of
+  /// an external reference can not be resolved, or if the matching method
+  /// reference points to a missing method.
+  pub fn try_resolve_with<'a, 'b, Q, R, A, B>(
+    &'b self,
+    query: Q,
+    resolver: &'b R,
+  ) -> Result<MethodWrap<'b, U>>
+  where
+    Q: Into<MethodQuery<'a>>,
+    R: ResolveDocument<A, U, B> + ?Sized,
+    A: 'b,
+    B: 'b,
+  {
+    let query: MethodQuery = query.into();
+
+    match self.try_resolve_method(query) {
+      Err(Error::ExternalReference { id, .. }) => {
+        let mut did: DID = DID::parse(&id)?;
+
+        did.set_fragment(None);
+        did.set_query(None);
+        did.set_path("");
+
+        let method: MethodWrap<'b, U> = resolver
+          .try_resolve_document(&did)?
+          .try_resolve(id.as_str())
+          .map_err(|error| Error::missing_reference(&id).with_source(error))?;
+
+        if query.matches(&method) {
+          Ok(method)
+        } else {
+          Err(query.not_found())
+        }
+      }
+      result => result,
+    }
+  }
+
+  /// Finds and returns the first verification `Method` matching the provided
+  /// `MethodQuery` and `predicate`, e.g. a condition on the `Method`
+  /// properties.
+  ///
+  /// Methods are searched in the same order as with `Document::methods`.
+  pub fn resolve_by<'a, Q, F>(&'a self, query: Q, mut predicate: F) -> Option<MethodWrap<'a, U>>
+  where
+    Q: Into<MethodQuery<'a>>,
+    F: FnMut(&Method<U>) -> bool,
+  {
+    self.methods(query).find(|method| predicate(method))
+  }
+
+  /// Finds and returns the first verification `Method` matching the provided
+  /// `MethodQuery` and `predicate`.
+  ///
+  /// # Errors
+  ///
   /// Fails if no matching verification `Method` is found.
-  pub fn try_resolve<'a, Q>(&self, query: Q) -> Result<MethodWrap<U>>
+  pub fn try_resolve_by<'a, Q, F>(&'a self, query: Q, predicate: F) -> Result<MethodWrap<'a, U>>
+  where
+    Q: Into<MethodQuery<'a>>,
+    F: FnMut(&Method<U>) -> bool,
+  {
+    let query: MethodQuery<'a> = query.into();
+
+    self
+      .resolve_by(query, predicate)
+      .ok_or_else(|| query.not_found())
+  }
+
+  /// Returns an iterator over all verification `Method`s matching the provided
+  /// `MethodQuery`, in all scopes unless the query has a scope.
+  ///
+  /// Method references are resolved to the referenced `Method`; references
+  /// that can not be resolved are skipped. See `Document::resolve_by` to match
+  /// arbitrary predicates.
+  pub fn methods<'a, Q>(&'a self, query: Q) -> impl Iterator<Item = MethodWrap<'a, U>> + 'a
   where
     Q: Into<MethodQuery<'a>>,
   {
-    self.resolve(query).ok_or_else(|| Error::message(ERR_VMNF))
+    self.query_methods(query.into())
+  }
+
+  /// Returns an iterator over all verification `Method`s of the `Document`,
+  /// each yielded once with the scopes that authorize it.
+  ///
+  /// Embedded methods and methods referenced by a verification relationship
+  /// are both included; references to other documents and missing methods
+  /// are skipped.
+  pub fn scoped_methods(&self) -> impl Iterator<Item = ScopedMethod<'_, U>> {
+    let mut methods: Vec<ScopedMethod<'_, U>> = Vec::new();
+    let mut index: BTreeMap<&DID, usize> = BTreeMap::new();
+
+    for scope in SCOPES.iter().copied() {
+      for method in self.query_methods(MethodQuery::any().scope(scope)) {
+        let method: &Method<U> = method.into_method();
+
+        let position: usize = *index.entry(method.id()).or_insert_with(|| {
+          methods.push(ScopedMethod::new(method));
+          methods.len() - 1
+        });
+
+        methods[position].insert(scope);
+      }
+    }
+
+    methods.into_iter()
   }
 
   pub fn resolve_bytes<'a, Q>(&self, query: Q) -> Option<Vec<u8>>
@@ -273,9 +437,21 @@ impl<T, U, V> Document<T, U, V> {
   where
     Q: Into<MethodQuery<'a>>,
   {
-    self.try_resolve(query)?.key_data().try_decode()
+    let method: MethodWrap<'_, U> = self.try_resolve(query)?;
+
+    method
+      .key_data()
+      .try_decode_key(method.key_type())
+      .map_err(|error| Error::invalid_key(method.id()).with_source(error))
   }
 
+  /// Returns `SignatureOptions` for the first verification `Method` matching
+  /// the provided `MethodQuery`, with the scope of the query as proof purpose.
+  ///
+  /// # Errors
+  ///
+  /// Fails if no matching verification `Method` is found, or if the scope of
+  /// the query is not a verification relationship.
   pub fn resolve_options<'a, Q>(&self, query: Q) -> Result<SignatureOptions>
   where
     Q: Into<MethodQuery<'a>>,
@@ -283,38 +459,555 @@ impl<T, U, V> Document<T, U, V> {
     let query: MethodQuery = query.into();
     let method: MethodWrap<U> = self.try_resolve(query)?;
 
-    Ok(SignatureOptions::with_purpose(
-      method.id.to_string(),
-      query.scope.as_str().to_string(),
-    ))
+    SignatureOptions::with_scope(method.id.to_string(), query.scope.unwrap_or(method.scope))
+  }
+
+  /// Returns a view of the `Document` that serializes method and service ids
+  /// relative to the `Document` id.
+  pub fn as_relative(&self) -> RelativeDocument<'_, T, U, V> {
+    RelativeDocument(self)
+  }
+
+  /// Returns the changes from this `Document` to `other`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if either document can not be serialized.
+  pub fn diff(&self, other: &Self) -> Result<DocumentDiff<U, V>>
+  where
+    T: Serialize,
+    U: Clone + PartialEq + Serialize,
+    V: Clone + PartialEq + Serialize,
+  {
+    DocumentDiff::new(self, other)
+  }
+
+  /// Serializes the `Document` with absolute ids, regardless of the form it
+  /// was deserialized from.
+  pub(crate) fn to_absolute(&self) -> Result<Value, serde_json::Error>
+  where
+    T: Serialize,
+    U: Serialize,
+    V: Serialize,
+  {
+    Document::serialize(self, ValueSerializer)
+  }
+
+  /// Returns a new `Document` with the JSON `patch` applied.
+  ///
+  /// The patch is applied to the `Document` with absolute ids, as produced by
+  /// `DocumentDiff::to_json_patch`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the patch can not be applied or does not produce a valid
+  /// `Document`.
+  pub fn apply_patch(&self, patch: &JsonPatch) -> Result<Self>
+  where
+    T: Serialize + DeserializeOwned,
+    U: Serialize + DeserializeOwned,
+    V: Serialize + DeserializeOwned,
+  {
+    let mut value: Value = self.to_absolute().map_err(|_| Error::message(ERR_ID))?;
+
+    patch.apply(&mut value)?;
+
+    from_value(value).map_err(|_| Error::message(ERR_ID))
+  }
+
+  /// Checks the DID Core invariants of the `Document`.
+  ///
+  /// The `id` and `controller` must be DIDs without a path, query, or
+  /// fragment, method ids must be unique across the `verificationMethod` set
+  /// and all embedded methods, and every reference to a method of this
+  /// `Document` must point to a method of the `verificationMethod` set.
+  ///
+  /// # Errors
+  ///
+  /// Fails if any of the invariants does not hold.
+  pub fn validate(&self) -> Result<()> {
+    if !is_bare(&self.id) {
+      return Err(Error::message(ERR_IDI));
+    }
+
+    if !self.controller.as_ref().is_none_or(is_bare) {
+      return Err(Error::message(ERR_IDC));
+    }
+
+    let mut ids: BTreeSet<&DID> = BTreeSet::new();
+
+    let embedded = self
+      .relationships()
+      .filter_map(|method| match method.as_ref() {
+        MethodRef::Embed(method) => Some(method),
+        MethodRef::Refer(_) => None,
+      });
+
+    for method in self
+      .verification_method
+      .iter()
+      .map(|method| &**method)
+      .chain(embedded)
+    {
+      if !ids.insert(method.id()) {
+        return Err(Error::duplicate_id(method.id()));
+      }
+    }
+
+    for method in self.relationships() {
+      if let MethodRef::Refer(did) = method.as_ref() {
+        if self.is_local(did) && !self.verification_method.contains(did) {
+          return Err(Error::missing_reference(did));
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Applies a typed `DocumentUpdate` to the `Document`.
+  ///
+  /// The `Document` is validated before and after the update, and is left
+  /// unchanged if either check or the update itself fails.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the `Document` is invalid, if the update does not apply, or if
+  /// the updated `Document` is invalid.
+  pub fn update(&mut self, update: DocumentUpdate<U, V>) -> Result<()>
+  where
+    T: Clone,
+    U: Clone,
+    V: Clone,
+  {
+    self.validate()?;
+
+    let mut next: Self = self.clone();
+
+    update.apply(&mut next)?;
+    next.validate()?;
+
+    *self = next;
+
+    Ok(())
+  }
+
+  /// Replaces the verification `Method` identified by `id` with `method`.
+  ///
+  /// The `Method` is replaced in the `verificationMethod` set and in every
+  /// verification relationship that embeds it, and all references to it are
+  /// re-pointed to the id of `method`. The `Document` is left unchanged on
+  /// failure.
+  ///
+  /// # Errors
+  ///
+  /// Fails if no method with the given `id` exists, or if the id of `method`
+  /// is already used by another method.
+  pub fn rotate_method(&mut self, id: &DID, method: Method<U>) -> Result<RotationReport<U>>
+  where
+    U: Clone,
+  {
+    let current: Method<U> = self
+      .find_method(id)
+      .cloned()
+      .ok_or_else(|| Error::method_not_found(id, MethodScope::VerificationMethod))?;
+
+    let update: DID = method.id().clone();
+
+    if &update != id && self.find_method(&update).is_some() {
+      return Err(Error::duplicate_id(update));
+    }
+
+    let mut replaced: Vec<MethodScope> = Vec::new();
+    let mut repointed: Vec<MethodScope> = Vec::new();
+
+    if self
+      .verification_method
+      .replace(id, DIDKey::new(method.clone()))
+    {
+      replaced.push(MethodScope::VerificationMethod);
+    }
+
+    for (scope, set) in self.relationships_mut() {
+      match set.find(id) {
+        Some(item) if item.is_embedded() => {
+          set.replace(id, DIDKey::new(MethodRef::Embed(method.clone())));
+          replaced.push(scope);
+        }
+        Some(_) => {
+          set.replace(id, DIDKey::new(MethodRef::Refer(update.clone())));
+          repointed.push(scope);
+        }
+        None => {}
+      }
+    }
+
+    Ok(RotationReport {
+      method: current,
+      update,
+      replaced,
+      repointed,
+      revoked: false,
+    })
+  }
+
+  /// Removes the verification `Method` identified by `id` from the `Document`.
+  ///
+  /// The `Method` is removed from the `verificationMethod` set and from every
+  /// verification relationship that embeds it. References to the `Method` are
+  /// handled according to the given `RemovalMode`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if no method with the given `id` exists, or if the method is still
+  /// referenced and `mode` is `RemovalMode::Reject`.
+  pub fn remove_method(&mut self, id: &DID, mode: RemovalMode) -> Result<Method<U>> {
+    if self.find_method(id).is_none() {
+      return Err(Error::method_not_found(id, MethodScope::VerificationMethod));
+    }
+
+    if mode == RemovalMode::Reject {
+      for (scope, set) in self.relationships_mut() {
+        if set.find(id).is_some_and(|item| item.is_referred()) {
+          return Err(Error::method_in_use(id, scope));
+        }
+      }
+    }
+
+    let mut removed: Option<Method<U>> =
+      self.verification_method.remove(id).map(DIDKey::into_inner);
+
+    for (_, set) in self.relationships_mut() {
+      if let Some(MethodRef::Embed(method)) = set.remove(id).map(DIDKey::into_inner) {
+        removed.get_or_insert(method);
+      }
+    }
+
+    removed.ok_or_else(|| Error::method_not_found(id, MethodScope::VerificationMethod))
+  }
+
+  /// Removes the `Service` identified by `id` from the `Document`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if no service with the given `id` exists.
+  pub fn remove_service(&mut self, id: &DID) -> Result<Service<V>> {
+    self
+      .service
+      .remove(id)
+      .map(DIDKey::into_inner)
+      .ok_or_else(|| Error::service_not_found(id))
   }
 
-  fn resolve_method<'a>(&self, query: MethodQuery<'a>) -> Option<MethodWrap<U>> {
-    let iter = match query.scope {
-      MethodScope::VerificationMethod => return self.resolve_verification_method(query),
-      MethodScope::Authentication => self.authentication.iter(),
-      MethodScope::AssertionMethod => self.assertion_method.iter(),
-      MethodScope::KeyAgreement => self.key_agreement.iter(),
-      MethodScope::CapabilityDelegation => self.capability_delegation.iter(),
-      MethodScope::CapabilityInvocation => self.capability_invocation.iter(),
+  fn find_method(&self, id: &DID) -> Option<&Method<U>> {
+    if let Some(method) = self.verification_method.find(id) {
+      return Some(method);
+    }
+
+    self.relationship_sets().iter().find_map(|set| {
+      match set.find(id).map(|method| method.as_ref()) {
+        Some(MethodRef::Embed(method)) => Some(method),
+        Some(MethodRef::Refer(_)) | None => None,
+      }
+    })
+  }
+
+  fn relationship_sets(&self) -> [&IndexedSet<MethodRef<U>>; 5] {
+    [
+      &self.authentication,
+      &self.assertion_method,
+      &self.key_agreement,
+      &self.capability_delegation,
+      &self.capability_invocation,
+    ]
+  }
+
+  fn relationships_mut(&mut self) -> [(MethodScope, &mut IndexedSet<MethodRef<U>>); 5] {
+    [
+      (MethodScope::Authentication, &mut self.authentication),
+      (MethodScope::AssertionMethod, &mut self.assertion_method),
+      (MethodScope::KeyAgreement, &mut self.key_agreement),
+      (
+        MethodScope::CapabilityDelegation,
+        &mut self.capability_delegation,
+      ),
+      (
+        MethodScope::CapabilityInvocation,
+        &mut self.capability_invocation,
+      ),
+    ]
+  }
+
+  fn relationships(&self) -> impl Iterator<Item = &DIDKey<MethodRef<U>>> + '_ {
+    IntoIterator::into_iter(self.relationship_sets()).flat_map(|set| set.iter())
+  }
+
+  fn resolve_method(&self, query: MethodQuery<'_>) -> Option<MethodWrap<'_, U>> {
+    self.try_resolve_method(query).ok()
+  }
+
+  fn try_resolve_method(&self, query: MethodQuery<'_>) -> Result<MethodWrap<'_, U>> {
+    if let MethodIndex::Any = query.ident {
+      return self
+        .query_methods(query)
+        .next()
+        .ok_or_else(|| query.not_found());
+    }
+
+    let scope: MethodScope = query.scope.unwrap_or_default();
+
+    let method: MethodWrap<'_, U> = match self.relationship_set(scope) {
+      Some(set) => {
+        let (index, method) = self
+          .lookup(set, query.ident)
+          .ok_or_else(|| query.not_found())?;
+
+        self.resolve_reference(method, index, scope)?
+      }
+      None => self
+        .lookup(&self.verification_method, query.ident)
+        .map(|(index, method)| MethodWrap::new(method, index, scope))
+        .ok_or_else(|| query.not_found())?,
+    };
+
+    if query.matches(&method) {
+      Ok(method)
+    } else {
+      Err(query.not_found())
+    }
+  }
+
+  fn resolve_reference<'a>(
+    &'a self,
+    method: &'a MethodRef<U>,
+    index: usize,
+    scope: MethodScope,
+  ) -> Result<MethodWrap<'a, U>> {
+    match method {
+      MethodRef::Refer(did) if self.is_local(did) => self
+        .verification_method
+        .position(did)
+        .map(|index| {
+          MethodWrap::new(
+            &self.verification_method[index],
+            index,
+            MethodScope::VerificationMethod,
+          )
+        })
+        .ok_or_else(|| Error::missing_reference(did)),
+      MethodRef::Refer(did) => Err(Error::external_reference(did)),
+      MethodRef::Embed(method) => Ok(MethodWrap::new(method, index, scope)),
+    }
+  }
+
+  fn query_methods<'a, 'q>(
+    &'a self,
+    query: MethodQuery<'q>,
+  ) -> impl Iterator<Item = MethodWrap<'a, U>> + 'q
+  where
+    'a: 'q,
+  {
+    SCOPES
+      .iter()
+      .copied()
+      .filter(move |scope| query.scope.is_none_or(|value| value == *scope))
+      .flat_map(move |scope| self.scope_methods(scope, query.ident))
+      .filter(move |method| query.matches(method))
+  }
+
+  fn scope_methods<'a, 'q>(
+    &'a self,
+    scope: MethodScope,
+    ident: MethodIndex<'q>,
+  ) -> impl Iterator<Item = MethodWrap<'a, U>> + 'q
+  where
+    'a: 'q,
+  {
+    // Identifiers are compared as full DID URLs, so methods of other DID
+    // Documents do not match by fragment alone
+    let target: Option<DID> = match ident {
+      MethodIndex::Ident(ident) => self.ident_id(ident),
+      MethodIndex::Index(_) | MethodIndex::Any => None,
+    };
+
+    let matches = move |index: usize, did: &DID| match ident {
+      MethodIndex::Index(value) => value == index,
+      MethodIndex::Ident(_) => target.as_ref() == Some(did),
+      MethodIndex::Any => true,
     };
 
-    iter
+    let (methods, references) = match self.relationship_set(scope) {
+      Some(set) => (None, Some(set)),
+      None => (Some(&self.verification_method), None),
+    };
+
+    let methods = methods
+      .into_iter()
+      .flatten()
       .enumerate()
-      .find(|(index, method)| query.ident == *index || query.ident.matches(method.id()))
-      .and_then(|(index, method)| match method.as_ref() {
-        MethodRef::Refer(did) => self.resolve(did.fragment()?),
-        MethodRef::Embed(method) => Some(MethodWrap::new(method, index, query.scope)),
+      .filter({
+        let matches = matches.clone();
+        move |(index, method)| matches(*index, method.id())
       })
+      .map(move |(index, method)| MethodWrap::new(method, index, scope));
+
+    let references = references
+      .into_iter()
+      .flatten()
+      .enumerate()
+      .filter(move |(index, method)| matches(*index, method.id()))
+      .filter_map(move |(index, method)| self.resolve_reference(method, index, scope).ok());
+
+    methods.chain(references)
+  }
+
+  fn relationship_set(&self, scope: MethodScope) -> Option<&IndexedSet<MethodRef<U>>> {
+    match scope {
+      MethodScope::VerificationMethod => None,
+      MethodScope::Authentication => Some(&self.authentication),
+      MethodScope::AssertionMethod => Some(&self.assertion_method),
+      MethodScope::KeyAgreement => Some(&self.key_agreement),
+      MethodScope::CapabilityDelegation => Some(&self.capability_delegation),
+      MethodScope::CapabilityInvocation => Some(&self.capability_invocation),
+    }
+  }
+
+  fn is_local(&self, did: &DID) -> bool {
+    did.method() == self.id.method() && did.method_id() == self.id.method_id()
+  }
+
+  fn lookup<'a, X>(
+    &self,
+    set: &'a IndexedSet<X>,
+    ident: MethodIndex<'_>,
+  ) -> Option<(usize, &'a DIDKey<X>)>
+  where
+    X: AsRef<DID>,
+  {
+    let index: usize = match ident {
+      MethodIndex::Index(index) => index,
+      MethodIndex::Any => 0,
+      MethodIndex::Ident(string) => set.position(&self.ident_id(string)?)?,
+    };
+
+    set.get(index).map(|item| (index, item))
+  }
+
+  fn ident_id(&self, ident: &str) -> Option<DID> {
+    if MethodIndex::is_did_url(ident) {
+      return DID::parse(ident).ok();
+    }
+
+    let mut did: DID = self.id.clone();
+
+    did.set_fragment(Some(ident.strip_prefix('#').unwrap_or(ident)));
+
+    Some(did)
   }
+}
+
+impl<U, V> Document<Object, U, V> {
+  /// Replaces the verification `Method` identified by `id` with `method`, and
+  /// adds the replaced `Method` to the `revoked` property of the `Document`.
+  ///
+  /// See `Document::rotate_method`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the rotation fails, or if the `revoked` property is not an
+  /// array.
+  pub fn rotate_method_revoked(&mut self, id: &DID, method: Method<U>) -> Result<RotationReport<U>>
+  where
+    U: Clone + Serialize,
+  {
+    let mut revoked: Vec<Value> = match self.properties.get(REVOKED) {
+      Some(Value::Array(revoked)) => revoked.clone(),
+      Some(_) => return Err(Error::message(ERR_IRM)),
+      None => Vec::new(),
+    };
+
+    if let Some(current) = self.find_method(id) {
+      revoked.push(to_value(current).map_err(|_| Error::message(ERR_MSF))?);
+    }
+
+    let mut report: RotationReport<U> = self.rotate_method(id, method)?;
 
-  fn resolve_verification_method(&self, query: MethodQuery) -> Option<MethodWrap<U>> {
     self
-      .verification_method
-      .iter()
-      .enumerate()
-      .find(|(index, method)| query.ident == *index || query.ident.matches(method.id()))
-      .map(|(index, method)| MethodWrap::new(method, index, MethodScope::VerificationMethod))
+      .properties
+      .insert(REVOKED.into(), Value::Array(revoked));
+
+    report.revoked = true;
+
+    Ok(report)
+  }
+}
+
+fn is_bare(did: &DID) -> bool {
+  did.path().is_empty() && did.query().is_none() && did.fragment().is_none()
+}
+
+impl<T, U, V> Serialize for Document<T, U, V>
+where
+  T: Serialize,
+  U: Serialize,
+  V: Serialize,
+{
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    if self.relative.is_empty() {
+      return Document::serialize(self, serializer);
+    }
+
+    let mut value: Value = Document::serialize(self, ValueSerializer).map_err(S::Error::custom)?;
+
+    restore_relative(&mut value, &self.relative);
+
+    value.serialize(serializer)
+  }
+}
+
+impl<'de, T, U, V> Deserialize<'de> for Document<T, U, V>
+where
+  T: Deserialize<'de>,
+  U: Deserialize<'de>,
+  V: Deserialize<'de>,
+{
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let mut value: Value = Value::deserialize(deserializer)?;
+
+    let relative: RelativeIds = resolve_relative(&mut value).map_err(D::Error::custom)?;
+
+    let mut this: Self = Document::deserialize(value).map_err(D::Error::custom)?;
+
+    this.relative = relative;
+
+    Ok(this)
+  }
+}
+
+/// Documents are compared by their contents; the original form of their ids
+/// is ignored.
+impl<T, U, V> PartialEq for Document<T, U, V>
+where
+  T: PartialEq,
+{
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.controller == other.controller
+      && self.also_known_as == other.also_known_as
+      && self.verification_method == other.verification_method
+      && self.authentication == other.authentication
+      && self.assertion_method == other.assertion_method
+      && self.key_agreement == other.key_agreement
+      && self.capability_delegation == other.capability_delegation
+      && self.capability_invocation == other.capability_invocation
+      && self.service == other.service
+      && self.properties == other.properties
   }
 }
 
@@ -337,24 +1030,40 @@ impl<T, U, V> ResolveMethod<U> for Document<T, U, V> {
   fn resolve_method(&self, query: MethodQuery<'_>) -> Option<MethodWrap<'_, U>> {
     Document::resolve_method(self, query)
   }
+
+  fn try_resolve_method(&self, query: MethodQuery<'_>) -> Result<MethodWrap<'_, U>> {
+    Document::try_resolve_method(self, query)
+  }
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
+  use alloc::vec;
+
+  use crate::lib::*;
   use crate::Document;
   use crate::DocumentBuilder;
+  use crate::Error;
   use crate::Method;
   use crate::MethodBuilder;
   use crate::MethodData;
+  use crate::MethodQuery;
+  use crate::MethodRef;
   use crate::MethodScope;
   use crate::MethodType;
+  use crate::RemovalMode;
+  use crate::Service;
   use did_url::DID;
 
-  fn controller() -> DID {
+  pub(crate) fn controller() -> DID {
     "did:example:1234".parse().unwrap()
   }
 
-  fn method(controller: &DID, fragment: &str) -> Method {
+  pub(crate) fn did(fragment: &str) -> DID {
+    controller().join(fragment).unwrap()
+  }
+
+  pub(crate) fn method(controller: &DID, fragment: &str) -> Method {
     MethodBuilder::default()
       .id(controller.join(fragment).unwrap())
       .controller(controller.clone())
@@ -364,6 +1073,15 @@ mod tests {
       .unwrap()
   }
 
+  pub(crate) fn service(fragment: &str, endpoint: &str) -> Service {
+    Service::builder(Default::default())
+      .id(did(fragment))
+      .type_("LinkedDomains")
+      .service_endpoint(endpoint.parse().unwrap())
+      .build()
+      .unwrap()
+  }
+
   fn document() -> Document {
     let controller: DID = controller();
 
@@ -395,6 +1113,22 @@ mod tests {
     assert_eq!(document.resolve("key-3").unwrap().id(), "did:example:1234#key-3");
   }
 
+  #[test]
+  #[rustfmt::skip]
+  fn test_resolve_did_like_fragment() {
+    let controller: DID = controller();
+    let document: Document = DocumentBuilder::default()
+      .id(controller.clone())
+      .verification_method(method(&controller, "#didcomm-key"))
+      .build()
+      .unwrap();
+
+    // Fragments starting with "did" are not DID URLs
+    assert_eq!(document.resolve("didcomm-key").unwrap().id(), "did:example:1234#didcomm-key");
+    assert_eq!(document.resolve("#didcomm-key").unwrap().id(), "did:example:1234#didcomm-key");
+    assert_eq!(document.methods("didcomm-key").count(), 1);
+  }
+
   #[test]
   #[rustfmt::skip]
   fn test_resolve_index_identifier() {
@@ -439,4 +1173,449 @@ mod tests {
     // Resolving an existing reference to a missing method returns None
     assert_eq!(document.resolve(("#key-4", MethodScope::KeyAgreement)), None);
   }
+
+  #[test]
+  #[rustfmt::skip]
+  fn test_try_resolve_errors() {
+    let document: Document = document();
+
+    assert_eq!(
+      document.try_resolve(("#key-4", MethodScope::KeyAgreement)).unwrap_err(),
+      Error::missing_reference("did:example:1234#key-4"),
+    );
+
+    assert_eq!(
+      document.try_resolve(("#key-1", MethodScope::KeyAgreement)).unwrap_err(),
+      Error::method_not_found("#key-1", MethodScope::KeyAgreement),
+    );
+
+    assert_eq!(
+      document.try_resolve(5).unwrap_err(),
+      Error::method_not_found("5", MethodScope::VerificationMethod),
+    );
+  }
+
+  #[test]
+  #[rustfmt::skip]
+  fn test_resolve_filters() {
+    let controller: DID = controller();
+    let other: DID = "did:example:other".parse().unwrap();
+
+    let mut key_2: Method = method(&controller, "#key-2");
+    let mut key_3: Method = method(&controller, "#key-3");
+
+    *key_2.key_type_mut() = MethodType::JsonWebKey2020;
+    *key_3.controller_mut() = other.clone();
+
+    let document: Document = DocumentBuilder::default()
+      .id(controller.clone())
+      .verification_method(method(&controller, "#key-1"))
+      .verification_method(key_2)
+      .verification_method(key_3)
+      .authentication(method(&controller, "#auth-key"))
+      .authentication(controller.join("#key-3").unwrap())
+      .build()
+      .unwrap();
+
+    let query = MethodQuery::new("#key-2").key_type(MethodType::Ed25519VerificationKey2018);
+
+    assert_eq!(document.try_resolve(query).unwrap_err(), Error::method_not_found("#key-2", MethodScope::VerificationMethod));
+    assert_eq!(document.resolve(MethodQuery::new("#key-2").key_type(MethodType::JsonWebKey2020)).unwrap().id(), "did:example:1234#key-2");
+
+    // Filters apply to the referenced method
+    assert_eq!(document.resolve(MethodQuery::new("#key-3").scope(MethodScope::Authentication).controller(&other)).unwrap().id(), "did:example:1234#key-3");
+    assert_eq!(document.resolve(MethodQuery::new("#key-3").scope(MethodScope::Authentication).controller(&controller)), None);
+
+    // Wildcard queries resolve the first matching method
+    let query = MethodQuery::any().scope(MethodScope::Authentication).key_type(MethodType::Ed25519VerificationKey2018);
+
+    assert_eq!(document.resolve(query).unwrap().id(), "did:example:1234#auth-key");
+    assert_eq!(document.resolve(query.controller(&other)).unwrap().id(), "did:example:1234#key-3");
+    assert_eq!(document.resolve(query.encodings(&["publicKeyMultibase"])), None);
+    assert_eq!(document.resolve(query.encodings(&["publicKeyMultibase", "publicKeyBase58"])).unwrap().id(), "did:example:1234#auth-key");
+  }
+
+  #[test]
+  fn test_methods() {
+    let document: Document = document();
+
+    let ids = |query: MethodQuery<'_>| -> Vec<(String, MethodScope)> {
+      document
+        .methods(query)
+        .map(|method| (method.id().fragment().unwrap().into(), method.scope()))
+        .collect()
+    };
+
+    // Missing references are skipped
+    assert_eq!(
+      ids(MethodQuery::any()),
+      [
+        ("key-1".into(), MethodScope::VerificationMethod),
+        ("key-2".into(), MethodScope::VerificationMethod),
+        ("key-3".into(), MethodScope::VerificationMethod),
+        ("auth-key".into(), MethodScope::Authentication),
+        ("key-3".into(), MethodScope::VerificationMethod),
+      ]
+    );
+
+    assert_eq!(
+      ids(MethodQuery::any().scope(MethodScope::Authentication)),
+      [
+        ("auth-key".into(), MethodScope::Authentication),
+        ("key-3".into(), MethodScope::VerificationMethod),
+      ]
+    );
+
+    assert_eq!(
+      ids(MethodQuery::from("#key-3")),
+      [("key-3".into(), MethodScope::VerificationMethod)]
+    );
+
+    assert!(ids(MethodQuery::any().key_type(MethodType::JsonWebKey2020)).is_empty());
+
+    // Custom predicates are applied to the iterator
+    let method = document
+      .methods(MethodQuery::any().scope(MethodScope::Authentication))
+      .find(|method| method.key_data() == &MethodData::new_b58("#key-3"));
+
+    assert_eq!(method.unwrap().id(), "did:example:1234#key-3");
+  }
+
+  #[test]
+  fn test_resolve_by() {
+    let document: Document = document();
+    let query: MethodQuery = MethodQuery::any()
+      .scope(MethodScope::Authentication)
+      .key_type(MethodType::Ed25519VerificationKey2018);
+
+    let method = document
+      .resolve_by(query, |method| {
+        method.key_data() == &MethodData::new_b58("#key-3")
+      })
+      .unwrap();
+
+    assert_eq!(method.id(), "did:example:1234#key-3");
+    assert_eq!(method.scope(), MethodScope::VerificationMethod);
+
+    // The predicate is combined with the conditions of the query
+    assert!(document
+      .resolve_by(query.key_type(MethodType::JsonWebKey2020), |_| true)
+      .is_none());
+
+    assert_eq!(
+      document.try_resolve_by(query, |_| false).unwrap_err(),
+      query.not_found()
+    );
+  }
+
+  #[test]
+  fn test_scoped_methods() {
+    let mut document: Document = document();
+
+    document
+      .assertion_method
+      .append(MethodRef::Refer(controller().join("#key-1").unwrap()).into());
+    document
+      .assertion_method
+      .append(MethodRef::Refer("did:example:other#key-1".parse().unwrap()).into());
+    document
+      .capability_invocation
+      .append(MethodRef::Refer(controller().join("#key-3").unwrap()).into());
+
+    let methods: Vec<(&str, Vec<MethodScope>)> = document
+      .scoped_methods()
+      .map(|method| {
+        let scopes: Vec<MethodScope> = method.scopes().to_vec();
+        (method.into_method().id().fragment().unwrap(), scopes)
+      })
+      .collect();
+
+    // Referenced methods are listed once; external and missing references are skipped
+    assert_eq!(
+      methods,
+      [
+        (
+          "key-1",
+          vec![
+            MethodScope::VerificationMethod,
+            MethodScope::AssertionMethod
+          ]
+        ),
+        ("key-2", vec![MethodScope::VerificationMethod]),
+        (
+          "key-3",
+          vec![
+            MethodScope::VerificationMethod,
+            MethodScope::Authentication,
+            MethodScope::CapabilityInvocation,
+          ]
+        ),
+        ("auth-key", vec![MethodScope::Authentication]),
+      ]
+    );
+
+    let method = document.scoped_methods().nth(3).unwrap();
+
+    assert!(method.authorizes(MethodScope::Authentication));
+    assert!(!method.authorizes(MethodScope::VerificationMethod));
+    assert_eq!(
+      method.into_method(),
+      document
+        .resolve(("#auth-key", MethodScope::Authentication))
+        .unwrap()
+        .into_method()
+    );
+  }
+
+  #[test]
+  #[rustfmt::skip]
+  fn test_resolve_external_reference() {
+    let other: DID = "did:example:other".parse().unwrap();
+    let mut document: Document = document();
+
+    document.assertion_method.append(MethodRef::Refer(other.join("#key-1").unwrap()).into());
+
+    // External references do not alias methods with the same fragment
+    assert_eq!(
+      document.try_resolve(("#key-1", MethodScope::AssertionMethod)).unwrap_err(),
+      Error::method_not_found("#key-1", MethodScope::AssertionMethod),
+    );
+    assert_eq!(document.methods(MethodQuery::new("#key-1").scope(MethodScope::AssertionMethod)).count(), 0);
+    assert_eq!(
+      document.try_resolve(("did:example:other#key-1", MethodScope::AssertionMethod)).unwrap_err(),
+      Error::external_reference("did:example:other#key-1"),
+    );
+
+    // Full DID URLs must match exactly
+    assert_eq!(document.resolve("did:example:1234#key-1").unwrap().id(), "did:example:1234#key-1");
+    assert_eq!(document.resolve("did:example:other#key-1"), None);
+
+    // External references are resolved through the resolver
+    let resolver: [Document; 1] = [
+      DocumentBuilder::default()
+        .id(other.clone())
+        .verification_method(method(&other, "#key-1"))
+        .build()
+        .unwrap(),
+    ];
+
+    let query: MethodQuery = MethodQuery::with_scope("did:example:other#key-1", MethodScope::AssertionMethod);
+    let method = document.try_resolve_with(query, &resolver[..]).unwrap();
+
+    assert_eq!(method.id(), "did:example:other#key-1");
+    assert_eq!(method.scope(), MethodScope::VerificationMethod);
+
+    // The conditions of the query apply to externally resolved methods
+    assert!(document.try_resolve_with(query.key_type(MethodType::Ed25519VerificationKey2018), &resolver[..]).is_ok());
+    assert_eq!(
+      document.try_resolve_with(query.key_type(MethodType::EcdsaSecp256k1VerificationKey2019), &resolver[..]).unwrap_err(),
+      query.not_found(),
+    );
+    assert_eq!(
+      document.try_resolve_with(query.controller(&controller()), &resolver[..]).unwrap_err(),
+      query.not_found(),
+    );
+
+    assert_eq!(
+      document.try_resolve_with(query, &resolver[..0]).unwrap_err(),
+      Error::document_not_found("did:example:other"),
+    );
+
+    // Local methods do not need the resolver
+    assert_eq!(
+      document.try_resolve_with("#key-2", &resolver[..0]).unwrap().id(),
+      "did:example:1234#key-2",
+    );
+  }
+
+  #[test]
+  fn test_rotate_method() {
+    let controller: DID = controller();
+
+    let mut document: Document = DocumentBuilder::default()
+      .id(controller.clone())
+      .verification_method(method(&controller, "#key-1"))
+      .verification_method(method(&controller, "#key-2"))
+      .authentication(controller.join("#key-1").unwrap())
+      .capability_invocation(controller.join("#key-1").unwrap())
+      .key_agreement(method(&controller, "#agreement"))
+      .build()
+      .unwrap();
+
+    let report = document
+      .rotate_method(
+        &controller.join("#key-1").unwrap(),
+        method(&controller, "#key-3"),
+      )
+      .unwrap();
+
+    assert_eq!(report.method(), &method(&controller, "#key-1"));
+    assert_eq!(report.update(), "did:example:1234#key-3");
+    assert_eq!(report.replaced(), &[MethodScope::VerificationMethod]);
+    assert_eq!(
+      report.repointed(),
+      &[
+        MethodScope::Authentication,
+        MethodScope::CapabilityInvocation
+      ]
+    );
+    assert!(!report.revoked());
+
+    assert_eq!(
+      document.verification_method()[0].id(),
+      "did:example:1234#key-3"
+    );
+    assert_eq!(
+      document.authentication()[0],
+      MethodRef::Refer(controller.join("#key-3").unwrap()).into()
+    );
+    assert_eq!(
+      document.capability_invocation()[0].id(),
+      "did:example:1234#key-3"
+    );
+    assert!(document.validate().is_ok());
+
+    // Embedded methods are replaced in place
+    let report = document
+      .rotate_method(
+        &controller.join("#agreement").unwrap(),
+        method(&controller, "#agreement-2"),
+      )
+      .unwrap();
+
+    assert_eq!(report.replaced(), &[MethodScope::KeyAgreement]);
+    assert!(report.repointed().is_empty());
+    assert_eq!(
+      document.key_agreement()[0].as_ref(),
+      &MethodRef::Embed(method(&controller, "#agreement-2"))
+    );
+  }
+
+  #[test]
+  fn test_rotate_method_errors() {
+    let controller: DID = controller();
+    let mut document: Document = document();
+
+    assert_eq!(
+      document.rotate_method(
+        &controller.join("#key-9").unwrap(),
+        method(&controller, "#key-10")
+      ),
+      Err(Error::method_not_found(
+        "did:example:1234#key-9",
+        MethodScope::VerificationMethod
+      )),
+    );
+
+    assert_eq!(
+      document.rotate_method(
+        &controller.join("#key-1").unwrap(),
+        method(&controller, "#auth-key")
+      ),
+      Err(Error::duplicate_id("did:example:1234#auth-key")),
+    );
+
+    assert_eq!(
+      serde_json::to_value(&document).unwrap(),
+      serde_json::to_value(self::document()).unwrap()
+    );
+  }
+
+  #[test]
+  fn test_rotate_method_revoked() {
+    let controller: DID = controller();
+    let mut document: Document = document();
+
+    let report = document
+      .rotate_method_revoked(
+        &controller.join("#key-3").unwrap(),
+        method(&controller, "#key-5"),
+      )
+      .unwrap();
+
+    assert!(report.revoked());
+    assert_eq!(report.repointed(), &[MethodScope::Authentication]);
+    assert_eq!(
+      document.properties()["revoked"],
+      serde_json::json!([serde_json::to_value(method(&controller, "#key-3")).unwrap()])
+    );
+
+    document
+      .properties_mut()
+      .insert("revoked".into(), serde_json::json!("invalid"));
+
+    assert_eq!(
+      document.rotate_method_revoked(
+        &controller.join("#key-5").unwrap(),
+        method(&controller, "#key-6")
+      ),
+      Err(Error::message("Invalid Revoked Methods")),
+    );
+  }
+
+  #[test]
+  fn test_remove_method() {
+    let controller: DID = controller();
+    let mut document: Document = document();
+
+    // Referenced methods can not be removed without cascading
+    assert_eq!(
+      document.remove_method(&controller.join("#key-3").unwrap(), RemovalMode::Reject),
+      Err(Error::method_in_use(
+        "did:example:1234#key-3",
+        MethodScope::Authentication
+      )),
+    );
+
+    assert_eq!(
+      document.remove_method(&controller.join("#key-4").unwrap(), RemovalMode::Cascade),
+      Err(Error::method_not_found(
+        "did:example:1234#key-4",
+        MethodScope::VerificationMethod
+      )),
+    );
+
+    assert_eq!(
+      serde_json::to_value(&document).unwrap(),
+      serde_json::to_value(self::document()).unwrap()
+    );
+
+    let removed: Method = document
+      .remove_method(&controller.join("#key-3").unwrap(), RemovalMode::Cascade)
+      .unwrap();
+
+    assert_eq!(removed, method(&controller, "#key-3"));
+    assert_eq!(document.verification_method().len(), 2);
+    assert_eq!(document.authentication().len(), 1);
+
+    // Embedded methods are removed from their verification relationship
+    let removed: Method = document
+      .remove_method(&controller.join("#auth-key").unwrap(), RemovalMode::Reject)
+      .unwrap();
+
+    assert_eq!(removed, method(&controller, "#auth-key"));
+    assert!(document.authentication().is_empty());
+  }
+
+  #[test]
+  fn test_remove_service() {
+    let service_id: DID = did("#service-1");
+
+    let mut document: Document = DocumentBuilder::default()
+      .id(controller())
+      .service(service("#service-1", "https://example.com"))
+      .build()
+      .unwrap();
+
+    assert_eq!(
+      document.remove_service(&service_id).unwrap().id(),
+      &service_id
+    );
+    assert!(document.service().is_empty());
+
+    assert_eq!(
+      document.remove_service(&service_id),
+      Err(Error::service_not_found("did:example:1234#service-1")),
+    );
+  }
 }
@@ -0,0 +1,430 @@
+use did_url::DID;
+use serde::Serialize;
+use serde_json::to_value;
+use url::Url;
+
+use crate::document::Document;
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::service::Service;
+use crate::utils::IndexedSet;
+use crate::utils::JsonPatch;
+use crate::utils::Object;
+use crate::utils::Value;
+use crate::verification::Method;
+use crate::verification::MethodRef;
+
+const ERR_DSF: &str = "Document Serialization Failed";
+
+/// A change to a single item of a `Document`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change<T> {
+  Added(T),
+  Removed(T),
+  Modified { old: T, new: T },
+}
+
+impl<T> Change<T> {
+  /// Returns the item before the change, if any.
+  pub fn before(&self) -> Option<&T> {
+    match self {
+      Self::Added(_) => None,
+      Self::Removed(old) | Self::Modified { old, .. } => Some(old),
+    }
+  }
+
+  /// Returns the item after the change, if any.
+  pub fn after(&self) -> Option<&T> {
+    match self {
+      Self::Removed(_) => None,
+      Self::Added(new) | Self::Modified { new, .. } => Some(new),
+    }
+  }
+
+  /// Returns the id of the changed item.
+  pub fn id(&self) -> &DID
+  where
+    T: AsRef<DID>,
+  {
+    match self {
+      Self::Added(item) | Self::Removed(item) | Self::Modified { new: item, .. } => item.as_ref(),
+    }
+  }
+
+  fn diff(old: Option<&T>, new: Option<&T>) -> Option<Self>
+  where
+    T: Clone + PartialEq,
+  {
+    match (old, new) {
+      (Some(old), Some(new)) if old != new => Some(Self::Modified {
+        old: old.clone(),
+        new: new.clone(),
+      }),
+      (Some(_), Some(_)) | (None, None) => None,
+      (Some(old), None) => Some(Self::Removed(old.clone())),
+      (None, Some(new)) => Some(Self::Added(new.clone())),
+    }
+  }
+}
+
+/// The changes between two versions of a `Document`.
+///
+/// Methods, relationship entries, and services are compared by id; the diff
+/// can be exported as a JSON Patch with `DocumentDiff::to_json_patch`.
+///
+/// See `Document::diff`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DocumentDiff<U = Object, V = Object> {
+  pub(crate) id: Option<Change<DID>>,
+  pub(crate) controller: Option<Change<DID>>,
+  pub(crate) also_known_as: Vec<Change<Url>>,
+  pub(crate) verification_method: Vec<Change<Method<U>>>,
+  pub(crate) authentication: Vec<Change<MethodRef<U>>>,
+  pub(crate) assertion_method: Vec<Change<MethodRef<U>>>,
+  pub(crate) key_agreement: Vec<Change<MethodRef<U>>>,
+  pub(crate) capability_delegation: Vec<Change<MethodRef<U>>>,
+  pub(crate) capability_invocation: Vec<Change<MethodRef<U>>>,
+  pub(crate) service: Vec<Change<Service<V>>>,
+  pub(crate) properties: BTreeMap<String, Change<Value>>,
+  pub(crate) patch: JsonPatch,
+}
+
+impl<U, V> DocumentDiff<U, V> {
+  /// Computes the changes from `old` to `new`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if either document can not be serialized.
+  pub fn new<T>(old: &Document<T, U, V>, new: &Document<T, U, V>) -> Result<Self>
+  where
+    T: Serialize,
+    U: Clone + PartialEq + Serialize,
+    V: Clone + PartialEq + Serialize,
+  {
+    let old_value: Value = old.to_absolute().map_err(|_| Error::message(ERR_DSF))?;
+    let new_value: Value = new.to_absolute().map_err(|_| Error::message(ERR_DSF))?;
+
+    let old_properties: Object = to_object(&old.properties)?;
+    let new_properties: Object = to_object(&new.properties)?;
+
+    let properties: BTreeMap<String, Change<Value>> = old_properties
+      .keys()
+      .chain(new_properties.keys())
+      .filter_map(|key| {
+        Change::diff(old_properties.get(key), new_properties.get(key))
+          .map(|change| (key.clone(), change))
+      })
+      .collect();
+
+    let also_known_as: Vec<Change<Url>> = old
+      .also_known_as
+      .iter()
+      .filter(|url| !new.also_known_as.contains(url))
+      .map(|url| Change::Removed(url.clone()))
+      .chain(
+        new
+          .also_known_as
+          .iter()
+          .filter(|url| !old.also_known_as.contains(url))
+          .map(|url| Change::Added(url.clone())),
+      )
+      .collect();
+
+    Ok(Self {
+      id: Change::diff(Some(&old.id), Some(&new.id)),
+      controller: Change::diff(old.controller.as_ref(), new.controller.as_ref()),
+      also_known_as,
+      verification_method: diff_set(&old.verification_method, &new.verification_method),
+      authentication: diff_set(&old.authentication, &new.authentication),
+      assertion_method: diff_set(&old.assertion_method, &new.assertion_method),
+      key_agreement: diff_set(&old.key_agreement, &new.key_agreement),
+      capability_delegation: diff_set(&old.capability_delegation, &new.capability_delegation),
+      capability_invocation: diff_set(&old.capability_invocation, &new.capability_invocation),
+      service: diff_set(&old.service, &new.service),
+      properties,
+      patch: JsonPatch::diff(&old_value, &new_value),
+    })
+  }
+
+  /// Returns `true` if no item of the `Document` changed.
+  pub fn is_empty(&self) -> bool {
+    self.id.is_none()
+      && self.controller.is_none()
+      && self.also_known_as.is_empty()
+      && self.verification_method.is_empty()
+      && self.authentication.is_empty()
+      && self.assertion_method.is_empty()
+      && self.key_agreement.is_empty()
+      && self.capability_delegation.is_empty()
+      && self.capability_invocation.is_empty()
+      && self.service.is_empty()
+      && self.properties.is_empty()
+  }
+
+  /// Returns the change of the `Document` id, if any.
+  pub fn id(&self) -> Option<&Change<DID>> {
+    self.id.as_ref()
+  }
+
+  /// Returns the change of the `Document` controller, if any.
+  pub fn controller(&self) -> Option<&Change<DID>> {
+    self.controller.as_ref()
+  }
+
+  /// Returns the added and removed `alsoKnownAs` URLs.
+  pub fn also_known_as(&self) -> &[Change<Url>] {
+    &self.also_known_as
+  }
+
+  /// Returns the changed verification methods.
+  pub fn verification_method(&self) -> &[Change<Method<U>>] {
+    &self.verification_method
+  }
+
+  /// Returns the changed `authentication` entries.
+  pub fn authentication(&self) -> &[Change<MethodRef<U>>] {
+    &self.authentication
+  }
+
+  /// Returns the changed `assertionMethod` entries.
+  pub fn assertion_method(&self) -> &[Change<MethodRef<U>>] {
+    &self.assertion_method
+  }
+
+  /// Returns the changed `keyAgreement` entries.
+  pub fn key_agreement(&self) -> &[Change<MethodRef<U>>] {
+    &self.key_agreement
+  }
+
+  /// Returns the changed `capabilityDelegation` entries.
+  pub fn capability_delegation(&self) -> &[Change<MethodRef<U>>] {
+    &self.capability_delegation
+  }
+
+  /// Returns the changed `capabilityInvocation` entries.
+  pub fn capability_invocation(&self) -> &[Change<MethodRef<U>>] {
+    &self.capability_invocation
+  }
+
+  /// Returns the changed services.
+  pub fn service(&self) -> &[Change<Service<V>>] {
+    &self.service
+  }
+
+  /// Returns the changed custom properties, by name.
+  pub fn properties(&self) -> &BTreeMap<String, Change<Value>> {
+    &self.properties
+  }
+
+  /// Returns the changes as a JSON Patch.
+  ///
+  /// The patch applies to the `Document` with absolute ids, whichever form
+  /// either document was deserialized from. See `Document::apply_patch`.
+  pub fn to_json_patch(&self) -> JsonPatch {
+    self.patch.clone()
+  }
+}
+
+fn diff_set<T>(old: &IndexedSet<T>, new: &IndexedSet<T>) -> Vec<Change<T>>
+where
+  T: AsRef<DID> + Clone + PartialEq,
+{
+  let removed = old
+    .iter()
+    .filter(|item| !new.contains(item.as_did()))
+    .map(|item| Change::Removed((**item).clone()));
+
+  let changed = new.iter().filter_map(|item| match old.find(item.as_did()) {
+    Some(current) => Change::diff(Some(&**current), Some(&**item)),
+    None => Some(Change::Added((**item).clone())),
+  });
+
+  removed.chain(changed).collect()
+}
+
+fn to_object<T>(properties: &T) -> Result<Object>
+where
+  T: Serialize,
+{
+  match to_value(properties).map_err(|_| Error::message(ERR_DSF))? {
+    Value::Object(object) => Ok(object.into_iter().collect()),
+    _ => Ok(Object::new()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+  use crate::document::document::tests::controller;
+  use crate::document::document::tests::did;
+  use crate::document::document::tests::method;
+  use crate::document::document::tests::service;
+  use crate::document::DocumentBuilder;
+  use crate::verification::MethodData;
+
+  fn key(fragment: &str, key: &[u8]) -> Method {
+    let mut method: Method = method(&controller(), fragment);
+
+    *method.key_data_mut() = MethodData::new_b58(key);
+    method
+  }
+
+  fn properties(value: Value) -> Object {
+    serde_json::from_value(value).unwrap()
+  }
+
+  fn old() -> Document {
+    DocumentBuilder::new(properties(json!({ "foo": 1, "baz": true })))
+      .id(controller())
+      .also_known_as("https://a.example.com".parse().unwrap())
+      .verification_method(key("#key-1", b"key-1"))
+      .verification_method(key("#key-2", b"key-2"))
+      .authentication(did("#key-1"))
+      .service(service("#service-1", "https://example.com"))
+      .build()
+      .unwrap()
+  }
+
+  fn new() -> Document {
+    DocumentBuilder::new(properties(json!({ "foo": 2, "bar": [] })))
+      .id(controller())
+      .controller("did:example:ctrl".parse().unwrap())
+      .also_known_as("https://b.example.com".parse().unwrap())
+      .verification_method(key("#key-3", b"key-3"))
+      .verification_method(key("#key-1", b"rotated"))
+      .authentication(did("#key-3"))
+      .service(service("#service-1", "https://example.org"))
+      .service(service("#service-2", "https://example.com"))
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_diff() {
+    let diff: DocumentDiff = old().diff(&new()).unwrap();
+
+    assert_eq!(diff.id(), None);
+    assert_eq!(
+      diff.controller(),
+      Some(&Change::Added("did:example:ctrl".parse().unwrap()))
+    );
+    assert_eq!(
+      diff.also_known_as(),
+      &[
+        Change::Removed("https://a.example.com".parse().unwrap()),
+        Change::Added("https://b.example.com".parse().unwrap()),
+      ]
+    );
+
+    assert_eq!(
+      diff.verification_method(),
+      &[
+        Change::Removed(key("#key-2", b"key-2")),
+        Change::Added(key("#key-3", b"key-3")),
+        Change::Modified {
+          old: key("#key-1", b"key-1"),
+          new: key("#key-1", b"rotated"),
+        },
+      ]
+    );
+
+    let ids: Vec<&DID> = diff.authentication().iter().map(Change::id).collect();
+
+    assert_eq!(ids, [&did("#key-1"), &did("#key-3")]);
+    assert!(matches!(diff.authentication()[0], Change::Removed(_)));
+    assert!(matches!(diff.authentication()[1], Change::Added(_)));
+    assert!(diff.assertion_method().is_empty());
+
+    assert_eq!(diff.service().len(), 2);
+    assert_eq!(
+      diff.service()[0]
+        .before()
+        .unwrap()
+        .service_endpoint()
+        .as_str(),
+      "https://example.com/"
+    );
+    assert_eq!(
+      diff.service()[0]
+        .after()
+        .unwrap()
+        .service_endpoint()
+        .as_str(),
+      "https://example.org/"
+    );
+    assert_eq!(diff.service()[1].before(), None);
+
+    assert_eq!(diff.properties().len(), 3);
+    assert_eq!(diff.properties()["baz"], Change::Removed(json!(true)));
+    assert_eq!(diff.properties()["bar"], Change::Added(json!([])));
+    assert_eq!(
+      diff.properties()["foo"],
+      Change::Modified {
+        old: json!(1),
+        new: json!(2),
+      }
+    );
+  }
+
+  #[test]
+  fn test_json_patch() {
+    let (old, new): (Document, Document) = (old(), new());
+    let patch: JsonPatch = old.diff(&new).unwrap().to_json_patch();
+
+    assert_eq!(
+      to_value(old.apply_patch(&patch).unwrap()).unwrap(),
+      to_value(&new).unwrap()
+    );
+
+    // Patches are reversible by diffing the other way around
+    let patch: JsonPatch = new.diff(&old).unwrap().to_json_patch();
+
+    assert_eq!(
+      to_value(new.apply_patch(&patch).unwrap()).unwrap(),
+      to_value(&old).unwrap()
+    );
+
+    assert!(old.diff(&old).unwrap().is_empty());
+  }
+
+  #[test]
+  fn test_diff_relative() {
+    let relative: Document =
+      serde_json::from_value(to_value(old().as_relative()).unwrap()).unwrap();
+
+    // The form of the ids is not a change
+    assert_ne!(to_value(&relative).unwrap(), to_value(old()).unwrap());
+    assert!(relative.diff(&old()).unwrap().is_empty());
+    assert!(relative.diff(&old()).unwrap().to_json_patch().is_empty());
+
+    let patch: JsonPatch = relative.diff(&new()).unwrap().to_json_patch();
+
+    assert_eq!(
+      to_value(relative.apply_patch(&patch).unwrap()).unwrap(),
+      to_value(new()).unwrap()
+    );
+
+    let patch: JsonPatch = new().diff(&relative).unwrap().to_json_patch();
+
+    assert_eq!(
+      to_value(new().apply_patch(&patch).unwrap()).unwrap(),
+      to_value(old()).unwrap()
+    );
+  }
+
+  #[test]
+  fn test_apply_patch_invalid() {
+    let patch: JsonPatch = serde_json::from_value(json!([
+      { "op": "remove", "path": "/id" },
+    ]))
+    .unwrap();
+
+    assert_eq!(
+      old().apply_patch(&patch),
+      Err(Error::message("Invalid Document"))
+    );
+  }
+}
@@ -0,0 +1,347 @@
+use did_url::DID;
+
+use crate::document::Document;
+use crate::document::RemovalMode;
+use crate::error::Error;
+use crate::error::Result;
+use crate::service::Service;
+use crate::utils::DIDKey;
+use crate::utils::IndexedSet;
+use crate::utils::Object;
+use crate::verification::Method;
+use crate::verification::MethodRef;
+use crate::verification::MethodScope;
+
+const ERR_IVR: &str = "Invalid Verification Relationship";
+
+/// A typed update operation of a `Document`.
+///
+/// Updates are serializable so they can be logged and replayed; see
+/// `Document::update`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "op", rename_all = "camelCase")]
+pub enum DocumentUpdate<U = Object, V = Object> {
+  /// Adds a method to the `verificationMethod` set.
+  AddMethod { method: Method<U> },
+  /// Removes a method that is not referenced by a verification relationship.
+  ///
+  /// See `Document::remove_method`.
+  RemoveMethod { id: DID },
+  /// Adds a method or method reference to a verification relationship.
+  AttachMethod {
+    method: MethodRef<U>,
+    scope: MethodScope,
+  },
+  /// Removes a method or method reference from a verification relationship.
+  DetachMethod { id: DID, scope: MethodScope },
+  /// Replaces a method and re-points all references to it.
+  ///
+  /// See `Document::rotate_method`.
+  RotateMethod { id: DID, method: Method<U> },
+  /// Adds a service to the `service` set.
+  AddService { service: Service<V> },
+  /// Removes a service from the `service` set.
+  RemoveService { id: DID },
+  /// Sets or clears the `Document` controller.
+  SetController { controller: Option<DID> },
+  /// Removes all methods, verification relationships, and services.
+  Deactivate,
+}
+
+impl<U, V> DocumentUpdate<U, V> {
+  pub(crate) fn apply<T>(self, document: &mut Document<T, U, V>) -> Result<()>
+  where
+    U: Clone,
+  {
+    match self {
+      Self::AddMethod { method } => {
+        let id: DID = method.id().clone();
+
+        if !document.verification_method.append(DIDKey::new(method)) {
+          return Err(Error::duplicate_id(id));
+        }
+      }
+      Self::RemoveMethod { id } => {
+        document.remove_method(&id, RemovalMode::Reject)?;
+      }
+      Self::AttachMethod { method, scope } => {
+        let id: DID = method.id().clone();
+
+        if !relationship(document, scope)?.append(DIDKey::new(method)) {
+          return Err(Error::duplicate_id(id));
+        }
+      }
+      Self::DetachMethod { id, scope } => {
+        if relationship(document, scope)?.remove(&id).is_none() {
+          return Err(Error::method_not_found(id, scope));
+        }
+      }
+      Self::RotateMethod { id, method } => {
+        document.rotate_method(&id, method)?;
+      }
+      Self::AddService { service } => {
+        let id: DID = service.id().clone();
+
+        if !document.service.append(DIDKey::new(service)) {
+          return Err(Error::duplicate_id(id));
+        }
+      }
+      Self::RemoveService { id } => {
+        document.remove_service(&id)?;
+      }
+      Self::SetController { controller } => {
+        document.controller = controller;
+      }
+      Self::Deactivate => {
+        document.verification_method.clear();
+        document.authentication.clear();
+        document.assertion_method.clear();
+        document.key_agreement.clear();
+        document.capability_delegation.clear();
+        document.capability_invocation.clear();
+        document.service.clear();
+      }
+    }
+
+    Ok(())
+  }
+}
+
+fn relationship<T, U, V>(
+  document: &mut Document<T, U, V>,
+  scope: MethodScope,
+) -> Result<&mut IndexedSet<MethodRef<U>>> {
+  match scope {
+    MethodScope::VerificationMethod => Err(Error::message(ERR_IVR)),
+    MethodScope::Authentication => Ok(&mut document.authentication),
+    MethodScope::AssertionMethod => Ok(&mut document.assertion_method),
+    MethodScope::KeyAgreement => Ok(&mut document.key_agreement),
+    MethodScope::CapabilityDelegation => Ok(&mut document.capability_delegation),
+    MethodScope::CapabilityInvocation => Ok(&mut document.capability_invocation),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::from_value;
+  use serde_json::json;
+  use serde_json::to_value;
+
+  use super::*;
+  use crate::document::document::tests::controller;
+  use crate::document::document::tests::did;
+  use crate::document::document::tests::method;
+  use crate::document::document::tests::service;
+  use crate::document::DocumentBuilder;
+
+  fn document() -> Document {
+    DocumentBuilder::default()
+      .id(controller())
+      .verification_method(method(&controller(), "#key-1"))
+      .verification_method(method(&controller(), "#key-2"))
+      .authentication(did("#key-1"))
+      .assertion_method(did("#key-1"))
+      .key_agreement(method(&controller(), "#agreement"))
+      .service(service("#service-1", "https://example.com"))
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_methods() {
+    let mut document: Document = document();
+
+    document
+      .update(DocumentUpdate::AddMethod {
+        method: method(&controller(), "#key-3"),
+      })
+      .unwrap();
+
+    document
+      .update(DocumentUpdate::AttachMethod {
+        method: MethodRef::Refer(did("#key-3")),
+        scope: MethodScope::CapabilityInvocation,
+      })
+      .unwrap();
+
+    assert_eq!(document.verification_method().len(), 3);
+    assert_eq!(document.capability_invocation().len(), 1);
+
+    document
+      .update(DocumentUpdate::DetachMethod {
+        id: did("#key-3"),
+        scope: MethodScope::CapabilityInvocation,
+      })
+      .unwrap();
+
+    document
+      .update(DocumentUpdate::RemoveMethod { id: did("#key-3") })
+      .unwrap();
+
+    assert_eq!(document.verification_method().len(), 2);
+    assert!(document.capability_invocation().is_empty());
+  }
+
+  #[test]
+  fn test_invariants() {
+    let mut document: Document = document();
+
+    // Methods referenced by a verification relationship can not be removed
+    assert_eq!(
+      document.update(DocumentUpdate::RemoveMethod { id: did("#key-1") }),
+      Err(Error::method_in_use(
+        did("#key-1"),
+        MethodScope::Authentication
+      ))
+    );
+
+    // Method ids must be unique across embedded methods
+    assert_eq!(
+      document.update(DocumentUpdate::AddMethod {
+        method: method(&controller(), "#agreement"),
+      }),
+      Err(Error::duplicate_id(did("#agreement")))
+    );
+
+    // References must point to a method of the document
+    assert_eq!(
+      document.update(DocumentUpdate::AttachMethod {
+        method: MethodRef::Refer(did("#key-5")),
+        scope: MethodScope::Authentication,
+      }),
+      Err(Error::missing_reference(did("#key-5")))
+    );
+
+    assert_eq!(
+      document.update(DocumentUpdate::AttachMethod {
+        method: MethodRef::Refer(did("#key-2")),
+        scope: MethodScope::VerificationMethod,
+      }),
+      Err(Error::message("Invalid Verification Relationship"))
+    );
+
+    assert_eq!(
+      document.update(DocumentUpdate::SetController {
+        controller: Some(did("#key-1")),
+      }),
+      Err(Error::message("Invalid Document Controller"))
+    );
+
+    // Failed updates leave the document unchanged
+    assert_eq!(
+      to_value(&document).unwrap(),
+      to_value(self::document()).unwrap()
+    );
+  }
+
+  #[test]
+  fn test_rotate_method() {
+    let mut document: Document = document();
+
+    document
+      .update(DocumentUpdate::RotateMethod {
+        id: did("#key-1"),
+        method: method(&controller(), "#key-3"),
+      })
+      .unwrap();
+
+    assert_eq!(document.verification_method()[0].id(), &did("#key-3"));
+    assert_eq!(document.authentication()[0].id(), &did("#key-3"));
+    assert_eq!(document.assertion_method()[0].id(), &did("#key-3"));
+
+    assert_eq!(
+      document.update(DocumentUpdate::RotateMethod {
+        id: did("#key-3"),
+        method: method(&controller(), "#key-2"),
+      }),
+      Err(Error::duplicate_id(did("#key-2")))
+    );
+  }
+
+  #[test]
+  fn test_services() {
+    let mut document: Document = document();
+
+    document
+      .update(DocumentUpdate::AddService {
+        service: service("#service-2", "https://example.com"),
+      })
+      .unwrap();
+
+    assert_eq!(
+      document.update(DocumentUpdate::AddService {
+        service: service("#service-2", "https://example.com"),
+      }),
+      Err(Error::duplicate_id(did("#service-2")))
+    );
+
+    document
+      .update(DocumentUpdate::RemoveService {
+        id: did("#service-1"),
+      })
+      .unwrap();
+
+    assert_eq!(
+      document.update(DocumentUpdate::RemoveService {
+        id: did("#service-1"),
+      }),
+      Err(Error::service_not_found(did("#service-1")))
+    );
+
+    assert_eq!(document.service().len(), 1);
+  }
+
+  #[test]
+  fn test_deactivate() {
+    let mut document: Document = document();
+
+    document
+      .update(DocumentUpdate::SetController {
+        controller: Some("did:example:ctrl".parse().unwrap()),
+      })
+      .unwrap();
+
+    document.update(DocumentUpdate::Deactivate).unwrap();
+
+    assert_eq!(document.controller().unwrap(), "did:example:ctrl");
+    assert!(document.verification_method().is_empty());
+    assert!(document.authentication().is_empty());
+    assert!(document.key_agreement().is_empty());
+    assert!(document.service().is_empty());
+  }
+
+  #[test]
+  fn test_serde() {
+    let update: DocumentUpdate = DocumentUpdate::AttachMethod {
+      method: MethodRef::Refer(did("#key-2")),
+      scope: MethodScope::KeyAgreement,
+    };
+
+    let value = to_value(&update).unwrap();
+
+    assert_eq!(
+      value,
+      json!({
+        "op": "attachMethod",
+        "method": "did:example:1234#key-2",
+        "scope": "keyAgreement",
+      })
+    );
+    assert_eq!(from_value::<DocumentUpdate>(value).unwrap(), update);
+
+    let update: DocumentUpdate = DocumentUpdate::AddMethod {
+      method: method(&controller(), "#key-3"),
+    };
+
+    let value = to_value(&update).unwrap();
+
+    assert_eq!(value["op"], "addMethod");
+    assert_eq!(value["method"]["id"], "did:example:1234#key-3");
+    assert_eq!(from_value::<DocumentUpdate>(value).unwrap(), update);
+
+    assert_eq!(
+      to_value(DocumentUpdate::<Object, Object>::Deactivate).unwrap(),
+      json!({ "op": "deactivate" })
+    );
+  }
+}
@@ -2,6 +2,16 @@
 
 mod document;
 mod document_builder;
+mod document_diff;
+mod document_update;
+mod relative_document;
+mod removal_mode;
+mod rotation_report;
 
 pub use self::document::*;
 pub use self::document_builder::*;
+pub use self::document_diff::*;
+pub use self::document_update::*;
+pub use self::relative_document::*;
+pub use self::removal_mode::*;
+pub use self::rotation_report::*;
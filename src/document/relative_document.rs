@@ -0,0 +1,430 @@
+use core::fmt::Display;
+use core::fmt::Error as FmtError;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use did_url::DID;
+use serde::ser::Error as _;
+use serde::Serialize;
+use serde::Serializer;
+use serde_json::to_string;
+use serde_json::to_string_pretty;
+use serde_json::to_value;
+use serde_json::Map;
+
+use crate::document::Document;
+use crate::error::Result;
+use crate::lib::*;
+use crate::utils::Value;
+
+const METHODS: [&str; 6] = [
+  "verificationMethod",
+  "authentication",
+  "assertionMethod",
+  "keyAgreement",
+  "capabilityDelegation",
+  "capabilityInvocation",
+];
+
+const SERVICE: &str = "service";
+const PREFIX: &str = "did:";
+
+/// A `Document` that serializes method and service ids relative to the
+/// `Document` id, e.g. `"#key-1"`.
+///
+/// See `Document::as_relative`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RelativeDocument<'a, T, U, V>(pub(crate) &'a Document<T, U, V>);
+
+impl<'a, T, U, V> RelativeDocument<'a, T, U, V> {
+  /// Returns a reference to the underlying `Document`.
+  pub const fn document(&self) -> &'a Document<T, U, V> {
+    self.0
+  }
+}
+
+impl<T, U, V> Serialize for RelativeDocument<'_, T, U, V>
+where
+  T: Serialize,
+  U: Serialize,
+  V: Serialize,
+{
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    let mut value: Value = to_value(self.0).map_err(S::Error::custom)?;
+
+    make_relative(&mut value);
+
+    value.serialize(serializer)
+  }
+}
+
+impl<T, U, V> Display for RelativeDocument<'_, T, U, V>
+where
+  T: Serialize,
+  U: Serialize,
+  V: Serialize,
+{
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    if f.alternate() {
+      f.write_str(&to_string_pretty(self).map_err(|_| FmtError)?)
+    } else {
+      f.write_str(&to_string(self).map_err(|_| FmtError)?)
+    }
+  }
+}
+
+/// The original form of the method, reference, and service ids of a
+/// deserialized `Document`.
+///
+/// Relative ids are resolved when a `Document` is deserialized; the original
+/// form is kept to serialize the `Document` as it was received, so proofs over
+/// the original form can be verified.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RelativeIds(BTreeMap<(&'static str, String), Original>);
+
+#[derive(Clone, Debug, Default)]
+struct Original {
+  // The id before it was resolved, if it was relative
+  id: Option<String>,
+  // `true` if the controller of an embedded method was omitted
+  controller: bool,
+}
+
+impl RelativeIds {
+  pub(crate) fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}
+
+/// Resolves relative method, reference, and service ids of a JSON `Document`
+/// against the `Document` id, and sets the controller of methods without one
+/// to the `Document` id.
+///
+/// Returns the original form of the changed ids; see `restore_relative`.
+pub(crate) fn resolve_relative(value: &mut Value) -> Result<RelativeIds> {
+  let mut relative: RelativeIds = RelativeIds::default();
+
+  // A missing or invalid `id` is reported when deserializing the `Document`
+  let base: DID = match value.get("id").and_then(Value::as_str).map(DID::parse) {
+    Some(Ok(base)) => base,
+    Some(Err(_)) | None => return Ok(relative),
+  };
+
+  let object: &mut Map<String, Value> = match value.as_object_mut() {
+    Some(object) => object,
+    None => return Ok(relative),
+  };
+
+  for key in METHODS.iter().chain(Some(&SERVICE)) {
+    for item in items_mut(object, key) {
+      let (id, controller): (&mut String, bool) = match item {
+        Value::String(id) if *key != SERVICE => (id, false),
+        Value::Object(object) => {
+          let controller: bool = *key != SERVICE && !object.contains_key("controller");
+
+          if controller {
+            object.insert("controller".into(), Value::String(base.to_string()));
+          }
+
+          match object.get_mut("id") {
+            Some(Value::String(id)) => (id, controller),
+            _ => continue,
+          }
+        }
+        _ => continue,
+      };
+
+      let original: Option<String> = resolve_id(&base, id)?;
+
+      if original.is_some() || controller {
+        relative.0.insert(
+          (key, id.clone()),
+          Original {
+            id: original,
+            controller,
+          },
+        );
+      }
+    }
+  }
+
+  Ok(relative)
+}
+
+/// Restores the original form of the ids of a JSON `Document` changed by
+/// `resolve_relative`.
+///
+/// Ids that no longer resolve to the same DID URL, e.g. after the `Document`
+/// id was changed, are kept as they are.
+pub(crate) fn restore_relative(value: &mut Value, relative: &RelativeIds) {
+  let base: DID = match value.get("id").and_then(Value::as_str).map(DID::parse) {
+    Some(Ok(base)) => base,
+    Some(Err(_)) | None => return,
+  };
+
+  let object: &mut Map<String, Value> = match value.as_object_mut() {
+    Some(object) => object,
+    None => return,
+  };
+
+  for key in METHODS.iter().chain(Some(&SERVICE)) {
+    for item in items_mut(object, key) {
+      match item {
+        Value::String(id) => {
+          if let Some(original) = relative.0.get(&(*key, id.clone())) {
+            restore_id(&base, original, id);
+          }
+        }
+        Value::Object(object) => {
+          let original: &Original = match object.get("id").and_then(Value::as_str) {
+            Some(id) => match relative.0.get(&(*key, id.to_string())) {
+              Some(original) => original,
+              None => continue,
+            },
+            None => continue,
+          };
+
+          if original.controller
+            && object.get("controller").and_then(Value::as_str) == Some(base.as_str())
+          {
+            object.remove("controller");
+          }
+
+          if let Some(Value::String(id)) = object.get_mut("id") {
+            restore_id(&base, original, id);
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+}
+
+fn make_relative(value: &mut Value) {
+  let base: String = match value.get("id").and_then(Value::as_str) {
+    Some(base) => base.to_string(),
+    None => return,
+  };
+
+  let object: &mut Map<String, Value> = match value.as_object_mut() {
+    Some(object) => object,
+    None => return,
+  };
+
+  for key in METHODS.iter().chain(Some(&SERVICE)) {
+    for item in items_mut(object, key) {
+      match item {
+        Value::String(id) => make_id(&base, id),
+        Value::Object(object) => {
+          if let Some(Value::String(id)) = object.get_mut("id") {
+            make_id(&base, id);
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+}
+
+fn items_mut<'a>(
+  object: &'a mut Map<String, Value>,
+  key: &str,
+) -> impl Iterator<Item = &'a mut Value> {
+  object
+    .get_mut(key)
+    .and_then(Value::as_array_mut)
+    .into_iter()
+    .flatten()
+}
+
+fn resolve_id(base: &DID, id: &mut String) -> Result<Option<String>> {
+  if id.starts_with(PREFIX) {
+    return Ok(None);
+  }
+
+  let resolved: String = base.join(&*id)?.into_string();
+
+  Ok(Some(core::mem::replace(id, resolved)))
+}
+
+fn restore_id(base: &DID, original: &Original, id: &mut String) {
+  if let Some(relative) = original.id.as_deref() {
+    if base.join(relative).is_ok_and(|did| did.as_str() == id) {
+      *id = relative.to_string();
+    }
+  }
+}
+
+fn make_id(base: &str, id: &mut String) {
+  if let Some(fragment) = id.strip_prefix(base).filter(|rest| rest.starts_with('#')) {
+    *id = fragment.to_string();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::from_str;
+  use serde_json::from_value;
+  use serde_json::json;
+
+  use super::*;
+  use crate::signature::ld_suite::tests::policy;
+  use crate::signature::ld_suite::tests::TestSuite;
+  use crate::signature::LdSuite;
+  use crate::signature::SignatureOptions;
+  use crate::verifiable::Verifiable;
+  use crate::verifiable::VerifiableDocument;
+
+  fn relative() -> Value {
+    json!({
+      "id": "did:example:1234",
+      "verificationMethod": [
+        {
+          "id": "#key-1",
+          "type": "Ed25519VerificationKey2018",
+          "publicKeyBase58": "3M5RCDjPTWPkKSN3sxUmmMqHbmRPegYP1tjcKyrDbt9J",
+        },
+        {
+          "id": "did:example:1234#key-2",
+          "controller": "did:example:ctrl",
+          "type": "Ed25519VerificationKey2018",
+          "publicKeyBase58": "3M5RCDjPTWPkKSN3sxUmmMqHbmRPegYP1tjcKyrDbt9J",
+        },
+      ],
+      "authentication": [
+        "#key-1",
+        "did:example:other#key-1",
+        {
+          "id": "#auth-key",
+          "type": "Ed25519VerificationKey2018",
+          "publicKeyBase58": "3M5RCDjPTWPkKSN3sxUmmMqHbmRPegYP1tjcKyrDbt9J",
+        },
+      ],
+      "service": [
+        {
+          "id": "#service-1",
+          "type": "LinkedDomains",
+          "serviceEndpoint": "https://example.com/",
+        },
+      ],
+    })
+  }
+
+  #[test]
+  fn test_deserialize_relative() {
+    let document: Document = from_value(relative()).unwrap();
+
+    let method = document.resolve("#key-1").unwrap();
+
+    assert_eq!(method.id(), "did:example:1234#key-1");
+    assert_eq!(method.controller(), "did:example:1234");
+
+    let method = document.resolve("#key-2").unwrap();
+
+    assert_eq!(method.controller(), "did:example:ctrl");
+
+    let ids: Vec<&str> = document
+      .authentication()
+      .iter()
+      .map(|method| method.id().as_str())
+      .collect();
+
+    assert_eq!(
+      ids,
+      [
+        "did:example:1234#key-1",
+        "did:example:other#key-1",
+        "did:example:1234#auth-key",
+      ]
+    );
+
+    assert_eq!(document.service()[0].id(), "did:example:1234#service-1");
+  }
+
+  #[test]
+  fn test_serialize_relative() {
+    let document: Document = from_value(relative()).unwrap();
+    let value: Value = to_value(document.as_relative()).unwrap();
+
+    assert_eq!(value["verificationMethod"][0]["id"], "#key-1");
+    assert!(value["verificationMethod"][0].get("controller").is_none());
+    assert_eq!(value["verificationMethod"][1]["id"], "#key-2");
+    assert_eq!(value["authentication"][0], "#key-1");
+    assert_eq!(value["authentication"][1], "did:example:other#key-1");
+    assert_eq!(value["authentication"][2]["id"], "#auth-key");
+    assert_eq!(value["service"][0]["id"], "#service-1");
+
+    // Relative documents deserialize to the same document
+    assert_eq!(from_value::<Document>(value).unwrap(), document);
+  }
+
+  #[test]
+  fn test_serialize_original() {
+    let mut document: Document = from_value(relative()).unwrap();
+
+    // Deserialized documents are serialized in their original form
+    assert_eq!(to_value(&document).unwrap(), relative());
+
+    // Ids that no longer resolve to the same DID URL are kept absolute
+    *document.id_mut() = "did:example:5678".parse().unwrap();
+
+    let value: Value = to_value(&document).unwrap();
+
+    assert_eq!(
+      value["verificationMethod"][0]["id"],
+      "did:example:1234#key-1"
+    );
+    assert_eq!(
+      value["verificationMethod"][0]["controller"],
+      "did:example:1234"
+    );
+  }
+
+  #[test]
+  fn test_sign_verify_relative() {
+    let secret: &[u8] = b"secret";
+    let json: Value = json!({
+      "id": "did:example:1234",
+      "verificationMethod": [
+        {
+          "id": "#key-1",
+          "type": "Ed25519VerificationKey2018",
+          "publicKeyBase58": bs58::encode(secret).into_string(),
+        },
+      ],
+      "assertionMethod": ["#key-1"],
+    });
+
+    let options = || {
+      let mut options: SignatureOptions =
+        SignatureOptions::with_purpose("#key-1".into(), "assertionMethod".into());
+
+      options.created = Some("2021-01-01T00:00:00Z".parse().unwrap());
+      options
+    };
+
+    // A proof created over the relative form by another implementation
+    let mut external: Verifiable<Value> = Verifiable::new(json.clone());
+
+    LdSuite::new(TestSuite)
+      .sign(&mut external, options(), secret)
+      .unwrap();
+
+    let document: VerifiableDocument = from_str(&external.to_string()).unwrap();
+
+    assert!(LdSuite::new(TestSuite).verify(&document, &policy()).is_ok());
+
+    // A proof created over a deserialized document
+    let mut document: VerifiableDocument = from_value(json).unwrap();
+
+    LdSuite::new(TestSuite)
+      .sign(&mut document, options(), secret)
+      .unwrap();
+
+    let document: VerifiableDocument = from_str(&document.to_string()).unwrap();
+
+    assert!(LdSuite::new(TestSuite).verify(&document, &policy()).is_ok());
+  }
+}
@@ -0,0 +1,11 @@
+/// Specifies how `Document::remove_method` handles references to the removed
+/// method.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RemovalMode {
+  /// Removes all references to the method from the verification relationships.
+  Cascade,
+  /// Rejects the removal if any verification relationship references the
+  /// method.
+  #[default]
+  Reject,
+}
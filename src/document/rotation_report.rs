@@ -0,0 +1,52 @@
+use did_url::DID;
+
+use crate::lib::*;
+use crate::utils::Object;
+use crate::verification::Method;
+use crate::verification::MethodScope;
+
+/// The changes made by rotating a verification method.
+///
+/// See `Document::rotate_method`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RotationReport<U = Object> {
+  pub(crate) method: Method<U>,
+  pub(crate) update: DID,
+  pub(crate) replaced: Vec<MethodScope>,
+  pub(crate) repointed: Vec<MethodScope>,
+  pub(crate) revoked: bool,
+}
+
+impl<U> RotationReport<U> {
+  /// Returns the replaced verification `Method`.
+  pub fn method(&self) -> &Method<U> {
+    &self.method
+  }
+
+  /// Returns the id of the replacement `Method`.
+  pub fn update(&self) -> &DID {
+    &self.update
+  }
+
+  /// Returns the sets in which the `Method` itself was replaced.
+  pub fn replaced(&self) -> &[MethodScope] {
+    &self.replaced
+  }
+
+  /// Returns the verification relationships in which a reference to the
+  /// `Method` was re-pointed.
+  pub fn repointed(&self) -> &[MethodScope] {
+    &self.repointed
+  }
+
+  /// Returns `true` if the replaced `Method` was added to the revoked methods
+  /// of the `Document`.
+  pub const fn revoked(&self) -> bool {
+    self.revoked
+  }
+
+  /// Consumes the `RotationReport` and returns the replaced `Method`.
+  pub fn into_method(self) -> Method<U> {
+    self.method
+  }
+}
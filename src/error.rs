@@ -3,10 +3,17 @@ use core::fmt::Display;
 use core::fmt::Formatter;
 use core::fmt::Result as FmtResult;
 
+use crate::lib::*;
+use crate::verification::MethodScope;
+use crate::verification::MethodType;
+
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
 /// Represents all possible errors that can occur in the crate.
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// Errors of a specific failure domain identify the offending id or index and
+/// may carry the underlying error as their `source`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Error {
   Message {
     error: &'static str,
@@ -18,23 +25,329 @@ pub enum Error {
   InvalidDID {
     error: did_url::Error,
   },
+  /// The key data of a verification method is not a valid public key.
+  InvalidKey {
+    id: String,
+    source: Option<Box<Error>>,
+  },
+  /// A signature does not match the signed data.
+  InvalidSignature {
+    id: String,
+    source: Option<Box<Error>>,
+  },
+  /// A verification method type is not supported by the requested operation.
+  InvalidMethodType {
+    id: String,
+    type_: MethodType,
+    source: Option<Box<Error>>,
+  },
+  /// An item occurs more than once in an `OrderedSet`.
+  DuplicateItem {
+    index: usize,
+    source: Option<Box<Error>>,
+  },
+  /// An id occurs more than once in a `Document`.
+  DuplicateId {
+    id: String,
+    source: Option<Box<Error>>,
+  },
+  /// A method reference points to a method not found in the document.
+  MissingReference {
+    id: String,
+    source: Option<Box<Error>>,
+  },
+  /// A method reference points to a method of another DID Document.
+  ExternalReference {
+    id: String,
+    source: Option<Box<Error>>,
+  },
+  /// No verification method matches a query.
+  MethodNotFound {
+    id: String,
+    scope: MethodScope,
+    source: Option<Box<Error>>,
+  },
+  /// A verification method is still referenced by a verification
+  /// relationship.
+  MethodInUse {
+    id: String,
+    scope: MethodScope,
+    source: Option<Box<Error>>,
+  },
+  /// No service matches an id.
+  ServiceNotFound {
+    id: String,
+    source: Option<Box<Error>>,
+  },
+  /// A DID could not be resolved to a DID Document.
+  DocumentNotFound {
+    id: String,
+    source: Option<Box<Error>>,
+  },
+  /// A DID uses a DID method not supported by the resolver.
+  UnsupportedDIDMethod {
+    id: String,
+    source: Option<Box<Error>>,
+  },
+  /// A DID could not be resolved for reasons other than a missing DID
+  /// Document, e.g. a failed network request.
+  ResolutionFailed {
+    id: String,
+    source: Option<Box<Error>>,
+  },
 }
 
 impl Error {
   pub const fn message(error: &'static str) -> Self {
     Self::Message { error }
   }
+
+  pub fn invalid_key(id: impl ToString) -> Self {
+    Self::InvalidKey {
+      id: id.to_string(),
+      source: None,
+    }
+  }
+
+  pub fn invalid_signature(id: impl ToString) -> Self {
+    Self::InvalidSignature {
+      id: id.to_string(),
+      source: None,
+    }
+  }
+
+  pub fn invalid_method_type(id: impl ToString, type_: MethodType) -> Self {
+    Self::InvalidMethodType {
+      id: id.to_string(),
+      type_,
+      source: None,
+    }
+  }
+
+  pub const fn duplicate_item(index: usize) -> Self {
+    Self::DuplicateItem {
+      index,
+      source: None,
+    }
+  }
+
+  pub fn duplicate_id(id: impl ToString) -> Self {
+    Self::DuplicateId {
+      id: id.to_string(),
+      source: None,
+    }
+  }
+
+  pub fn missing_reference(id: impl ToString) -> Self {
+    Self::MissingReference {
+      id: id.to_string(),
+      source: None,
+    }
+  }
+
+  pub fn external_reference(id: impl ToString) -> Self {
+    Self::ExternalReference {
+      id: id.to_string(),
+      source: None,
+    }
+  }
+
+  pub fn method_not_found(id: impl ToString, scope: MethodScope) -> Self {
+    Self::MethodNotFound {
+      id: id.to_string(),
+      scope,
+      source: None,
+    }
+  }
+
+  pub fn method_in_use(id: impl ToString, scope: MethodScope) -> Self {
+    Self::MethodInUse {
+      id: id.to_string(),
+      scope,
+      source: None,
+    }
+  }
+
+  pub fn service_not_found(id: impl ToString) -> Self {
+    Self::ServiceNotFound {
+      id: id.to_string(),
+      source: None,
+    }
+  }
+
+  pub fn document_not_found(id: impl ToString) -> Self {
+    Self::DocumentNotFound {
+      id: id.to_string(),
+      source: None,
+    }
+  }
+
+  pub fn unsupported_did_method(id: impl ToString) -> Self {
+    Self::UnsupportedDIDMethod {
+      id: id.to_string(),
+      source: None,
+    }
+  }
+
+  pub fn resolution_failed(id: impl ToString) -> Self {
+    Self::ResolutionFailed {
+      id: id.to_string(),
+      source: None,
+    }
+  }
+
+  /// Sets the underlying error; has no effect on errors without a source.
+  #[must_use]
+  pub fn with_source(mut self, error: Error) -> Self {
+    if let Some(source) = self.source_mut() {
+      *source = Some(Box::new(error));
+    }
+
+    self
+  }
+
+  /// Returns the underlying error, if any.
+  pub fn source_error(&self) -> Option<&Error> {
+    match self {
+      Self::Message { .. } | Self::InvalidBuilder { .. } | Self::InvalidDID { .. } => None,
+      Self::InvalidKey { source, .. }
+      | Self::InvalidSignature { source, .. }
+      | Self::InvalidMethodType { source, .. }
+      | Self::DuplicateItem { source, .. }
+      | Self::DuplicateId { source, .. }
+      | Self::MissingReference { source, .. }
+      | Self::ExternalReference { source, .. }
+      | Self::MethodNotFound { source, .. }
+      | Self::MethodInUse { source, .. }
+      | Self::ServiceNotFound { source, .. }
+      | Self::DocumentNotFound { source, .. }
+      | Self::UnsupportedDIDMethod { source, .. }
+      | Self::ResolutionFailed { source, .. } => source.as_deref(),
+    }
+  }
+
+  /// Returns the id of the offending DID, verification method, or signature,
+  /// if any.
+  pub fn id(&self) -> Option<&str> {
+    match self {
+      Self::Message { .. }
+      | Self::InvalidBuilder { .. }
+      | Self::InvalidDID { .. }
+      | Self::DuplicateItem { .. } => None,
+      Self::InvalidKey { id, .. }
+      | Self::InvalidSignature { id, .. }
+      | Self::InvalidMethodType { id, .. }
+      | Self::DuplicateId { id, .. }
+      | Self::MissingReference { id, .. }
+      | Self::ExternalReference { id, .. }
+      | Self::MethodNotFound { id, .. }
+      | Self::MethodInUse { id, .. }
+      | Self::ServiceNotFound { id, .. }
+      | Self::DocumentNotFound { id, .. }
+      | Self::UnsupportedDIDMethod { id, .. }
+      | Self::ResolutionFailed { id, .. } => Some(id),
+    }
+  }
+
+  /// Returns the DID Resolution error code of the error.
+  ///
+  /// Errors without a corresponding code, e.g. signature mismatches, map to
+  /// `internalError`.
+  pub const fn code(&self) -> &'static str {
+    match self {
+      Self::InvalidDID { .. } => "invalidDid",
+      Self::InvalidKey { .. } => "invalidPublicKey",
+      Self::InvalidMethodType { .. } => "unsupportedPublicKeyType",
+      Self::DuplicateId { .. } => "invalidDidDocument",
+      Self::MissingReference { .. }
+      | Self::ExternalReference { .. }
+      | Self::MethodNotFound { .. }
+      | Self::ServiceNotFound { .. }
+      | Self::DocumentNotFound { .. } => "notFound",
+      Self::UnsupportedDIDMethod { .. } => "methodNotSupported",
+      Self::Message { .. }
+      | Self::InvalidBuilder { .. }
+      | Self::InvalidSignature { .. }
+      | Self::DuplicateItem { .. }
+      | Self::MethodInUse { .. }
+      | Self::ResolutionFailed { .. } => "internalError",
+    }
+  }
+
+  fn source_mut(&mut self) -> Option<&mut Option<Box<Error>>> {
+    match self {
+      Self::Message { .. } | Self::InvalidBuilder { .. } | Self::InvalidDID { .. } => None,
+      Self::InvalidKey { source, .. }
+      | Self::InvalidSignature { source, .. }
+      | Self::InvalidMethodType { source, .. }
+      | Self::DuplicateItem { source, .. }
+      | Self::DuplicateId { source, .. }
+      | Self::MissingReference { source, .. }
+      | Self::ExternalReference { source, .. }
+      | Self::MethodNotFound { source, .. }
+      | Self::MethodInUse { source, .. }
+      | Self::ServiceNotFound { source, .. }
+      | Self::DocumentNotFound { source, .. }
+      | Self::UnsupportedDIDMethod { source, .. }
+      | Self::ResolutionFailed { source, .. } => Some(source),
+    }
+  }
 }
 
 impl Display for Error {
   fn fmt(&self, f: &mut Formatter) -> FmtResult {
     match self {
-      Self::Message { error } => f.write_str(error),
+      Self::Message { error } => f.write_str(error)?,
       Self::InvalidBuilder { name, error } => {
-        f.write_fmt(format_args!("Invalid Builder({}): {}", name, error))
+        f.write_fmt(format_args!("Invalid Builder({}): {}", name, error))?
+      }
+      Self::InvalidDID { error } => Display::fmt(error, f)?,
+      Self::InvalidKey { id, .. } => f.write_fmt(format_args!("Invalid Key Data({})", id))?,
+      Self::InvalidSignature { id, .. } => {
+        f.write_fmt(format_args!("Invalid Signature({})", id))?
+      }
+      Self::InvalidMethodType { id, type_, .. } => f.write_fmt(format_args!(
+        "Invalid Method Type({}): {}",
+        id,
+        type_.as_str()
+      ))?,
+      Self::DuplicateItem { index, .. } => {
+        f.write_fmt(format_args!("Duplicate Item in Ordered Set({})", index))?
       }
-      Self::InvalidDID { error } => Display::fmt(error, f),
+      Self::DuplicateId { id, .. } => f.write_fmt(format_args!("Duplicate Id({})", id))?,
+      Self::MissingReference { id, .. } => {
+        f.write_fmt(format_args!("Missing Method Reference({})", id))?
+      }
+      Self::ExternalReference { id, .. } => {
+        f.write_fmt(format_args!("External Method Reference({})", id))?
+      }
+      Self::MethodNotFound { id, scope, .. } => f.write_fmt(format_args!(
+        "Verification Method Not Found({}): {}",
+        id,
+        scope.as_str()
+      ))?,
+      Self::MethodInUse { id, scope, .. } => f.write_fmt(format_args!(
+        "Verification Method In Use({}): {}",
+        id,
+        scope.as_str()
+      ))?,
+      Self::ServiceNotFound { id, .. } => f.write_fmt(format_args!("Service Not Found({})", id))?,
+      Self::DocumentNotFound { id, .. } => {
+        f.write_fmt(format_args!("Document Not Found({})", id))?
+      }
+      Self::UnsupportedDIDMethod { id, .. } => {
+        f.write_fmt(format_args!("Unsupported DID Method({})", id))?
+      }
+      Self::ResolutionFailed { id, .. } => {
+        f.write_fmt(format_args!("Resolution Failed({})", id))?
+      }
+    }
+
+    if let Some(source) = self.source_error() {
+      f.write_fmt(format_args!(": {}", source))?;
     }
+
+    Ok(())
   }
 }
 
@@ -45,4 +358,77 @@ impl From<did_url::Error> for Error {
 }
 
 #[cfg(feature = "std")]
-impl ::std::error::Error for Error {}
+impl ::std::error::Error for Error {
+  fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+    self
+      .source_error()
+      .map(|error| error as &(dyn ::std::error::Error + 'static))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_display() {
+    let error: Error = Error::invalid_signature("did:example:123#key-1")
+      .with_source(Error::message("Invalid Ed25519 Signature"));
+
+    assert_eq!(
+      error.to_string(),
+      "Invalid Signature(did:example:123#key-1): Invalid Ed25519 Signature"
+    );
+    assert_eq!(error.id(), Some("did:example:123#key-1"));
+    assert_eq!(
+      error.source_error(),
+      Some(&Error::message("Invalid Ed25519 Signature"))
+    );
+
+    let error: Error = Error::method_not_found("#key-2", MethodScope::Authentication);
+
+    assert_eq!(
+      error.to_string(),
+      "Verification Method Not Found(#key-2): authentication"
+    );
+    assert_eq!(error.source_error(), None);
+  }
+
+  #[test]
+  fn test_code() {
+    let did: Error = "did:example".parse::<did_url::DID>().unwrap_err().into();
+
+    assert_eq!(did.code(), "invalidDid");
+    assert_eq!(Error::invalid_key("#key-1").code(), "invalidPublicKey");
+    assert_eq!(
+      Error::invalid_method_type("#key-1", MethodType::Bls12381G2Key2020).code(),
+      "unsupportedPublicKeyType"
+    );
+    assert_eq!(
+      Error::document_not_found("did:example:123").code(),
+      "notFound"
+    );
+    assert_eq!(
+      Error::unsupported_did_method("did:unknown:123").code(),
+      "methodNotSupported"
+    );
+    assert_eq!(
+      Error::resolution_failed("did:example:123").code(),
+      "internalError"
+    );
+    assert_eq!(Error::duplicate_item(1).code(), "internalError");
+  }
+
+  #[test]
+  fn test_with_source() {
+    // Errors without a source are left unchanged
+    assert_eq!(
+      Error::message("Error").with_source(Error::message("Source")),
+      Error::message("Error")
+    );
+
+    let error: Error = Error::missing_reference("#key-1").with_source(Error::duplicate_item(0));
+
+    assert_eq!(error.source_error(), Some(&Error::duplicate_item(0)));
+  }
+}
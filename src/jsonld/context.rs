@@ -0,0 +1,639 @@
+use core::slice;
+use url::Url;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld::contexts;
+use crate::lib::*;
+use crate::utils::Value;
+
+type Map = serde_json::Map<String, Value>;
+
+const ERR_ICT: &str = "Invalid JSON-LD Context";
+const ERR_ITD: &str = "Invalid JSON-LD Term Definition";
+const ERR_IIM: &str = "Invalid JSON-LD IRI Mapping";
+const ERR_CTD: &str = "Cyclic JSON-LD Term Definition";
+const ERR_RCI: &str = "Recursive JSON-LD Context Inclusion";
+const ERR_KWR: &str = "JSON-LD Keyword Redefinition";
+const ERR_PTR: &str = "Protected JSON-LD Term Redefinition";
+const ERR_UFT: &str = "Unsupported JSON-LD Feature";
+
+const KEYWORDS: &[&str] = &[
+  "@base",
+  "@container",
+  "@context",
+  "@direction",
+  "@graph",
+  "@id",
+  "@import",
+  "@included",
+  "@index",
+  "@json",
+  "@language",
+  "@list",
+  "@nest",
+  "@none",
+  "@prefix",
+  "@propagate",
+  "@protected",
+  "@reverse",
+  "@set",
+  "@type",
+  "@value",
+  "@version",
+  "@vocab",
+];
+
+const CONTAINERS: &[&str] = &[
+  "@graph",
+  "@id",
+  "@index",
+  "@language",
+  "@list",
+  "@set",
+  "@type",
+];
+
+const TERM_KEYS: &[&str] = &[
+  "@container",
+  "@context",
+  "@direction",
+  "@id",
+  "@index",
+  "@language",
+  "@nest",
+  "@prefix",
+  "@protected",
+  "@reverse",
+  "@type",
+];
+
+// Characters ending an IRI that can be used as a prefix (RFC 3986 gen-delims)
+const GEN_DELIMS: &[char] = &[':', '/', '?', '#', '[', ']', '@'];
+
+/// Returns `true` if `value` is a JSON-LD keyword.
+pub(crate) fn is_keyword(value: &str) -> bool {
+  KEYWORDS.contains(&value)
+}
+
+/// Returns `true` if `value` has the form of a keyword; such values are
+/// reserved and ignored.
+fn is_keyword_like(value: &str) -> bool {
+  value.len() > 1
+    && value.starts_with('@')
+    && value[1..].bytes().all(|byte| byte.is_ascii_alphabetic())
+}
+
+/// Returns `true` if `value` is a blank node identifier.
+pub(crate) fn is_blank(value: &str) -> bool {
+  value.starts_with("_:")
+}
+
+/// Returns `true` if `value` is an IRI with a scheme.
+pub(crate) fn is_absolute_iri(value: &str) -> bool {
+  match value.find(':') {
+    Some(index) if index > 0 => {
+      let scheme: &[u8] = &value.as_bytes()[..index];
+
+      scheme[0].is_ascii_alphabetic()
+        && scheme
+          .iter()
+          .all(|byte| byte.is_ascii_alphanumeric() || b"+-.".contains(byte))
+    }
+    _ => false,
+  }
+}
+
+/// A JSON-LD term definition.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Term {
+  /// The IRI or keyword the term expands to; `None` for terms mapped to
+  /// `null`.
+  pub(crate) id: Option<String>,
+  /// The type mapping, an IRI or one of `@id`, `@json`, `@none`, `@vocab`.
+  pub(crate) type_: Option<String>,
+  pub(crate) container: Vec<String>,
+  /// The scoped context, processed when the term is used.
+  pub(crate) context: Option<Value>,
+  pub(crate) language: Option<Option<String>>,
+  pub(crate) prefix: bool,
+  pub(crate) protected: bool,
+}
+
+impl Term {
+  pub(crate) fn has_container(&self, container: &str) -> bool {
+    self.container.iter().any(|item| item == container)
+  }
+
+  fn same(&self, other: &Self) -> bool {
+    Self {
+      protected: other.protected,
+      ..self.clone()
+    } == *other
+  }
+}
+
+/// A JSON-LD active context.
+///
+/// Remote contexts are resolved from the documents embedded in this crate;
+/// `@import`, `@reverse`, `@nest` and base directions are not supported.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Context {
+  terms: BTreeMap<String, Term>,
+  base: Option<String>,
+  original_base: Option<String>,
+  vocab: Option<String>,
+  language: Option<String>,
+  previous: Option<Box<Context>>,
+}
+
+impl Context {
+  /// Creates an empty active context resolving relative IRIs against `base`.
+  pub(crate) fn new(base: Option<&str>) -> Self {
+    Self {
+      base: base.map(Into::into),
+      original_base: base.map(Into::into),
+      ..Self::default()
+    }
+  }
+
+  pub(crate) fn term(&self, term: &str) -> Option<&Term> {
+    self.terms.get(term)
+  }
+
+  /// Returns the context replaced by a non-propagated (type-scoped) context.
+  pub(crate) fn previous(&self) -> Option<&Context> {
+    self.previous.as_deref()
+  }
+
+  /// Returns the default language of strings.
+  pub(crate) fn language(&self) -> Option<&str> {
+    self.language.as_deref()
+  }
+
+  /// Returns the result of processing the `local` context.
+  pub(crate) fn process(
+    &self,
+    local: &Value,
+    override_protected: bool,
+    propagate: bool,
+  ) -> Result<Self> {
+    self.process_with(local, override_protected, propagate, &mut Vec::new())
+  }
+
+  fn process_with(
+    &self,
+    local: &Value,
+    override_protected: bool,
+    propagate: bool,
+    remote: &mut Vec<String>,
+  ) -> Result<Self> {
+    let propagate: bool = match local.get("@propagate") {
+      Some(value) => value.as_bool().ok_or_else(|| Error::message(ERR_ICT))?,
+      None => propagate,
+    };
+
+    let mut result: Self = self.clone();
+
+    if !propagate && result.previous.is_none() {
+      result.previous = Some(Box::new(self.clone()));
+    }
+
+    let locals: &[Value] = match local {
+      Value::Array(locals) => locals,
+      local => slice::from_ref(local),
+    };
+
+    for context in locals {
+      match context {
+        Value::Null => {
+          if !override_protected && result.terms.values().any(|term| term.protected) {
+            return Err(Error::message(ERR_ICT));
+          }
+
+          result = Self {
+            base: self.original_base.clone(),
+            original_base: self.original_base.clone(),
+            previous: (!propagate).then(|| Box::new(result.clone())),
+            ..Self::default()
+          };
+        }
+        Value::String(url) => {
+          if remote.contains(url) {
+            return Err(Error::message(ERR_RCI));
+          }
+
+          let document: Value = contexts::load(url)?;
+          let context: &Value = document
+            .get("@context")
+            .ok_or_else(|| Error::message(ERR_ICT))?;
+
+          remote.push(url.clone());
+          result = result.process_with(context, false, true, remote)?;
+          remote.pop();
+        }
+        Value::Object(context) => result.define(context, override_protected, !remote.is_empty())?,
+        _ => return Err(Error::message(ERR_ICT)),
+      }
+    }
+
+    Ok(result)
+  }
+
+  fn define(&mut self, context: &Map, override_protected: bool, remote: bool) -> Result<()> {
+    if let Some(version) = context.get("@version") {
+      if version.as_f64() != Some(1.1) {
+        return Err(Error::message(ERR_ICT));
+      }
+    }
+
+    if context.contains_key("@import")
+      || context
+        .get("@direction")
+        .is_some_and(|value| !value.is_null())
+    {
+      return Err(Error::message(ERR_UFT));
+    }
+
+    // Remote contexts can not change the base IRI of the document
+    if let Some(base) = context.get("@base").filter(|_| !remote) {
+      self.base = match base {
+        Value::Null => None,
+        Value::String(base) => Some(self.resolve(base)),
+        _ => return Err(Error::message(ERR_ICT)),
+      };
+    }
+
+    if let Some(vocab) = context.get("@vocab") {
+      self.vocab = match vocab {
+        Value::Null => None,
+        Value::String(vocab) => self
+          .expand_iri(vocab, true, true)
+          .filter(|iri| is_absolute_iri(iri) || is_blank(iri))
+          .map(Some)
+          .ok_or_else(|| Error::message(ERR_ICT))?,
+        _ => return Err(Error::message(ERR_ICT)),
+      };
+    }
+
+    if let Some(language) = context.get("@language") {
+      self.language = match language {
+        Value::Null => None,
+        Value::String(language) => Some(language.to_lowercase()),
+        _ => return Err(Error::message(ERR_ICT)),
+      };
+    }
+
+    let protected: bool = match context.get("@protected") {
+      Some(value) => value.as_bool().ok_or_else(|| Error::message(ERR_ICT))?,
+      None => false,
+    };
+
+    let mut defined: BTreeMap<String, bool> = BTreeMap::new();
+
+    for term in context.keys() {
+      if matches!(
+        term.as_str(),
+        "@base"
+          | "@direction"
+          | "@import"
+          | "@language"
+          | "@propagate"
+          | "@protected"
+          | "@version"
+          | "@vocab"
+      ) {
+        continue;
+      }
+
+      self.define_term(context, term, &mut defined, protected, override_protected)?;
+    }
+
+    Ok(())
+  }
+
+  fn define_term(
+    &mut self,
+    context: &Map,
+    term: &str,
+    defined: &mut BTreeMap<String, bool>,
+    protected: bool,
+    override_protected: bool,
+  ) -> Result<()> {
+    match defined.get(term) {
+      Some(true) => return Ok(()),
+      Some(false) => return Err(Error::message(ERR_CTD)),
+      None => {}
+    }
+
+    if term.is_empty() {
+      return Err(Error::message(ERR_ITD));
+    }
+
+    defined.insert(term.to_string(), false);
+
+    let value: &Value = context.get(term).unwrap_or(&Value::Null);
+
+    if term == "@type" {
+      // `@type` may only be marked as a `@set` container
+      let valid: bool = value.as_object().is_some_and(|object| {
+        !object.is_empty()
+          && object.iter().all(|(key, value)| {
+            (key == "@container" && value == "@set") || (key == "@protected" && value.is_boolean())
+          })
+      });
+
+      if !valid {
+        return Err(Error::message(ERR_KWR));
+      }
+
+      defined.insert(term.to_string(), true);
+
+      return Ok(());
+    }
+
+    if is_keyword(term) {
+      return Err(Error::message(ERR_KWR));
+    }
+
+    if is_keyword_like(term) {
+      defined.insert(term.to_string(), true);
+
+      return Ok(());
+    }
+
+    let previous: Option<Term> = self.terms.remove(term);
+
+    let (value, simple): (Map, bool) = match value {
+      Value::Null => (simple(Value::Null), false),
+      Value::String(id) => (simple(Value::from(id.as_str())), true),
+      Value::Object(object) => (object.clone(), false),
+      _ => return Err(Error::message(ERR_ITD)),
+    };
+
+    if value.keys().any(|key| !TERM_KEYS.contains(&key.as_str())) {
+      return Err(Error::message(ERR_ITD));
+    }
+
+    if value.contains_key("@reverse")
+      || value.contains_key("@nest")
+      || value.contains_key("@index")
+      || value
+        .get("@direction")
+        .is_some_and(|value| !value.is_null())
+    {
+      return Err(Error::message(ERR_UFT));
+    }
+
+    let mut definition: Term = Term {
+      protected: match value.get("@protected") {
+        Some(value) => value.as_bool().ok_or_else(|| Error::message(ERR_ITD))?,
+        None => protected,
+      },
+      ..Term::default()
+    };
+
+    if let Some(type_) = value.get("@type") {
+      let type_: &str = type_.as_str().ok_or_else(|| Error::message(ERR_ITD))?;
+      let type_: String = self
+        .expand_local(context, defined, type_, protected, override_protected)?
+        .filter(|iri| {
+          matches!(iri.as_str(), "@id" | "@json" | "@none" | "@vocab") || is_absolute_iri(iri)
+        })
+        .ok_or_else(|| Error::message(ERR_ITD))?;
+
+      definition.type_ = Some(type_);
+    }
+
+    match value.get("@id") {
+      Some(Value::Null) => {}
+      Some(Value::String(id)) if id != term => {
+        if !is_keyword(id) && is_keyword_like(id) {
+          defined.insert(term.to_string(), true);
+
+          return Ok(());
+        }
+
+        let iri: String = self
+          .expand_local(context, defined, id, protected, override_protected)?
+          .filter(|iri| is_keyword(iri) || is_absolute_iri(iri) || is_blank(iri))
+          .filter(|iri| iri != "@context")
+          .ok_or_else(|| Error::message(ERR_IIM))?;
+
+        definition.prefix = simple
+          && !term.contains(':')
+          && !term.contains('/')
+          && (iri.ends_with(GEN_DELIMS) || is_blank(&iri));
+        definition.id = Some(iri);
+      }
+      Some(Value::String(_)) | None => {
+        definition.id =
+          Some(self.term_iri(context, defined, term, protected, override_protected)?);
+      }
+      Some(_) => return Err(Error::message(ERR_IIM)),
+    }
+
+    if let Some(container) = value.get("@container") {
+      let container: Vec<String> = match container {
+        Value::String(container) => alloc::vec![container.clone()],
+        Value::Array(items) => items
+          .iter()
+          .map(|item| item.as_str().map(Into::into))
+          .collect::<Option<_>>()
+          .ok_or_else(|| Error::message(ERR_ITD))?,
+        _ => return Err(Error::message(ERR_ITD)),
+      };
+
+      if container
+        .iter()
+        .any(|item| !CONTAINERS.contains(&item.as_str()))
+      {
+        return Err(Error::message(ERR_ITD));
+      }
+
+      definition.container = container;
+    }
+
+    if let Some(context) = value.get("@context") {
+      definition.context = Some(context.clone());
+    }
+
+    if let Some(language) = value
+      .get("@language")
+      .filter(|_| definition.type_.is_none())
+    {
+      definition.language = match language {
+        Value::Null => Some(None),
+        Value::String(language) => Some(Some(language.to_lowercase())),
+        _ => return Err(Error::message(ERR_ITD)),
+      };
+    }
+
+    if let Some(prefix) = value.get("@prefix") {
+      if term.contains(':') || term.contains('/') {
+        return Err(Error::message(ERR_ITD));
+      }
+
+      definition.prefix = prefix.as_bool().ok_or_else(|| Error::message(ERR_ITD))?;
+    }
+
+    if let Some(previous) = previous.filter(|previous| previous.protected && !override_protected) {
+      if !previous.same(&definition) {
+        return Err(Error::message(ERR_PTR));
+      }
+
+      definition = previous;
+    }
+
+    self.terms.insert(term.to_string(), definition);
+    defined.insert(term.to_string(), true);
+
+    Ok(())
+  }
+
+  // Returns the IRI of a term defined without an explicit `@id`.
+  fn term_iri(
+    &mut self,
+    context: &Map,
+    defined: &mut BTreeMap<String, bool>,
+    term: &str,
+    protected: bool,
+    override_protected: bool,
+  ) -> Result<String> {
+    if let Some(index) = colon(term) {
+      let (prefix, suffix): (&str, &str) = (&term[..index], &term[index + 1..]);
+
+      if context.contains_key(prefix) {
+        self.define_term(context, prefix, defined, protected, override_protected)?;
+      }
+
+      // A compact IRI, or otherwise an absolute IRI or blank node identifier
+      return Ok(
+        match self.terms.get(prefix).and_then(|term| term.id.as_deref()) {
+          Some(iri) => alloc::format!("{}{}", iri, suffix),
+          None => term.to_string(),
+        },
+      );
+    }
+
+    if term.contains('/') {
+      return self
+        .expand_iri(term, false, true)
+        .filter(|iri| is_absolute_iri(iri))
+        .ok_or_else(|| Error::message(ERR_IIM));
+    }
+
+    match self.vocab.as_deref() {
+      Some(vocab) => Ok(alloc::format!("{}{}", vocab, term)),
+      None => Err(Error::message(ERR_IIM)),
+    }
+  }
+
+  // Expands `value` while defining the terms of `context`, defining the terms
+  // `value` depends on first.
+  fn expand_local(
+    &mut self,
+    context: &Map,
+    defined: &mut BTreeMap<String, bool>,
+    value: &str,
+    protected: bool,
+    override_protected: bool,
+  ) -> Result<Option<String>> {
+    if context.contains_key(value) && defined.get(value) != Some(&true) {
+      self.define_term(context, value, defined, protected, override_protected)?;
+    }
+
+    if let Some(index) = colon(value) {
+      let prefix: &str = &value[..index];
+
+      if context.contains_key(prefix) && defined.get(prefix) != Some(&true) {
+        self.define_term(context, prefix, defined, protected, override_protected)?;
+      }
+    }
+
+    Ok(self.expand_iri(value, false, true))
+  }
+
+  /// Expands `value` to an IRI, keyword or blank node identifier.
+  ///
+  /// Terms and `@vocab` apply if `vocab` is set; otherwise relative IRIs are
+  /// resolved against the base IRI if `relative` is set. Returns `None` for
+  /// values mapped to `null` and reserved keyword-like values.
+  pub(crate) fn expand_iri(&self, value: &str, relative: bool, vocab: bool) -> Option<String> {
+    if is_keyword(value) {
+      return Some(value.to_string());
+    }
+
+    if is_keyword_like(value) {
+      return None;
+    }
+
+    if vocab {
+      if let Some(term) = self.terms.get(value) {
+        return term.id.clone();
+      }
+    }
+
+    if let Some(index) = colon(value) {
+      let (prefix, suffix): (&str, &str) = (&value[..index], &value[index + 1..]);
+
+      if prefix == "_" || suffix.starts_with("//") {
+        return Some(value.to_string());
+      }
+
+      if let Some(Term {
+        id: Some(iri),
+        prefix: true,
+        ..
+      }) = self.terms.get(prefix)
+      {
+        return Some(alloc::format!("{}{}", iri, suffix));
+      }
+
+      if is_absolute_iri(value) {
+        return Some(value.to_string());
+      }
+    }
+
+    if vocab {
+      if let Some(vocab) = self.vocab.as_deref() {
+        return Some(alloc::format!("{}{}", vocab, value));
+      }
+    }
+
+    if relative {
+      return Some(self.resolve(value));
+    }
+
+    Some(value.to_string())
+  }
+
+  // Resolves `value` against the base IRI; unresolvable values are returned
+  // as-is and rejected when converted to RDF.
+  fn resolve(&self, value: &str) -> String {
+    self
+      .base
+      .as_deref()
+      .and_then(|base| Url::parse(base).ok())
+      .and_then(|base| base.join(value).ok())
+      .map(String::from)
+      .unwrap_or_else(|| value.to_string())
+  }
+}
+
+// Returns the index of the first colon after the first character of `value`.
+fn colon(value: &str) -> Option<usize> {
+  value
+    .char_indices()
+    .skip(1)
+    .find(|(_, char)| *char == ':')
+    .map(|(index, _)| index)
+}
+
+// Returns the expanded form of a term definition that is only an IRI mapping.
+fn simple(id: Value) -> Map {
+  let mut definition: Map = Map::new();
+
+  definition.insert("@id".into(), id);
+  definition
+}
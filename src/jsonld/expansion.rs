@@ -0,0 +1,409 @@
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld::context::is_absolute_iri;
+use crate::jsonld::context::is_blank;
+use crate::jsonld::context::is_keyword;
+use crate::jsonld::context::Context;
+use crate::jsonld::context::Term;
+use crate::lib::*;
+use crate::utils::Value;
+
+type Map = serde_json::Map<String, Value>;
+
+const ERR_UTM: &str = "Undefined JSON-LD Term";
+const ERR_RIR: &str = "Relative JSON-LD IRI";
+const ERR_CKW: &str = "Colliding JSON-LD Keywords";
+const ERR_IID: &str = "Invalid JSON-LD @id Value";
+const ERR_ITV: &str = "Invalid JSON-LD @type Value";
+const ERR_IVO: &str = "Invalid JSON-LD Value Object";
+const ERR_ILV: &str = "Invalid JSON-LD Language Value";
+const ERR_ISO: &str = "Invalid JSON-LD Set or List Object";
+const ERR_FFV: &str = "Free-floating JSON-LD Value";
+const ERR_UFT: &str = "Unsupported JSON-LD Feature";
+
+const VALUE_KEYS: &[&str] = &["@index", "@language", "@type", "@value"];
+
+/// Expands `element` with the JSON-LD 1.1 expansion algorithm.
+///
+/// Expansion runs in "safe mode": instead of dropping data that does not
+/// expand, undefined terms, relative IRIs and free-floating values are
+/// rejected, so every signed property is covered by the canonical form.
+pub(crate) fn expand(context: &Context, element: &Value) -> Result<Vec<Value>> {
+  let expanded: Option<Value> = expand_element(context, None, element, false)?;
+
+  let expanded: Value = match expanded {
+    Some(Value::Object(mut object)) if object.len() == 1 && object.contains_key("@graph") => {
+      object.remove("@graph").unwrap_or(Value::Null)
+    }
+    Some(expanded) => expanded,
+    None => return Ok(Vec::new()),
+  };
+
+  Ok(into_array(expanded))
+}
+
+fn expand_element(
+  active: &Context,
+  property: Option<&str>,
+  element: &Value,
+  from_map: bool,
+) -> Result<Option<Value>> {
+  let definition: Option<&Term> = property.and_then(|property| active.term(property));
+  let scoped: Option<&Value> = definition.and_then(|term| term.context.as_ref());
+
+  match element {
+    Value::Null => Ok(None),
+    Value::Array(items) => {
+      let list: bool = definition.is_some_and(|term| term.has_container("@list"));
+      let mut output: Vec<Value> = Vec::new();
+
+      for item in items {
+        match expand_element(active, property, item, from_map)? {
+          Some(Value::Array(expanded)) if list => output.push(list_object(expanded)),
+          Some(Value::Array(expanded)) => output.extend(expanded),
+          Some(expanded) => output.push(expanded),
+          None => {}
+        }
+      }
+
+      Ok(Some(Value::Array(output)))
+    }
+    Value::Object(element) => expand_object(active, property, scoped, element, from_map),
+    scalar => {
+      if matches!(property, None | Some("@graph")) {
+        return Err(Error::message(ERR_FFV));
+      }
+
+      let active: Cow<'_, Context> = match scoped {
+        Some(scoped) => Cow::Owned(active.process(scoped, true, true)?),
+        None => Cow::Borrowed(active),
+      };
+
+      expand_value(&active, property.unwrap_or_default(), scalar).map(Some)
+    }
+  }
+}
+
+fn expand_object(
+  active: &Context,
+  property: Option<&str>,
+  scoped: Option<&Value>,
+  element: &Map,
+  from_map: bool,
+) -> Result<Option<Value>> {
+  let mut active: Cow<'_, Context> = Cow::Borrowed(active);
+
+  // Type-scoped contexts do not apply to nested node objects
+  if let Some(previous) = active.previous().filter(|_| !from_map) {
+    let value: bool = element
+      .keys()
+      .any(|key| active.expand_iri(key, false, true).as_deref() == Some("@value"));
+    let reference: bool = element.len() == 1
+      && element
+        .keys()
+        .all(|key| active.expand_iri(key, false, true).as_deref() == Some("@id"));
+
+    if !value && !reference {
+      active = Cow::Owned(previous.clone());
+    }
+  }
+
+  if let Some(scoped) = scoped {
+    active = Cow::Owned(active.process(scoped, true, true)?);
+  }
+
+  if let Some(context) = element.get("@context") {
+    active = Cow::Owned(active.process(context, false, true)?);
+  }
+
+  // Type values are expanded with the context before applying type-scoped
+  // contexts, which are applied in lexicographical order
+  let types: Context = active.clone().into_owned();
+
+  for (key, value) in element {
+    if types.expand_iri(key, false, true).as_deref() != Some("@type") {
+      continue;
+    }
+
+    let mut terms: Vec<&str> = match value {
+      Value::String(term) => alloc::vec![term.as_str()],
+      Value::Array(terms) => terms
+        .iter()
+        .map(Value::as_str)
+        .collect::<Option<_>>()
+        .ok_or_else(|| Error::message(ERR_ITV))?,
+      _ => return Err(Error::message(ERR_ITV)),
+    };
+
+    terms.sort_unstable();
+
+    for term in terms {
+      if let Some(context) = types.term(term).and_then(|term| term.context.as_ref()) {
+        active = Cow::Owned(active.process(context, false, false)?);
+      }
+    }
+  }
+
+  let mut result: Map = Map::new();
+
+  for (key, value) in element {
+    if key == "@context" {
+      continue;
+    }
+
+    let expanded: String = active
+      .expand_iri(key, false, true)
+      .filter(|iri| is_keyword(iri) || is_absolute_iri(iri) || is_blank(iri))
+      .ok_or_else(|| Error::message(ERR_UTM))?;
+
+    if is_keyword(&expanded) {
+      if result.contains_key(&expanded) {
+        return Err(Error::message(ERR_CKW));
+      }
+
+      let expanded_value: Value = match expanded.as_str() {
+        "@id" => {
+          let id: &str = value.as_str().ok_or_else(|| Error::message(ERR_IID))?;
+
+          Value::String(reference(&active, id, false)?)
+        }
+        "@type" => match value {
+          Value::String(term) => Value::String(expand_type(&types, term)?),
+          Value::Array(terms) => terms
+            .iter()
+            .map(|term| term.as_str().ok_or_else(|| Error::message(ERR_ITV)))
+            .map(|term| {
+              term
+                .and_then(|term| expand_type(&types, term))
+                .map(Value::String)
+            })
+            .collect::<Result<_>>()?,
+          _ => return Err(Error::message(ERR_ITV)),
+        },
+        "@graph" => {
+          let graph: Option<Value> = expand_element(&active, Some("@graph"), value, from_map)?;
+
+          Value::Array(graph.map(into_array).unwrap_or_default())
+        }
+        "@value" => {
+          let json: bool = result.get("@type").and_then(Value::as_str) == Some("@json");
+
+          if !json && (value.is_object() || value.is_array()) {
+            return Err(Error::message(ERR_IVO));
+          }
+
+          value.clone()
+        }
+        "@language" => match value {
+          Value::String(language) => Value::String(language.to_lowercase()),
+          _ => return Err(Error::message(ERR_ILV)),
+        },
+        "@index" => match value {
+          Value::String(_) => value.clone(),
+          _ => return Err(Error::message(ERR_IVO)),
+        },
+        "@list" => {
+          if matches!(property, None | Some("@graph")) {
+            return Err(Error::message(ERR_FFV));
+          }
+
+          let list: Option<Value> = expand_element(&active, property, value, from_map)?;
+
+          Value::Array(list.map(into_array).unwrap_or_default())
+        }
+        "@set" => match expand_element(&active, property, value, from_map)? {
+          Some(set) => set,
+          None => Value::Array(Vec::new()),
+        },
+        _ => return Err(Error::message(ERR_UFT)),
+      };
+
+      result.insert(expanded, expanded_value);
+
+      continue;
+    }
+
+    if is_blank(&expanded) {
+      return Err(Error::message(ERR_UTM));
+    }
+
+    let definition: Option<&Term> = active.term(key);
+
+    let expanded_value: Option<Value> =
+      if definition.and_then(|term| term.type_.as_deref()) == Some("@json") {
+        let mut object: Map = Map::new();
+
+        object.insert("@value".into(), value.clone());
+        object.insert("@type".into(), Value::from("@json"));
+
+        Some(Value::Object(object))
+      } else if definition.is_some_and(|term| {
+        ["@id", "@index", "@language", "@type"]
+          .iter()
+          .any(|container| term.has_container(container))
+      }) {
+        return Err(Error::message(ERR_UFT));
+      } else {
+        expand_element(&active, Some(key), value, false)?
+      };
+
+    let mut expanded_value: Value = match expanded_value {
+      Some(expanded_value) => expanded_value,
+      None => continue,
+    };
+
+    if definition.is_some_and(|term| term.has_container("@list")) && !is_list(&expanded_value) {
+      expanded_value = list_object(into_array(expanded_value));
+    }
+
+    if definition.is_some_and(|term| term.has_container("@graph")) {
+      expanded_value = into_array(expanded_value)
+        .into_iter()
+        .map(|item| {
+          let mut object: Map = Map::new();
+
+          object.insert("@graph".into(), Value::Array(into_array(item)));
+
+          Value::Object(object)
+        })
+        .collect();
+    }
+
+    match result.get_mut(&expanded) {
+      Some(Value::Array(items)) => items.extend(into_array(expanded_value)),
+      _ => {
+        result.insert(expanded, Value::Array(into_array(expanded_value)));
+      }
+    }
+  }
+
+  if result.contains_key("@value") {
+    if result.keys().any(|key| !VALUE_KEYS.contains(&key.as_str())) {
+      return Err(Error::message(ERR_IVO));
+    }
+
+    let json: bool = result.get("@type").and_then(Value::as_str) == Some("@json");
+
+    match result.get("@value") {
+      Some(Value::Null) => return Ok(None),
+      Some(Value::String(_)) => {}
+      Some(_) if result.contains_key("@language") && !json => return Err(Error::message(ERR_ILV)),
+      Some(_) => {}
+      None => unreachable!(),
+    }
+
+    if result.contains_key("@type") && result.contains_key("@language") {
+      return Err(Error::message(ERR_IVO));
+    }
+
+    if matches!(property, None | Some("@graph")) {
+      return Err(Error::message(ERR_FFV));
+    }
+  } else if result.contains_key("@set") || result.contains_key("@list") {
+    if result
+      .keys()
+      .any(|key| !matches!(key.as_str(), "@index" | "@list" | "@set"))
+    {
+      return Err(Error::message(ERR_ISO));
+    }
+
+    if let Some(set) = result.remove("@set") {
+      return Ok(Some(set));
+    }
+  } else if let Some(Value::String(type_)) = result.get("@type") {
+    let type_: Value = Value::String(type_.clone());
+
+    result.insert("@type".into(), Value::Array(alloc::vec![type_]));
+  }
+
+  if result.len() == 1 && result.contains_key("@language") {
+    return Ok(None);
+  }
+
+  // Drop free-floating node references and empty objects at the top level
+  if matches!(property, None | Some("@graph")) {
+    if result.contains_key("@list") {
+      return Err(Error::message(ERR_FFV));
+    }
+
+    if result.is_empty() || (result.len() == 1 && result.contains_key("@id")) {
+      return Ok(None);
+    }
+  }
+
+  Ok(Some(Value::Object(result)))
+}
+
+fn expand_value(active: &Context, property: &str, value: &Value) -> Result<Value> {
+  let definition: Option<&Term> = active.term(property);
+  let type_: Option<&str> = definition.and_then(|term| term.type_.as_deref());
+
+  let mut result: Map = Map::new();
+
+  match (type_, value) {
+    (Some("@id"), Value::String(id)) => {
+      result.insert("@id".into(), Value::String(reference(active, id, false)?));
+    }
+    (Some("@vocab"), Value::String(id)) => {
+      result.insert("@id".into(), Value::String(reference(active, id, true)?));
+    }
+    (Some(type_), _) if !matches!(type_, "@id" | "@vocab" | "@none") => {
+      result.insert("@value".into(), value.clone());
+      result.insert("@type".into(), Value::from(type_));
+    }
+    (_, Value::String(_)) => {
+      let language: Option<&str> = match definition.and_then(|term| term.language.as_ref()) {
+        Some(language) => language.as_deref(),
+        None => active.language(),
+      };
+
+      result.insert("@value".into(), value.clone());
+
+      if let Some(language) = language {
+        result.insert("@language".into(), Value::from(language));
+      }
+    }
+    _ => {
+      result.insert("@value".into(), value.clone());
+    }
+  }
+
+  Ok(Value::Object(result))
+}
+
+// Expands a node reference, which must resolve to an absolute IRI or a blank
+// node identifier.
+fn reference(active: &Context, value: &str, vocab: bool) -> Result<String> {
+  active
+    .expand_iri(value, true, vocab)
+    .filter(|iri| is_absolute_iri(iri) || is_blank(iri))
+    .ok_or_else(|| Error::message(ERR_RIR))
+}
+
+// Expands a type, which must be a defined term or an absolute IRI; types are
+// not resolved against the base IRI.
+fn expand_type(active: &Context, value: &str) -> Result<String> {
+  active
+    .expand_iri(value, false, true)
+    .filter(|iri| iri == "@json" || is_absolute_iri(iri) || is_blank(iri))
+    .ok_or_else(|| Error::message(ERR_UTM))
+}
+
+fn is_list(value: &Value) -> bool {
+  value.get("@list").is_some()
+}
+
+fn list_object(items: Vec<Value>) -> Value {
+  let mut object: Map = Map::new();
+
+  object.insert("@list".into(), Value::Array(items));
+
+  Value::Object(object)
+}
+
+fn into_array(value: Value) -> Vec<Value> {
+  match value {
+    Value::Array(items) => items,
+    value => alloc::vec![value],
+  }
+}
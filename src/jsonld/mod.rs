@@ -0,0 +1,232 @@
+//! A subset of JSON-LD 1.1 and the URDNA2015 canonicalization algorithm, as
+//! used by the Linked Data signature suites.
+//!
+//! Only the contexts embedded in [`contexts`] can be loaded, and documents
+//! that would lose data when converted to RDF are rejected.
+
+mod context;
+pub(crate) mod contexts;
+mod expansion;
+mod node_map;
+mod rdf;
+mod urdna2015;
+
+use serde::Serialize;
+use serde_json::to_value;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld::context::Context;
+use crate::lib::*;
+use crate::signature::SignatureData;
+use crate::utils::Value;
+
+const ERR_SI: &str = "Invalid Signature Input";
+const ERR_MPF: &str = "Missing Proof";
+const ERR_MCT: &str = "Missing JSON-LD Context";
+
+const PROOF: &str = "proof";
+const CONTEXT: &str = "@context";
+
+/// Returns the canonical N-Quads of the JSON-LD document `document`, with
+/// relative IRIs resolved against `base`.
+pub(crate) fn canonicalize(document: &Value, base: Option<&str>) -> Result<String> {
+  let expanded: Vec<Value> = expansion::expand(&Context::new(base), document)?;
+
+  urdna2015::canonicalize(&rdf::to_rdf(&expanded)?)
+}
+
+/// Returns the input signed by the URDNA2015-based suites: the SHA-256 hash of
+/// the canonical proof options followed by the SHA-256 hash of the canonical
+/// document.
+///
+/// `data` is a document with the signature at `proof`; the proof options are
+/// the proof without its signature value and with the `@context` of the
+/// document. Relative IRIs are resolved against the `id` of the document.
+pub(crate) fn signing_input<T>(data: &T) -> Result<Vec<u8>>
+where
+  T: Serialize,
+{
+  let mut document: Value = to_value(data).map_err(|_| Error::message(ERR_SI))?;
+  let object = document
+    .as_object_mut()
+    .ok_or_else(|| Error::message(ERR_SI))?;
+
+  let mut proof: Value = object
+    .remove(PROOF)
+    .ok_or_else(|| Error::message(ERR_MPF))?;
+  let context: Value = object
+    .get(CONTEXT)
+    .cloned()
+    .ok_or_else(|| Error::message(ERR_MCT))?;
+
+  let options = proof
+    .as_object_mut()
+    .ok_or_else(|| Error::message(ERR_MPF))?;
+
+  for key in SignatureData::KEYS {
+    options.remove(*key);
+  }
+
+  options.insert(CONTEXT.into(), context);
+
+  let base: Option<&str> = document.get("id").and_then(Value::as_str);
+  let mut input: Vec<u8> = Vec::with_capacity(64);
+
+  input.extend_from_slice(&Sha256::digest(canonicalize(&proof, base)?.as_bytes()));
+  input.extend_from_slice(&Sha256::digest(canonicalize(&document, base)?.as_bytes()));
+
+  Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_canonicalize() {
+    let document: Value = json!({
+      "@context": {
+        "ex": "https://example.com/",
+        "name": "ex:name",
+        "knows": {"@id": "ex:knows", "@type": "@id"},
+        "age": {"@id": "ex:age", "@type": "http://www.w3.org/2001/XMLSchema#integer"}
+      },
+      "@id": "https://example.com/alice",
+      "name": "Alice",
+      "age": "30",
+      "knows": {"name": "Bob", "knows": "https://example.com/alice"}
+    });
+
+    let expected: &str = "\
+<https://example.com/alice> <https://example.com/age> \"30\"^^<http://www.w3.org/2001/XMLSchema#integer> .\n\
+<https://example.com/alice> <https://example.com/knows> _:c14n0 .\n\
+<https://example.com/alice> <https://example.com/name> \"Alice\" .\n\
+_:c14n0 <https://example.com/knows> <https://example.com/alice> .\n\
+_:c14n0 <https://example.com/name> \"Bob\" .\n";
+
+    assert_eq!(canonicalize(&document, None).unwrap(), expected);
+  }
+
+  #[test]
+  fn test_canonicalize_blank_nodes() {
+    let context: Value =
+      json!({"ex": "https://example.com/", "p": {"@id": "ex:p", "@type": "@id"}, "q": "ex:q"});
+
+    let a: Value = json!({
+      "@context": context,
+      "@graph": [
+        {"@id": "_:x", "p": "_:y", "q": "1"},
+        {"@id": "_:y", "p": "_:z", "q": "2"},
+        {"@id": "_:z", "p": "_:x", "q": "3"}
+      ]
+    });
+
+    let b: Value = json!({
+      "@context": context,
+      "@graph": [
+        {"@id": "_:3", "q": "3", "p": "_:1"},
+        {"@id": "_:1", "q": "1", "p": "_:2"},
+        {"@id": "_:2", "q": "2", "p": "_:3"}
+      ]
+    });
+
+    assert_eq!(
+      canonicalize(&a, None).unwrap(),
+      canonicalize(&b, None).unwrap()
+    );
+
+    let c: Value = json!({
+      "@context": context,
+      "@graph": [
+        {"@id": "_:x", "p": "_:y"},
+        {"@id": "_:y", "p": "_:x"},
+        {"@id": "_:z", "p": "_:w"},
+        {"@id": "_:w", "p": "_:z"}
+      ]
+    });
+
+    let output: String = canonicalize(&c, None).unwrap();
+
+    assert_eq!(output.lines().count(), 4);
+    assert!(output.contains("_:c14n3"));
+  }
+
+  #[test]
+  fn test_canonicalize_safe_mode() {
+    let context: Value = json!({"ex": "https://example.com/", "name": "ex:name"});
+
+    // Undefined terms
+    assert!(canonicalize(
+      &json!({"@context": context, "name": "a", "other": "b"}),
+      None
+    )
+    .is_err());
+    // Relative IRIs without a base IRI
+    assert!(canonicalize(
+      &json!({"@context": context, "@id": "#a", "name": "a"}),
+      None
+    )
+    .is_err());
+    assert!(canonicalize(
+      &json!({"@context": context, "@id": "#a", "name": "a"}),
+      Some("https://example.com/")
+    )
+    .is_ok());
+    // Unknown remote contexts
+    assert!(canonicalize(
+      &json!({"@context": "https://example.com/context", "name": "a"}),
+      None
+    )
+    .is_err());
+  }
+
+  #[test]
+  fn test_canonicalize_protected() {
+    let document: Value = json!({
+      "@context": [contexts::DID_V1, {"controller": "https://example.com/controller"}],
+      "id": "did:example:123"
+    });
+
+    assert!(canonicalize(&document, None).is_err());
+  }
+
+  #[test]
+  fn test_signing_input() {
+    let document: Value = json!({
+      "@context": [contexts::DID_V1, contexts::SECP256K1_2019_V1],
+      "id": "did:example:123",
+      "proof": {
+        "type": "EcdsaSecp256k1Signature2019",
+        "verificationMethod": "#key-1",
+        "proofPurpose": "assertionMethod",
+        "created": "2021-01-01T00:00:00Z",
+        "jws": "a..b"
+      }
+    });
+
+    let input: Vec<u8> = signing_input(&document).unwrap();
+
+    assert_eq!(input.len(), 64);
+
+    let mut other: Value = document.clone();
+
+    other["proof"]["jws"] = Value::from("c..d");
+
+    assert_eq!(signing_input(&other).unwrap(), input);
+
+    other["proof"]["created"] = Value::from("2021-01-02T00:00:00Z");
+
+    assert_ne!(signing_input(&other).unwrap(), input);
+
+    let mut other: Value = document.clone();
+
+    other.as_object_mut().unwrap().remove("@context");
+
+    assert!(signing_input(&other).is_err());
+  }
+}
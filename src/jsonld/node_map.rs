@@ -0,0 +1,166 @@
+use crate::jsonld::context::is_blank;
+use crate::jsonld::urdna2015::Issuer;
+use crate::lib::*;
+use crate::utils::Value;
+
+type Map = serde_json::Map<String, Value>;
+
+/// The name of the default graph in a `NodeMap`.
+pub(crate) const DEFAULT: &str = "@default";
+
+/// Node objects of an expanded document by graph name and subject, with blank
+/// node identifiers relabeled.
+pub(crate) type NodeMap = BTreeMap<String, BTreeMap<String, Map>>;
+
+/// Returns the node map of the expanded document `elements`.
+pub(crate) fn node_map(elements: &[Value], issuer: &mut Issuer) -> NodeMap {
+  let mut map: NodeMap = NodeMap::new();
+
+  map.insert(DEFAULT.into(), BTreeMap::new());
+
+  for element in elements {
+    generate(element, &mut map, issuer, DEFAULT, None, None);
+  }
+
+  map
+}
+
+fn generate(
+  element: &Value,
+  map: &mut NodeMap,
+  issuer: &mut Issuer,
+  graph: &str,
+  subject: Option<(&str, &str)>,
+  mut list: Option<&mut Vec<Value>>,
+) {
+  let element: &Map = match element {
+    Value::Array(items) => {
+      for item in items {
+        generate(item, map, issuer, graph, subject, list.as_deref_mut());
+      }
+
+      return;
+    }
+    Value::Object(element) => element,
+    _ => return,
+  };
+
+  let mut element: Map = element.clone();
+
+  if let Some(Value::Array(types)) = element.get_mut("@type") {
+    for type_ in types.iter_mut() {
+      if let Some(label) = type_.as_str().filter(|type_| is_blank(type_)) {
+        *type_ = Value::String(issuer.issue(label));
+      }
+    }
+  }
+
+  if element.contains_key("@value") {
+    match list {
+      Some(list) => list.push(Value::Object(element)),
+      None => add(map, graph, subject, Value::Object(element), true),
+    }
+
+    return;
+  }
+
+  if let Some(items) = element.get("@list") {
+    let mut result: Vec<Value> = Vec::new();
+
+    generate(items, map, issuer, graph, subject, Some(&mut result));
+
+    let mut object: Map = Map::new();
+
+    object.insert("@list".into(), Value::Array(result));
+
+    match list {
+      Some(list) => list.push(Value::Object(object)),
+      None => add(map, graph, subject, Value::Object(object), false),
+    }
+
+    return;
+  }
+
+  let id: String = match element.remove("@id") {
+    Some(Value::String(id)) if is_blank(&id) => issuer.issue(&id),
+    Some(Value::String(id)) => id,
+    _ => issuer.issue_new(),
+  };
+
+  map
+    .entry(graph.into())
+    .or_default()
+    .entry(id.clone())
+    .or_insert_with(|| {
+      let mut node: Map = Map::new();
+
+      node.insert("@id".into(), Value::String(id.clone()));
+      node
+    });
+
+  if subject.is_some() {
+    let mut reference: Map = Map::new();
+
+    reference.insert("@id".into(), Value::String(id.clone()));
+
+    match list {
+      Some(list) => list.push(Value::Object(reference)),
+      None => add(map, graph, subject, Value::Object(reference), true),
+    }
+  }
+
+  if let Some(Value::Array(types)) = element.remove("@type") {
+    for type_ in types {
+      add(map, graph, Some((&id, "@type")), type_, true);
+    }
+  }
+
+  if let Some(graph) = element.remove("@graph") {
+    map.entry(id.clone()).or_default();
+
+    generate(&graph, map, issuer, &id, None, None);
+  }
+
+  element.remove("@index");
+
+  for (property, value) in element {
+    let node: &mut Map = node_mut(map, graph, &id);
+
+    if !node.contains_key(&property) {
+      node.insert(property.clone(), Value::Array(Vec::new()));
+    }
+
+    generate(&value, map, issuer, graph, Some((&id, &property)), None);
+  }
+}
+
+// Adds `value` to the values of `property` of `subject`.
+fn add(map: &mut NodeMap, graph: &str, subject: Option<(&str, &str)>, value: Value, unique: bool) {
+  let (subject, property): (&str, &str) = match subject {
+    Some(subject) => subject,
+    None => return,
+  };
+
+  let node: &mut Map = node_mut(map, graph, subject);
+
+  match node
+    .entry(property)
+    .or_insert_with(|| Value::Array(Vec::new()))
+  {
+    Value::Array(values) if !(unique && values.contains(&value)) => values.push(value),
+    _ => {}
+  }
+}
+
+fn node_mut<'a>(map: &'a mut NodeMap, graph: &str, subject: &str) -> &'a mut Map {
+  map
+    .entry(graph.into())
+    .or_default()
+    .entry(subject.into())
+    .or_insert_with(|| {
+      let mut node: Map = Map::new();
+
+      node.insert("@id".into(), Value::from(subject));
+      node
+    })
+}
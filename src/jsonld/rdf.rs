@@ -0,0 +1,351 @@
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld::context::is_absolute_iri;
+use crate::jsonld::context::is_blank;
+use crate::jsonld::node_map::node_map;
+use crate::jsonld::node_map::NodeMap;
+use crate::jsonld::node_map::DEFAULT;
+use crate::jsonld::urdna2015::Issuer;
+use crate::lib::*;
+use crate::utils::canonicalize;
+use crate::utils::Value;
+
+const ERR_IRT: &str = "Invalid RDF Term";
+const ERR_IRL: &str = "Invalid RDF Literal";
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const RDF_FIRST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#first";
+const RDF_REST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#rest";
+const RDF_NIL: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#nil";
+const RDF_JSON: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON";
+const RDF_LANG_STRING: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString";
+const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+const XSD_DOUBLE: &str = "http://www.w3.org/2001/XMLSchema#double";
+const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+
+/// An RDF term.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Term {
+  Iri(String),
+  Blank(String),
+  Literal {
+    value: String,
+    datatype: String,
+    language: Option<String>,
+  },
+}
+
+impl Term {
+  /// Returns the blank node identifier of the term, if any.
+  pub(crate) fn blank(&self) -> Option<&str> {
+    match self {
+      Self::Blank(label) => Some(label),
+      _ => None,
+    }
+  }
+
+  fn node(id: &str) -> Result<Self> {
+    if is_blank(id) {
+      Ok(Self::Blank(id.to_string()))
+    } else if is_absolute_iri(id) {
+      Ok(Self::Iri(id.to_string()))
+    } else {
+      Err(Error::message(ERR_IRT))
+    }
+  }
+}
+
+impl Display for Term {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    match self {
+      Self::Iri(iri) => write!(f, "<{}>", iri),
+      Self::Blank(label) => f.write_str(label),
+      Self::Literal {
+        value,
+        datatype,
+        language,
+      } => {
+        f.write_str("\"")?;
+
+        for char in value.chars() {
+          match char {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            char => write!(f, "{}", char)?,
+          }
+        }
+
+        f.write_str("\"")?;
+
+        match language {
+          Some(language) => write!(f, "@{}", language),
+          None if datatype == XSD_STRING => Ok(()),
+          None => write!(f, "^^<{}>", datatype),
+        }
+      }
+    }
+  }
+}
+
+/// An RDF quad; `graph` is `None` for the default graph.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Quad {
+  pub(crate) subject: Term,
+  pub(crate) predicate: Term,
+  pub(crate) object: Term,
+  pub(crate) graph: Option<Term>,
+}
+
+impl Quad {
+  /// Returns a copy of the quad with blank node identifiers replaced by `f`.
+  pub(crate) fn relabel<F>(&self, mut f: F) -> Self
+  where
+    F: FnMut(&str) -> String,
+  {
+    let mut relabel = |term: &Term| match term {
+      Term::Blank(label) => Term::Blank(f(label)),
+      term => term.clone(),
+    };
+
+    Self {
+      subject: relabel(&self.subject),
+      predicate: self.predicate.clone(),
+      object: relabel(&self.object),
+      graph: self.graph.as_ref().map(&mut relabel),
+    }
+  }
+}
+
+/// Formats the quad as a line of an N-Quads document.
+impl Display for Quad {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    write!(f, "{} {} {} ", self.subject, self.predicate, self.object)?;
+
+    if let Some(graph) = self.graph.as_ref() {
+      write!(f, "{} ", graph)?;
+    }
+
+    f.write_str(".\n")
+  }
+}
+
+/// Converts the expanded document `elements` to an RDF dataset.
+///
+/// Unlike the JSON-LD to RDF algorithm, relative IRIs and blank node
+/// predicates are rejected instead of being dropped.
+pub(crate) fn to_rdf(elements: &[Value]) -> Result<Vec<Quad>> {
+  let mut issuer: Issuer = Issuer::new("_:b");
+  let map: NodeMap = node_map(elements, &mut issuer);
+  let mut quads: Vec<Quad> = Vec::new();
+
+  for (name, graph) in map.iter() {
+    let name: Option<Term> = match name.as_str() {
+      DEFAULT => None,
+      name => Some(Term::node(name)?),
+    };
+
+    for (subject, node) in graph.iter() {
+      let subject: Term = Term::node(subject)?;
+
+      for (property, values) in node.iter() {
+        let values: &[Value] = values.as_array().map(Vec::as_slice).unwrap_or_default();
+
+        if property == "@type" {
+          for type_ in values {
+            let type_: &str = type_.as_str().ok_or_else(|| Error::message(ERR_IRT))?;
+
+            quads.push(Quad {
+              subject: subject.clone(),
+              predicate: Term::Iri(RDF_TYPE.into()),
+              object: Term::node(type_)?,
+              graph: name.clone(),
+            });
+          }
+
+          continue;
+        }
+
+        if property.starts_with('@') {
+          continue;
+        }
+
+        if !is_absolute_iri(property) {
+          return Err(Error::message(ERR_IRT));
+        }
+
+        for value in values {
+          let mut triples: Vec<(Term, Term, Term)> = Vec::new();
+          let object: Term = object(value, &mut triples, &mut issuer)?;
+
+          triples.insert(0, (subject.clone(), Term::Iri(property.clone()), object));
+
+          quads.extend(
+            triples
+              .into_iter()
+              .map(|(subject, predicate, object)| Quad {
+                subject,
+                predicate,
+                object,
+                graph: name.clone(),
+              }),
+          );
+        }
+      }
+    }
+  }
+
+  Ok(quads)
+}
+
+// Converts a node, list or value object to an RDF term, adding the triples
+// of lists to `triples`.
+fn object(
+  value: &Value,
+  triples: &mut Vec<(Term, Term, Term)>,
+  issuer: &mut Issuer,
+) -> Result<Term> {
+  if let Some(items) = value.get("@list") {
+    let items: &[Value] = items.as_array().map(Vec::as_slice).unwrap_or_default();
+
+    return list(items, triples, issuer);
+  }
+
+  if value.get("@value").is_none() {
+    let id: &str = value
+      .get("@id")
+      .and_then(Value::as_str)
+      .ok_or_else(|| Error::message(ERR_IRT))?;
+
+    return Term::node(id);
+  }
+
+  literal(value)
+}
+
+fn list(
+  items: &[Value],
+  triples: &mut Vec<(Term, Term, Term)>,
+  issuer: &mut Issuer,
+) -> Result<Term> {
+  let nodes: Vec<Term> = items
+    .iter()
+    .map(|_| Term::Blank(issuer.issue_new()))
+    .collect();
+
+  for (index, item) in items.iter().enumerate() {
+    let mut nested: Vec<(Term, Term, Term)> = Vec::new();
+    let first: Term = object(item, &mut nested, issuer)?;
+    let rest: Term = nodes
+      .get(index + 1)
+      .cloned()
+      .unwrap_or_else(|| Term::Iri(RDF_NIL.into()));
+
+    triples.push((nodes[index].clone(), Term::Iri(RDF_FIRST.into()), first));
+    triples.extend(nested);
+    triples.push((nodes[index].clone(), Term::Iri(RDF_REST.into()), rest));
+  }
+
+  Ok(
+    nodes
+      .into_iter()
+      .next()
+      .unwrap_or_else(|| Term::Iri(RDF_NIL.into())),
+  )
+}
+
+fn literal(value: &Value) -> Result<Term> {
+  let datatype: Option<&str> = value.get("@type").and_then(Value::as_str);
+  let language: Option<&str> = value.get("@language").and_then(Value::as_str);
+  let data: &Value = value.get("@value").unwrap_or(&Value::Null);
+
+  if datatype.is_some_and(|datatype| datatype != "@json" && !is_absolute_iri(datatype)) {
+    return Err(Error::message(ERR_IRL));
+  }
+
+  let (value, default): (String, &str) = match (datatype, data) {
+    (Some("@json"), data) => {
+      let data: Vec<u8> = canonicalize(data)?;
+      let data: String = String::from_utf8(data).map_err(|_| Error::message(ERR_IRL))?;
+
+      return Ok(Term::Literal {
+        value: data,
+        datatype: RDF_JSON.into(),
+        language: None,
+      });
+    }
+    (_, Value::Bool(data)) => (data.to_string(), XSD_BOOLEAN),
+    (datatype, Value::Number(number)) => {
+      let double: bool = datatype == Some(XSD_DOUBLE);
+
+      match (number.as_i64(), number.as_u64(), number.as_f64()) {
+        (Some(integer), _, _) if !double => (integer.to_string(), XSD_INTEGER),
+        (_, Some(integer), _) if !double => (integer.to_string(), XSD_INTEGER),
+        (_, _, Some(float)) if !double && float.fract() == 0.0 && float.abs() < 1e21 => {
+          (alloc::format!("{:.0}", float), XSD_INTEGER)
+        }
+        (_, _, Some(float)) if float.is_finite() => (format_double(float), XSD_DOUBLE),
+        _ => return Err(Error::message(ERR_IRL)),
+      }
+    }
+    (_, Value::String(data)) => (data.clone(), XSD_STRING),
+    _ => return Err(Error::message(ERR_IRL)),
+  };
+
+  if let Some(language) = language {
+    return Ok(Term::Literal {
+      value,
+      datatype: RDF_LANG_STRING.into(),
+      language: Some(language.into()),
+    });
+  }
+
+  Ok(Term::Literal {
+    value,
+    datatype: datatype.unwrap_or(default).into(),
+    language: None,
+  })
+}
+
+// Formats `value` in the canonical lexical form of `xsd:double`, e.g. `1.1E0`.
+fn format_double(value: f64) -> String {
+  let formatted: String = alloc::format!("{:.15e}", value);
+  let (mantissa, exponent): (&str, &str) = formatted.split_once('e').unwrap_or((&formatted, "0"));
+  let mantissa: &str = mantissa.trim_end_matches('0');
+
+  if mantissa.ends_with('.') {
+    alloc::format!("{}0E{}", mantissa, exponent)
+  } else {
+    alloc::format!("{}E{}", mantissa, exponent)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_format_double() {
+    assert_eq!(format_double(1.1), "1.1E0");
+    assert_eq!(format_double(-0.5), "-5.0E-1");
+    assert_eq!(format_double(1e21), "1.0E21");
+    assert_eq!(format_double(123.456), "1.23456E2");
+  }
+
+  #[test]
+  fn test_literal_escape() {
+    let term: Term = Term::Literal {
+      value: "a\"b\\c\nd\re\tf".into(),
+      datatype: XSD_STRING.into(),
+      language: None,
+    };
+
+    assert_eq!(term.to_string(), "\"a\\\"b\\\\c\\nd\\re\tf\"");
+  }
+}
@@ -0,0 +1,360 @@
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld::rdf::Quad;
+use crate::jsonld::rdf::Term;
+use crate::lib::*;
+
+const ERR_CLE: &str = "Canonicalization Limit Exceeded";
+
+// The maximum number of permutations and N-degree hashes computed for a
+// dataset; blank nodes with many indistinguishable neighbours otherwise take
+// factorial time to canonicalize.
+const MAX_STEPS: usize = 1 << 14;
+
+/// Issues blank node identifiers with a prefix and counter, in order.
+#[derive(Clone, Debug)]
+pub(crate) struct Issuer {
+  prefix: &'static str,
+  issued: BTreeMap<String, String>,
+  order: Vec<String>,
+}
+
+impl Issuer {
+  pub(crate) fn new(prefix: &'static str) -> Self {
+    Self {
+      prefix,
+      issued: BTreeMap::new(),
+      order: Vec::new(),
+    }
+  }
+
+  /// Returns the identifier issued for `existing`, issuing a new one if
+  /// needed.
+  pub(crate) fn issue(&mut self, existing: &str) -> String {
+    if let Some(issued) = self.issued.get(existing) {
+      return issued.clone();
+    }
+
+    let issued: String = alloc::format!("{}{}", self.prefix, self.order.len());
+
+    self.issued.insert(existing.to_string(), issued.clone());
+    self.order.push(existing.to_string());
+
+    issued
+  }
+
+  /// Issues an identifier not associated with an existing identifier.
+  pub(crate) fn issue_new(&mut self) -> String {
+    let existing: String = alloc::format!("\u{0}{}", self.order.len());
+
+    self.issue(&existing)
+  }
+
+  fn get(&self, existing: &str) -> Option<&str> {
+    self.issued.get(existing).map(String::as_str)
+  }
+}
+
+/// Canonicalizes `quads` with the URDNA2015 algorithm, returning the sorted
+/// N-Quads document of the canonical dataset.
+///
+/// # Errors
+///
+/// Fails if canonicalization would exceed a fixed amount of work, which only
+/// happens for datasets with many indistinguishable blank nodes.
+pub(crate) fn canonicalize(quads: &[Quad]) -> Result<String> {
+  let mut state: State<'_> = State::new(quads);
+
+  state.run()?;
+
+  let mut lines: Vec<String> = quads
+    .iter()
+    .map(|quad| {
+      quad
+        .relabel(|label| state.canonical.get(label).unwrap_or(label).to_string())
+        .to_string()
+    })
+    .collect();
+
+  lines.sort_unstable();
+  lines.dedup();
+
+  Ok(lines.concat())
+}
+
+struct State<'a> {
+  quads: &'a [Quad],
+  blanks: BTreeMap<String, Vec<usize>>,
+  hashes: BTreeMap<String, String>,
+  canonical: Issuer,
+  steps: usize,
+}
+
+impl<'a> State<'a> {
+  fn new(quads: &'a [Quad]) -> Self {
+    let mut blanks: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+
+    for (index, quad) in quads.iter().enumerate() {
+      for term in components(quad) {
+        if let Some(label) = term.blank() {
+          let indices: &mut Vec<usize> = blanks.entry(label.to_string()).or_default();
+
+          if indices.last() != Some(&index) {
+            indices.push(index);
+          }
+        }
+      }
+    }
+
+    Self {
+      quads,
+      blanks,
+      hashes: BTreeMap::new(),
+      canonical: Issuer::new("_:c14n"),
+      steps: 0,
+    }
+  }
+
+  fn run(&mut self) -> Result<()> {
+    let mut pending: BTreeSet<String> = self.blanks.keys().cloned().collect();
+    let mut hash_to_blanks: BTreeMap<String, Vec<String>>;
+
+    loop {
+      hash_to_blanks = BTreeMap::new();
+
+      for label in pending.iter() {
+        let hash: String = self.hash_first_degree(label);
+
+        hash_to_blanks.entry(hash).or_default().push(label.clone());
+      }
+
+      let mut simple: bool = false;
+
+      for labels in hash_to_blanks.values().filter(|labels| labels.len() == 1) {
+        self.canonical.issue(&labels[0]);
+        pending.remove(&labels[0]);
+        simple = true;
+      }
+
+      if !simple {
+        break;
+      }
+    }
+
+    for labels in hash_to_blanks.into_values() {
+      let mut results: Vec<(String, Issuer)> = Vec::new();
+
+      for label in labels {
+        if self.canonical.get(&label).is_some() {
+          continue;
+        }
+
+        let mut issuer: Issuer = Issuer::new("_:b");
+
+        issuer.issue(&label);
+        results.push(self.hash_n_degree(&label, issuer)?);
+      }
+
+      results.sort_by(|a, b| a.0.cmp(&b.0));
+
+      for (_, issuer) in results {
+        for label in issuer.order.iter() {
+          self.canonical.issue(label);
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  fn hash_first_degree(&mut self, label: &str) -> String {
+    if let Some(hash) = self.hashes.get(label) {
+      return hash.clone();
+    }
+
+    let mut lines: Vec<String> = self.blanks[label]
+      .iter()
+      .map(|index| {
+        self.quads[*index]
+          .relabel(|other| if other == label { "_:a" } else { "_:z" }.to_string())
+          .to_string()
+      })
+      .collect();
+
+    lines.sort_unstable();
+
+    let hash: String = sha256(&lines.concat());
+
+    self.hashes.insert(label.to_string(), hash.clone());
+
+    hash
+  }
+
+  fn hash_related(
+    &mut self,
+    related: &str,
+    quad: &Quad,
+    issuer: &Issuer,
+    position: &str,
+  ) -> String {
+    let identifier: String = match self.canonical.get(related).or_else(|| issuer.get(related)) {
+      Some(identifier) => identifier.to_string(),
+      None => self.hash_first_degree(related),
+    };
+
+    let predicate: String = match position {
+      "g" => String::new(),
+      _ => quad.predicate.to_string(),
+    };
+
+    sha256(&alloc::format!("{}{}{}", position, predicate, identifier))
+  }
+
+  fn hash_n_degree(&mut self, label: &str, mut issuer: Issuer) -> Result<(String, Issuer)> {
+    self.step()?;
+
+    let mut related: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for index in self.blanks[label].clone() {
+      let quad: &Quad = &self.quads[index];
+
+      let positions: [(&str, Option<&Term>); 3] = [
+        ("s", Some(&quad.subject)),
+        ("o", Some(&quad.object)),
+        ("g", quad.graph.as_ref()),
+      ];
+
+      for (position, term) in positions {
+        if let Some(other) = term.and_then(Term::blank).filter(|other| *other != label) {
+          let hash: String = self.hash_related(other, quad, &issuer, position);
+
+          related.entry(hash).or_default().push(other.to_string());
+        }
+      }
+    }
+
+    let mut data: String = String::new();
+
+    for (hash, mut labels) in related {
+      data.push_str(&hash);
+
+      let mut chosen: Option<(String, Issuer)> = None;
+
+      labels.sort_unstable();
+
+      loop {
+        self.step()?;
+
+        if let Some(candidate) = self.hash_path(
+          &labels,
+          &issuer,
+          chosen.as_ref().map(|(path, _)| path.as_str()),
+        )? {
+          if chosen.as_ref().is_none_or(|(path, _)| candidate.0 < *path) {
+            chosen = Some(candidate);
+          }
+        }
+
+        if !next_permutation(&mut labels) {
+          break;
+        }
+      }
+
+      if let Some((path, chosen)) = chosen {
+        data.push_str(&path);
+        issuer = chosen;
+      }
+    }
+
+    Ok((sha256(&data), issuer))
+  }
+
+  // Returns the path of a permutation of related blank nodes, or `None` if
+  // the path can not be shorter than the `chosen` path.
+  fn hash_path(
+    &mut self,
+    labels: &[String],
+    issuer: &Issuer,
+    chosen: Option<&str>,
+  ) -> Result<Option<(String, Issuer)>> {
+    let mut issuer: Issuer = issuer.clone();
+    let mut path: String = String::new();
+    let mut recursion: Vec<&str> = Vec::new();
+
+    let prune =
+      |path: &str| chosen.is_some_and(|chosen| path.len() >= chosen.len() && path > chosen);
+
+    for label in labels {
+      match self.canonical.get(label) {
+        Some(canonical) => path.push_str(canonical),
+        None => {
+          if issuer.get(label).is_none() {
+            recursion.push(label);
+          }
+
+          path.push_str(&issuer.issue(label));
+        }
+      }
+
+      if prune(&path) {
+        return Ok(None);
+      }
+    }
+
+    for label in recursion {
+      let (hash, result): (String, Issuer) = self.hash_n_degree(label, issuer.clone())?;
+
+      path.push_str(&issuer.issue(label));
+      path.push('<');
+      path.push_str(&hash);
+      path.push('>');
+      issuer = result;
+
+      if prune(&path) {
+        return Ok(None);
+      }
+    }
+
+    Ok(Some((path, issuer)))
+  }
+
+  fn step(&mut self) -> Result<()> {
+    self.steps += 1;
+
+    if self.steps > MAX_STEPS {
+      return Err(Error::message(ERR_CLE));
+    }
+
+    Ok(())
+  }
+}
+
+fn components(quad: &Quad) -> impl Iterator<Item = &Term> {
+  IntoIterator::into_iter([Some(&quad.subject), Some(&quad.object), quad.graph.as_ref()]).flatten()
+}
+
+// Rearranges `items` into the next lexicographical permutation, returning
+// `false` once all permutations have been visited.
+fn next_permutation(items: &mut [String]) -> bool {
+  let pivot: usize = match items.windows(2).rposition(|pair| pair[0] < pair[1]) {
+    Some(pivot) => pivot,
+    None => return false,
+  };
+
+  let swap: usize = items
+    .iter()
+    .rposition(|item| *item > items[pivot])
+    .unwrap_or(pivot);
+
+  items.swap(pivot, swap);
+  items[pivot + 1..].reverse();
+
+  true
+}
+
+fn sha256(data: &str) -> String {
+  hex::encode(Sha256::digest(data.as_bytes()))
+}
@@ -0,0 +1,94 @@
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::str::FromStr;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::Value;
+use crate::verification::MethodData;
+use crate::verification::MethodType;
+
+const ERR_UJA: &str = "Unsupported JWS Algorithm";
+const ERR_IJA: &str = "Invalid JWS Algorithm";
+
+/// Supported JSON Web Signature algorithms.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum JwsAlgorithm {
+  /// ECDSA using P-256 and SHA-256.
+  ES256,
+  /// ECDSA using secp256k1 and SHA-256.
+  ES256K,
+  /// ECDSA using secp256k1 and SHA-256, with a recoverable signature.
+  #[serde(rename = "ES256K-R")]
+  ES256KR,
+}
+
+impl JwsAlgorithm {
+  pub const fn as_str(self) -> &'static str {
+    match self {
+      Self::ES256 => "ES256",
+      Self::ES256K => "ES256K",
+      Self::ES256KR => "ES256K-R",
+    }
+  }
+
+  /// Returns the elliptic curve of the algorithm, as named by JWK `crv`.
+  pub const fn curve(self) -> &'static str {
+    match self {
+      Self::ES256 => "P-256",
+      Self::ES256K | Self::ES256KR => "secp256k1",
+    }
+  }
+
+  /// Checks that the algorithm can be used with keys of the given method.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the key type or JWK parameters do not support the algorithm.
+  pub fn check_method(self, key_type: MethodType, key_data: &MethodData) -> Result<()> {
+    let valid: bool = match key_type {
+      MethodType::EcdsaSecp256k1VerificationKey2019 => {
+        matches!(self, Self::ES256K | Self::ES256KR)
+      }
+      MethodType::EcdsaSecp256k1RecoveryMethod2020 => self == Self::ES256KR,
+      MethodType::JsonWebKey2020 | MethodType::JwsVerificationKey2020 => match key_data {
+        MethodData::PublicKeyJwk(jwk) => {
+          let crv: Option<&Value> = jwk.get("crv");
+          let alg: Option<&Value> = jwk.get("alg");
+
+          crv.and_then(Value::as_str) == Some(self.curve())
+            && alg.is_none_or(|alg| alg.as_str() == Some(self.as_str()))
+        }
+        _ => false,
+      },
+      _ => false,
+    };
+
+    if valid {
+      Ok(())
+    } else {
+      Err(Error::message(ERR_IJA))
+    }
+  }
+}
+
+impl Display for JwsAlgorithm {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    f.write_str(self.as_str())
+  }
+}
+
+impl FromStr for JwsAlgorithm {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    match string {
+      "ES256" => Ok(Self::ES256),
+      "ES256K" => Ok(Self::ES256K),
+      "ES256K-R" => Ok(Self::ES256KR),
+      _ => Err(Error::message(ERR_UJA)),
+    }
+  }
+}
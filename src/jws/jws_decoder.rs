@@ -0,0 +1,253 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use serde_json::from_slice;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jws::JwsHeader;
+use crate::lib::*;
+
+const ERR_IJ: &str = "Invalid JWS";
+const ERR_IJH: &str = "Invalid JWS Header";
+const ERR_IJP: &str = "Invalid JWS Payload";
+const ERR_MJP: &str = "Missing JWS Payload";
+
+/// A parsed compact or detached JSON Web Signature.
+#[derive(Clone, Debug)]
+pub struct DecodedJws<'a> {
+  header: JwsHeader,
+  encoded: &'a str,
+  payload: Option<&'a str>,
+  signature: Vec<u8>,
+}
+
+impl<'a> DecodedJws<'a> {
+  /// Parses a compact JWS, or a detached JWS if the payload segment is empty.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the JWS is malformed or the protected header is invalid.
+  pub fn decode(jws: &'a str) -> Result<Self> {
+    let mut split: core::str::Split<'a, char> = jws.split('.');
+
+    let (encoded, payload, signature): (&str, &str, &str) =
+      match (split.next(), split.next(), split.next(), split.next()) {
+        (Some(header), Some(payload), Some(signature), None) => (header, payload, signature),
+        _ => return Err(Error::message(ERR_IJ)),
+      };
+
+    let header: JwsHeader = URL_SAFE_NO_PAD
+      .decode(encoded)
+      .ok()
+      .and_then(|header| from_slice(&header).ok())
+      .ok_or_else(|| Error::message(ERR_IJH))?;
+
+    header.validate()?;
+
+    let signature: Vec<u8> = URL_SAFE_NO_PAD
+      .decode(signature)
+      .map_err(|_| Error::message(ERR_IJ))?;
+
+    Ok(Self {
+      header,
+      encoded,
+      payload: Some(payload).filter(|payload| !payload.is_empty()),
+      signature,
+    })
+  }
+
+  /// Parses a detached JWS with an unencoded payload (RFC 7797), as used by
+  /// Linked Data signature suites.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the JWS is malformed, is not detached, or has an encoded payload.
+  pub fn decode_detached(jws: &'a str) -> Result<Self> {
+    let this: Self = Self::decode(jws)?;
+
+    if !this.is_detached() {
+      return Err(Error::message(ERR_IJ));
+    }
+
+    if this.header.is_encoded() {
+      return Err(Error::message(ERR_IJH));
+    }
+
+    Ok(this)
+  }
+
+  /// Returns the protected header.
+  pub const fn header(&self) -> &JwsHeader {
+    &self.header
+  }
+
+  /// Returns `true` if the JWS does not contain the payload.
+  pub const fn is_detached(&self) -> bool {
+    self.payload.is_none()
+  }
+
+  /// Returns the raw signature.
+  pub fn signature(&self) -> &[u8] {
+    &self.signature
+  }
+
+  /// Returns the decoded payload of a compact JWS.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the JWS is detached or the payload is not valid base64url.
+  pub fn payload(&self) -> Result<Cow<'a, [u8]>> {
+    let payload: &str = self.payload.ok_or_else(|| Error::message(ERR_MJP))?;
+
+    if self.header.is_encoded() {
+      URL_SAFE_NO_PAD
+        .decode(payload)
+        .map(Cow::Owned)
+        .map_err(|_| Error::message(ERR_IJP))
+    } else {
+      Ok(Cow::Borrowed(payload.as_bytes()))
+    }
+  }
+
+  /// Returns the JWS signing input.
+  ///
+  /// The `detached` payload must be provided if, and only if, the JWS is
+  /// detached.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the payload is missing or unexpected.
+  pub fn signing_input(&self, detached: Option<&[u8]>) -> Result<Vec<u8>> {
+    let mut input: Vec<u8> = self.encoded.as_bytes().to_vec();
+
+    input.push(b'.');
+
+    match (self.payload, detached) {
+      (Some(payload), None) => {
+        input.extend_from_slice(payload.as_bytes());
+      }
+      (None, Some(payload)) if self.header.is_encoded() => {
+        input.extend_from_slice(URL_SAFE_NO_PAD.encode(payload).as_bytes());
+      }
+      (None, Some(payload)) => {
+        input.extend_from_slice(payload);
+      }
+      (None, None) => return Err(Error::message(ERR_MJP)),
+      (Some(_), Some(_)) => return Err(Error::message(ERR_IJP)),
+    }
+
+    Ok(input)
+  }
+
+  /// Verifies the signature with the `public` key.
+  ///
+  /// See `DecodedJws::signing_input` for the requirements of `detached`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the payload is missing or the signature is invalid.
+  #[cfg(feature = "ecdsa")]
+  pub fn verify(&self, detached: Option<&[u8]>, public: &[u8]) -> Result<()> {
+    let input: Vec<u8> = self.signing_input(detached)?;
+
+    crate::suites::ecdsa::verify(self.header.alg, public, &input, &self.signature)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::jws::JwsAlgorithm;
+  use crate::jws::JwsEncoder;
+
+  const PAYLOAD: &[u8] = b"{\"hello\":\"world\"}";
+
+  fn encoder(header: JwsHeader) -> JwsEncoder {
+    JwsEncoder::new(header).unwrap()
+  }
+
+  #[test]
+  fn test_compact() {
+    for header in [
+      JwsHeader::new(JwsAlgorithm::ES256),
+      JwsHeader::new_unencoded(JwsAlgorithm::ES256),
+    ] {
+      let encoder: JwsEncoder = encoder(header);
+      let jws: String = encoder.encode_compact(PAYLOAD, &[1, 2, 3]).unwrap();
+      let decoded: DecodedJws<'_> = DecodedJws::decode(&jws).unwrap();
+
+      assert!(!decoded.is_detached());
+      assert_eq!(decoded.header(), encoder.header());
+      assert_eq!(decoded.payload().unwrap(), PAYLOAD);
+      assert_eq!(decoded.signature(), &[1, 2, 3]);
+      assert_eq!(
+        decoded.signing_input(None).unwrap(),
+        encoder.signing_input(PAYLOAD)
+      );
+      assert!(decoded.signing_input(Some(PAYLOAD)).is_err());
+      assert!(DecodedJws::decode_detached(&jws).is_err());
+    }
+  }
+
+  #[test]
+  fn test_detached() {
+    let encoder: JwsEncoder = encoder(JwsHeader::new_unencoded(JwsAlgorithm::ES256K));
+    let jws: String = encoder.encode_detached(&[1, 2, 3]);
+    let decoded: DecodedJws<'_> = DecodedJws::decode_detached(&jws).unwrap();
+
+    assert!(jws.contains(".."));
+    assert!(decoded.is_detached());
+    assert!(decoded.payload().is_err());
+    assert!(decoded.signing_input(None).is_err());
+    assert_eq!(
+      decoded.signing_input(Some(PAYLOAD)).unwrap(),
+      encoder.signing_input(PAYLOAD)
+    );
+
+    let encoder: JwsEncoder = self::encoder(JwsHeader::new(JwsAlgorithm::ES256K));
+    let jws: String = encoder.encode_detached(&[1, 2, 3]);
+
+    assert!(DecodedJws::decode(&jws).is_ok());
+    assert!(DecodedJws::decode_detached(&jws).is_err());
+  }
+
+  #[test]
+  fn test_invalid() {
+    let encoder: JwsEncoder = encoder(JwsHeader::new_unencoded(JwsAlgorithm::ES256K));
+
+    assert!(encoder.encode_compact(b"a.b", &[1]).is_err());
+    assert!(DecodedJws::decode("abc").is_err());
+    assert!(DecodedJws::decode("a.b.c.d").is_err());
+    assert!(DecodedJws::decode("eyJhbGciOiJub25lIn0..AQ").is_err());
+
+    let mut header: JwsHeader = JwsHeader::new(JwsAlgorithm::ES256K);
+
+    header.b64 = Some(false);
+
+    assert!(JwsEncoder::new(header).is_err());
+  }
+
+  #[cfg(feature = "ecdsa")]
+  #[test]
+  fn test_sign_verify() {
+    let secret: [u8; 32] = [7; 32];
+    let public = k256::ecdsa::SigningKey::from_slice(&secret)
+      .unwrap()
+      .verifying_key()
+      .to_encoded_point(true);
+
+    let encoder: JwsEncoder = encoder(JwsHeader::new(JwsAlgorithm::ES256K));
+    let jws: String = encoder.sign_compact(PAYLOAD, &secret).unwrap();
+
+    assert!(DecodedJws::decode(&jws)
+      .unwrap()
+      .verify(None, public.as_bytes())
+      .is_ok());
+
+    let jws: String = encoder.sign_detached(PAYLOAD, &secret).unwrap();
+    let decoded: DecodedJws<'_> = DecodedJws::decode(&jws).unwrap();
+
+    assert!(decoded.verify(Some(PAYLOAD), public.as_bytes()).is_ok());
+    assert!(decoded.verify(Some(b"{}"), public.as_bytes()).is_err());
+  }
+}
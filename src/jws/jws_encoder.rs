@@ -0,0 +1,118 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jws::JwsHeader;
+use crate::lib::*;
+
+const ERR_IJP: &str = "Invalid JWS Payload";
+
+/// Creates compact and detached JSON Web Signatures with a protected header.
+#[derive(Clone, Debug)]
+pub struct JwsEncoder {
+  header: JwsHeader,
+  encoded: String,
+}
+
+impl JwsEncoder {
+  /// Creates a new `JwsEncoder` with the given protected header.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the header is invalid or cannot be serialized.
+  pub fn new(header: JwsHeader) -> Result<Self> {
+    header.validate()?;
+
+    let encoded: String = header.encode()?;
+
+    Ok(Self { header, encoded })
+  }
+
+  /// Returns the protected header.
+  pub const fn header(&self) -> &JwsHeader {
+    &self.header
+  }
+
+  /// Returns the JWS signing input of `payload`.
+  pub fn signing_input(&self, payload: &[u8]) -> Vec<u8> {
+    let mut input: Vec<u8> = self.encoded.as_bytes().to_vec();
+
+    input.push(b'.');
+
+    if self.header.is_encoded() {
+      input.extend_from_slice(URL_SAFE_NO_PAD.encode(payload).as_bytes());
+    } else {
+      input.extend_from_slice(payload);
+    }
+
+    input
+  }
+
+  /// Returns a compact JWS of `payload` with the given raw `signature`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the payload is unencoded and is not a valid UTF-8 string without
+  /// a `.` character (RFC 7797, section 5.2).
+  pub fn encode_compact(&self, payload: &[u8], signature: &[u8]) -> Result<String> {
+    let payload: Cow<'_, str> = if self.header.is_encoded() {
+      Cow::Owned(URL_SAFE_NO_PAD.encode(payload))
+    } else {
+      match core::str::from_utf8(payload) {
+        Ok(payload) if !payload.contains('.') => Cow::Borrowed(payload),
+        _ => return Err(Error::message(ERR_IJP)),
+      }
+    };
+
+    Ok(self.encode(&payload, signature))
+  }
+
+  /// Returns a detached JWS (with an empty payload) with the given raw
+  /// `signature`.
+  pub fn encode_detached(&self, signature: &[u8]) -> String {
+    self.encode("", signature)
+  }
+
+  /// Signs `payload` with the `secret` key; returns a compact JWS.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the payload cannot be represented or signing fails.
+  #[cfg(feature = "ecdsa")]
+  pub fn sign_compact(&self, payload: &[u8], secret: &[u8]) -> Result<String> {
+    let signature: Vec<u8> = self.sign(payload, secret)?;
+
+    self.encode_compact(payload, &signature)
+  }
+
+  /// Signs `payload` with the `secret` key; returns a detached JWS.
+  ///
+  /// # Errors
+  ///
+  /// Fails if signing fails.
+  #[cfg(feature = "ecdsa")]
+  pub fn sign_detached(&self, payload: &[u8], secret: &[u8]) -> Result<String> {
+    self
+      .sign(payload, secret)
+      .map(|signature| self.encode_detached(&signature))
+  }
+
+  #[cfg(feature = "ecdsa")]
+  fn sign(&self, payload: &[u8], secret: &[u8]) -> Result<Vec<u8>> {
+    crate::suites::ecdsa::sign(self.header.alg, secret, &self.signing_input(payload))
+  }
+
+  fn encode(&self, payload: &str, signature: &[u8]) -> String {
+    let signature: String = URL_SAFE_NO_PAD.encode(signature);
+    let mut output: String =
+      String::with_capacity(self.encoded.len() + payload.len() + signature.len() + 2);
+
+    output.push_str(&self.encoded);
+    output.push('.');
+    output.push_str(payload);
+    output.push('.');
+    output.push_str(&signature);
+    output
+  }
+}
@@ -0,0 +1,239 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use did_url::DID;
+use serde_json::to_vec;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jws::JwsAlgorithm;
+use crate::lib::*;
+use crate::verification::MethodWrap;
+
+const ERR_IJH: &str = "Invalid JWS Header";
+const ERR_UJC: &str = "Unsupported JWS Critical Header";
+const ERR_IJK: &str = "Invalid JWS Key Id";
+
+// Header parameters understood by this implementation when listed in `crit`.
+const CRITICAL: &[&str] = &["b64"];
+
+/// The protected header of a JSON Web Signature.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct JwsHeader {
+  /// The algorithm used to sign the JWS.
+  pub alg: JwsAlgorithm,
+  /// The identifier of the key used to sign the JWS.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub kid: Option<String>,
+  /// The media type of the complete JWS.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub typ: Option<String>,
+  /// The media type of the JWS payload.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cty: Option<String>,
+  /// Whether the payload is base64url-encoded (RFC 7797).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub b64: Option<bool>,
+  /// Header parameters that must be understood and processed.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub crit: Option<Vec<String>>,
+}
+
+impl JwsHeader {
+  /// Creates a new `JwsHeader` with the given algorithm.
+  pub const fn new(alg: JwsAlgorithm) -> Self {
+    Self {
+      alg,
+      kid: None,
+      typ: None,
+      cty: None,
+      b64: None,
+      crit: None,
+    }
+  }
+
+  /// Creates a new `JwsHeader` for an unencoded payload (RFC 7797).
+  pub fn new_unencoded(alg: JwsAlgorithm) -> Self {
+    Self {
+      b64: Some(false),
+      crit: Some(alloc::vec!["b64".to_string()]),
+      ..Self::new(alg)
+    }
+  }
+
+  /// Returns `true` if the payload is base64url-encoded.
+  pub fn is_encoded(&self) -> bool {
+    self.b64.unwrap_or(true)
+  }
+
+  /// Checks the header against the processing rules of RFC 7515 and RFC 7797.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `crit` is empty, names an unsupported or absent parameter, or if
+  /// `b64` is present without being listed in `crit`.
+  pub fn validate(&self) -> Result<()> {
+    if let Some(crit) = self.crit.as_deref() {
+      if crit.is_empty() {
+        return Err(Error::message(ERR_IJH));
+      }
+
+      for name in crit {
+        if !CRITICAL.contains(&name.as_str()) {
+          return Err(Error::message(ERR_UJC));
+        }
+
+        if name == "b64" && self.b64.is_none() {
+          return Err(Error::message(ERR_IJH));
+        }
+      }
+    }
+
+    if self.b64.is_some() && !self.is_critical("b64") {
+      return Err(Error::message(ERR_IJH));
+    }
+
+    Ok(())
+  }
+
+  /// Checks that the header can be verified with `method`, which is referenced
+  /// by `verification_method`.
+  ///
+  /// The `kid`, if present, must identify the method, and `alg` must be
+  /// supported by its key type.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the `kid` or `alg` do not match the method.
+  pub fn check_method<M>(
+    &self,
+    verification_method: &str,
+    method: &MethodWrap<'_, M>,
+  ) -> Result<()> {
+    if let Some(kid) = self.kid.as_deref() {
+      if !Self::matches_kid(kid, verification_method, method.id()) {
+        return Err(Error::message(ERR_IJK));
+      }
+    }
+
+    self.alg.check_method(method.key_type(), method.key_data())
+  }
+
+  /// Returns the base64url-encoded JSON representation of the header.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the header cannot be serialized.
+  pub fn encode(&self) -> Result<String> {
+    to_vec(self)
+      .map(|header| URL_SAFE_NO_PAD.encode(header))
+      .map_err(|_| Error::message(ERR_IJH))
+  }
+
+  fn is_critical(&self, name: &str) -> bool {
+    self
+      .crit
+      .as_deref()
+      .is_some_and(|crit| crit.iter().any(|value| value == name))
+  }
+
+  fn matches_kid(kid: &str, verification_method: &str, id: &DID) -> bool {
+    if kid == verification_method {
+      return true;
+    }
+
+    // A relative key id is resolved against the DID of the method
+    match kid.strip_prefix('#') {
+      Some(fragment) => id.fragment() == Some(fragment),
+      None => id == kid,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::document::Document;
+  use crate::document::DocumentBuilder;
+  use crate::utils::Object;
+  use crate::utils::Value;
+  use crate::verification::MethodBuilder;
+  use crate::verification::MethodData;
+  use crate::verification::MethodType;
+
+  fn document(crv: &str) -> Document {
+    let mut jwk: Object = Object::new();
+
+    jwk.insert("kty".into(), Value::from("EC"));
+    jwk.insert("crv".into(), Value::from(crv));
+
+    DocumentBuilder::default()
+      .id("did:example:123".parse().unwrap())
+      .verification_method(
+        MethodBuilder::default()
+          .id("did:example:123#key-1".parse().unwrap())
+          .controller("did:example:123".parse().unwrap())
+          .key_type(MethodType::JsonWebKey2020)
+          .key_data(MethodData::PublicKeyJwk(jwk))
+          .build()
+          .unwrap(),
+      )
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_validate() {
+    let mut header: JwsHeader = JwsHeader::new_unencoded(JwsAlgorithm::ES256K);
+
+    assert!(header.validate().is_ok());
+    assert!(JwsHeader::new(JwsAlgorithm::ES256K).validate().is_ok());
+
+    header.crit = None;
+    assert!(header.validate().is_err());
+
+    header.crit = Some(Vec::new());
+    assert!(header.validate().is_err());
+
+    header.crit = Some(alloc::vec!["b64".into(), "exp".into()]);
+    assert!(header.validate().is_err());
+
+    header.b64 = None;
+    header.crit = Some(alloc::vec!["b64".into()]);
+    assert!(header.validate().is_err());
+  }
+
+  #[test]
+  fn test_check_kid() {
+    let document: Document = document("secp256k1");
+    let method: MethodWrap<'_> = document.try_resolve("#key-1").unwrap();
+    let mut header: JwsHeader = JwsHeader::new_unencoded(JwsAlgorithm::ES256K);
+
+    assert!(header.check_method("#key-1", &method).is_ok());
+
+    for kid in ["#key-1", "did:example:123#key-1", "key-1"] {
+      header.kid = Some(kid.into());
+      assert!(header.check_method("key-1", &method).is_ok());
+    }
+
+    for kid in ["#key-2", "did:example:456#key-1"] {
+      header.kid = Some(kid.into());
+      assert!(header.check_method("#key-1", &method).is_err());
+    }
+  }
+
+  #[test]
+  fn test_check_alg() {
+    let document: Document = document("P-256");
+    let method: MethodWrap<'_> = document.try_resolve("#key-1").unwrap();
+
+    assert!(JwsHeader::new(JwsAlgorithm::ES256)
+      .check_method("#key-1", &method)
+      .is_ok());
+    assert!(JwsHeader::new(JwsAlgorithm::ES256K)
+      .check_method("#key-1", &method)
+      .is_err());
+    assert!(JwsHeader::new(JwsAlgorithm::ES256KR)
+      .check_method("#key-1", &method)
+      .is_err());
+  }
+}
@@ -0,0 +1,9 @@
+mod jws_algorithm;
+mod jws_decoder;
+mod jws_encoder;
+mod jws_header;
+
+pub use self::jws_algorithm::*;
+pub use self::jws_decoder::*;
+pub use self::jws_encoder::*;
+pub use self::jws_header::*;
@@ -19,14 +19,26 @@ pub use url;
 
 mod document;
 mod error;
+#[cfg(feature = "ecdsa")]
+mod jsonld;
+mod jws;
+#[cfg(feature = "merkle")]
+mod merkle;
 mod service;
 mod signature;
+mod suites;
 mod utils;
 mod verifiable;
 mod verification;
 
+pub use self::document::Change;
 pub use self::document::Document;
 pub use self::document::DocumentBuilder;
+pub use self::document::DocumentDiff;
+pub use self::document::DocumentUpdate;
+pub use self::document::RelativeDocument;
+pub use self::document::RemovalMode;
+pub use self::document::RotationReport;
 
 pub use self::error::Error;
 pub use self::error::Result;
@@ -34,26 +46,87 @@ pub use self::error::Result;
 pub use self::service::Service;
 pub use self::service::ServiceBuilder;
 
+pub use self::signature::AsyncSigner;
+pub use self::signature::BatchItem;
+pub use self::signature::BatchVerifier;
+pub use self::signature::Clock;
+pub use self::signature::Encode;
 pub use self::signature::LdSuite;
+pub use self::signature::SecretKey;
 pub use self::signature::Sign;
+pub use self::signature::SignFuture;
 pub use self::signature::Signature;
 pub use self::signature::SignatureData;
 pub use self::signature::SignatureOptions;
 pub use self::signature::SignatureValue;
+pub use self::signature::Signer;
 pub use self::signature::SuiteName;
+#[cfg(feature = "std")]
+pub use self::signature::SystemClock;
+pub use self::signature::VerificationPolicy;
 pub use self::signature::Verify;
 
+pub use self::jws::DecodedJws;
+pub use self::jws::JwsAlgorithm;
+pub use self::jws::JwsEncoder;
+pub use self::jws::JwsHeader;
+
+#[cfg(feature = "merkle")]
+pub use self::merkle::KeyCollection;
+#[cfg(feature = "merkle")]
+pub use self::merkle::MerkleKey;
+#[cfg(feature = "merkle")]
+pub use self::merkle::MerkleProof;
+#[cfg(feature = "merkle")]
+pub use self::merkle::ProofNode;
+#[cfg(feature = "merkle")]
+pub use self::merkle::RevocationBitmap;
+
+#[cfg(feature = "bbs")]
+pub use self::suites::BbsBlsSignature2020;
+#[cfg(feature = "bbs")]
+pub use self::suites::BbsBlsSignatureProof2020;
+#[cfg(feature = "ecdsa")]
+pub use self::suites::EcdsaSecp256k1RecoverySignature2020;
+#[cfg(feature = "ecdsa")]
+pub use self::suites::EcdsaSecp256k1Signature2019;
+#[cfg(feature = "ecdsa")]
+pub use self::suites::JcsEcdsaSecp256k1Signature2019;
+#[cfg(feature = "ed25519")]
+pub use self::suites::JcsEd25519Signature2020;
+#[cfg(feature = "ecdsa")]
+pub use self::suites::JcsJsonWebSignature2020;
+#[cfg(feature = "ecdsa")]
+pub use self::suites::JsonWebSignature2020;
+#[cfg(any(feature = "ed25519", feature = "ecdsa", feature = "pq"))]
+pub use self::suites::LocalSigner;
+#[cfg(feature = "merkle")]
+pub use self::suites::MerkleKeySignature2021;
+#[cfg(feature = "pq")]
+pub use self::suites::MlDsa65Signature2024;
+#[cfg(feature = "pq")]
+pub use self::suites::SlhDsaSha2128sSignature2024;
+
+pub use self::utils::canonicalize;
 pub use self::utils::DIDKey;
+pub use self::utils::IndexedSet;
+pub use self::utils::JsonPatch;
 pub use self::utils::Object;
 pub use self::utils::OrderedSet;
+pub use self::utils::PatchOperation;
+pub use self::utils::Timestamp;
 pub use self::utils::Value;
 
+pub use self::verifiable::ResolveDocument;
 pub use self::verifiable::ResolveMethod;
 pub use self::verifiable::SetSignature;
 pub use self::verifiable::TrySignature;
 pub use self::verifiable::TrySignatureMut;
+pub use self::verifiable::Verifiable;
 pub use self::verifiable::VerifiableDocument;
+pub use self::verifiable::VerifiableMethod;
 pub use self::verifiable::VerifiableProperties;
+pub use self::verifiable::VerifiableService;
 
 pub use self::verification::Method;
 pub use self::verification::MethodBuilder;
@@ -64,13 +137,24 @@ pub use self::verification::MethodRef;
 pub use self::verification::MethodScope;
 pub use self::verification::MethodType;
 pub use self::verification::MethodWrap;
+pub use self::verification::ScopedMethod;
 
 mod lib {
+  #[cfg(all(feature = "alloc", not(feature = "std")))]
+  pub use alloc::boxed::Box;
+  #[cfg(feature = "std")]
+  pub use std::boxed::Box;
+
   #[cfg(all(feature = "alloc", not(feature = "std")))]
   pub use alloc::borrow::Borrow;
   #[cfg(feature = "std")]
   pub use std::borrow::Borrow;
 
+  #[cfg(all(feature = "alloc", not(feature = "std")))]
+  pub use alloc::borrow::Cow;
+  #[cfg(feature = "std")]
+  pub use std::borrow::Cow;
+
   #[cfg(all(feature = "alloc", not(feature = "std")))]
   pub use alloc::string::{String, ToString};
   #[cfg(feature = "std")]
@@ -85,4 +169,9 @@ mod lib {
   pub use alloc::collections::BTreeMap;
   #[cfg(feature = "std")]
   pub use std::collections::BTreeMap;
+
+  #[cfg(all(feature = "alloc", not(feature = "std")))]
+  pub use alloc::collections::BTreeSet;
+  #[cfg(feature = "std")]
+  pub use std::collections::BTreeSet;
 }
@@ -0,0 +1,133 @@
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::merkle::compute_proof;
+use crate::merkle::compute_root;
+use crate::merkle::hash_leaf;
+use crate::merkle::Hash;
+use crate::merkle::MerkleKey;
+use crate::merkle::MerkleProof;
+use crate::verification::MethodData;
+
+const ERR_IKC: &str = "Invalid Key Collection Size";
+const ERR_IPK: &str = "Invalid Key Collection Public Key";
+
+// The length of an Ed25519 public key.
+const KEY_LEN: usize = 32;
+
+/// A collection of Ed25519 public keys committed to by a Merkle tree root.
+///
+/// The collection is stored in a `MerkleKeyCollection2021` verification method
+/// as the tagged root; signatures carry the public key and inclusion proof of
+/// the signing key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyCollection {
+  keys: Vec<Vec<u8>>,
+  leaves: Vec<Hash>,
+}
+
+impl KeyCollection {
+  /// Creates a new `KeyCollection` from a list of public keys.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the number of keys is not a power of two or a key is not a valid
+  /// Ed25519 public key length.
+  pub fn new(keys: Vec<Vec<u8>>) -> Result<Self> {
+    if !keys.len().is_power_of_two() {
+      return Err(Error::message(ERR_IKC));
+    }
+
+    if keys.iter().any(|key| key.len() != KEY_LEN) {
+      return Err(Error::message(ERR_IPK));
+    }
+
+    let leaves: Vec<Hash> = keys.iter().map(|key| hash_leaf(key)).collect();
+
+    Ok(Self { keys, leaves })
+  }
+
+  /// Returns the number of keys in the collection.
+  pub fn len(&self) -> usize {
+    self.keys.len()
+  }
+
+  /// Returns `true` if the collection is empty; this is never the case.
+  pub fn is_empty(&self) -> bool {
+    self.keys.is_empty()
+  }
+
+  /// Returns the public key at `index`.
+  pub fn public(&self, index: usize) -> Option<&[u8]> {
+    self.keys.get(index).map(|key| &key[..])
+  }
+
+  /// Returns the Merkle tree root of the collection.
+  pub fn root(&self) -> Hash {
+    compute_root(&self.leaves)
+  }
+
+  /// Returns the inclusion proof of the key at `index`.
+  pub fn proof(&self, index: usize) -> Option<MerkleProof> {
+    if index < self.leaves.len() {
+      Some(compute_proof(&self.leaves, index))
+    } else {
+      None
+    }
+  }
+
+  /// Returns the `MethodData` of a `MerkleKeyCollection2021` method.
+  pub fn to_method_data(&self) -> MethodData {
+    MerkleKey::encode(&self.root())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn collection(size: u8) -> KeyCollection {
+    KeyCollection::new((0..size).map(|index| alloc::vec![index; KEY_LEN]).collect()).unwrap()
+  }
+
+  #[test]
+  fn test_new() {
+    assert!(KeyCollection::new(Vec::new()).is_err());
+    assert!(KeyCollection::new(alloc::vec![alloc::vec![0; KEY_LEN]; 3]).is_err());
+    assert!(KeyCollection::new(alloc::vec![alloc::vec![0; 31]]).is_err());
+    assert_eq!(collection(1).root(), hash_leaf(&[0; KEY_LEN]));
+  }
+
+  #[test]
+  fn test_proof() {
+    for size in [1, 2, 8, 32] {
+      let collection: KeyCollection = collection(size);
+      let root: Hash = collection.root();
+
+      for index in 0..collection.len() {
+        let proof: MerkleProof = collection.proof(index).unwrap();
+        let leaf: Hash = hash_leaf(collection.public(index).unwrap());
+
+        assert_eq!(proof.index(), index);
+        assert_eq!(proof.root(leaf), root);
+        assert_eq!(MerkleProof::from_bytes(&proof.to_bytes()).unwrap(), proof);
+      }
+
+      assert!(collection.proof(collection.len()).is_none());
+    }
+  }
+
+  #[test]
+  fn test_method_data() {
+    let collection: KeyCollection = collection(4);
+    let data: Vec<u8> = collection.to_method_data().try_decode().unwrap();
+
+    assert_eq!(
+      data[..2],
+      [MerkleKey::DIGEST_SHA256, MerkleKey::SIGNATURE_ED25519]
+    );
+    assert_eq!(MerkleKey::decode(&data).unwrap(), collection.root());
+    assert!(MerkleKey::decode(&data[..33]).is_err());
+    assert!(MerkleKey::decode(&[0xFF, 0x00]).is_err());
+  }
+}
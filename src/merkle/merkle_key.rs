@@ -0,0 +1,53 @@
+use core::convert::TryInto;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::merkle::Hash;
+use crate::verification::MethodData;
+
+const ERR_IMK: &str = "Invalid Merkle Key";
+const ERR_UMD: &str = "Unsupported Merkle Key Digest";
+const ERR_UMS: &str = "Unsupported Merkle Key Signature";
+
+/// The encoding of the root of a Merkle key collection in `MethodData`.
+///
+/// The root is prefixed with a tag identifying the digest of the tree and a tag
+/// identifying the signature algorithm of the keys.
+#[derive(Clone, Copy, Debug)]
+pub struct MerkleKey;
+
+impl MerkleKey {
+  /// The digest tag of SHA-256.
+  pub const DIGEST_SHA256: u8 = 0x00;
+
+  /// The signature tag of Ed25519.
+  pub const SIGNATURE_ED25519: u8 = 0x00;
+
+  /// Returns the `MethodData` representation of a collection `root`.
+  pub fn encode(root: &Hash) -> MethodData {
+    let mut data: Vec<u8> = Vec::with_capacity(2 + root.len());
+
+    data.push(Self::DIGEST_SHA256);
+    data.push(Self::SIGNATURE_ED25519);
+    data.extend_from_slice(root);
+
+    MethodData::new_b58(data)
+  }
+
+  /// Returns the collection root of decoded `MethodData`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the data is malformed or the tags are not supported.
+  pub fn decode(data: &[u8]) -> Result<Hash> {
+    match data {
+      [Self::DIGEST_SHA256, Self::SIGNATURE_ED25519, root @ ..] => {
+        root.try_into().map_err(|_| Error::message(ERR_IMK))
+      }
+      [Self::DIGEST_SHA256, _, ..] => Err(Error::message(ERR_UMS)),
+      [_, _, ..] => Err(Error::message(ERR_UMD)),
+      _ => Err(Error::message(ERR_IMK)),
+    }
+  }
+}
@@ -0,0 +1,105 @@
+use core::convert::TryInto;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::merkle::hash_node;
+use crate::merkle::Hash;
+
+const ERR_IMP: &str = "Invalid Merkle Proof";
+
+// The maximum depth of a Merkle key collection.
+const MAX_DEPTH: usize = 32;
+
+// Serialized tags of sibling nodes.
+const TAG_L: u8 = 0x00;
+const TAG_R: u8 = 0x01;
+
+/// A sibling node in a `MerkleProof`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ProofNode {
+  /// A sibling on the left of the path.
+  L(Hash),
+  /// A sibling on the right of the path.
+  R(Hash),
+}
+
+/// An inclusion proof of a leaf in a Merkle tree.
+///
+/// The proof lists the siblings of the path from the leaf to the root.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct MerkleProof {
+  nodes: Vec<ProofNode>,
+}
+
+impl MerkleProof {
+  /// Creates a new `MerkleProof` from the siblings of a leaf, bottom-up.
+  pub const fn new(nodes: Vec<ProofNode>) -> Self {
+    Self { nodes }
+  }
+
+  /// Returns the sibling nodes of the proof.
+  pub fn nodes(&self) -> &[ProofNode] {
+    &self.nodes
+  }
+
+  /// Returns the index of the proven leaf.
+  pub fn index(&self) -> usize {
+    self
+      .nodes
+      .iter()
+      .enumerate()
+      .filter(|(_, node)| matches!(node, ProofNode::L(_)))
+      .fold(0, |index, (depth, _)| index | (1 << depth))
+  }
+
+  /// Returns the root hash computed from the hash of the proven leaf.
+  pub fn root(&self, leaf: Hash) -> Hash {
+    self.nodes.iter().fold(leaf, |hash, node| match node {
+      ProofNode::L(sibling) => hash_node(sibling, &hash),
+      ProofNode::R(sibling) => hash_node(&hash, sibling),
+    })
+  }
+
+  /// Returns the binary representation of the proof.
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut output: Vec<u8> = Vec::with_capacity(self.nodes.len() * 33);
+
+    for node in self.nodes.iter() {
+      let (tag, hash): (u8, &Hash) = match node {
+        ProofNode::L(hash) => (TAG_L, hash),
+        ProofNode::R(hash) => (TAG_R, hash),
+      };
+
+      output.push(tag);
+      output.extend_from_slice(hash);
+    }
+
+    output
+  }
+
+  /// Parses a proof from the representation returned by `to_bytes`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the input is malformed or the proof is too deep.
+  pub fn from_bytes(data: &[u8]) -> Result<Self> {
+    if !data.len().is_multiple_of(33) || data.len() / 33 > MAX_DEPTH {
+      return Err(Error::message(ERR_IMP));
+    }
+
+    data
+      .chunks(33)
+      .map(|chunk| {
+        let hash: Hash = chunk[1..].try_into().map_err(|_| Error::message(ERR_IMP))?;
+
+        match chunk[0] {
+          TAG_L => Ok(ProofNode::L(hash)),
+          TAG_R => Ok(ProofNode::R(hash)),
+          _ => Err(Error::message(ERR_IMP)),
+        }
+      })
+      .collect::<Result<_>>()
+      .map(Self::new)
+  }
+}
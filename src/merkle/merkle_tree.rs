@@ -0,0 +1,70 @@
+use sha2::Digest as _;
+use sha2::Sha256;
+
+use crate::lib::*;
+use crate::merkle::MerkleProof;
+use crate::merkle::ProofNode;
+
+/// A SHA-256 digest of a Merkle tree node.
+pub(crate) type Hash = [u8; 32];
+
+// Domain separation prefixes of leaf and internal nodes (RFC 6962).
+const LEAF: u8 = 0x00;
+const NODE: u8 = 0x01;
+
+/// Returns the hash of a leaf node.
+pub(crate) fn hash_leaf(data: &[u8]) -> Hash {
+  Sha256::new()
+    .chain_update([LEAF])
+    .chain_update(data)
+    .finalize()
+    .into()
+}
+
+/// Returns the hash of an internal node.
+pub(crate) fn hash_node(lhs: &Hash, rhs: &Hash) -> Hash {
+  Sha256::new()
+    .chain_update([NODE])
+    .chain_update(lhs)
+    .chain_update(rhs)
+    .finalize()
+    .into()
+}
+
+/// Returns the root hash of a tree with a power-of-two number of leaves.
+pub(crate) fn compute_root(leaves: &[Hash]) -> Hash {
+  let mut level: Vec<Hash> = leaves.to_vec();
+
+  while level.len() > 1 {
+    level = level
+      .chunks(2)
+      .map(|pair| hash_node(&pair[0], &pair[1]))
+      .collect();
+  }
+
+  level[0]
+}
+
+/// Returns the inclusion proof of the leaf at `index` in a tree with a
+/// power-of-two number of leaves.
+pub(crate) fn compute_proof(leaves: &[Hash], mut index: usize) -> MerkleProof {
+  let mut level: Vec<Hash> = leaves.to_vec();
+  let mut nodes: Vec<ProofNode> = Vec::new();
+
+  while level.len() > 1 {
+    if index.is_multiple_of(2) {
+      nodes.push(ProofNode::R(level[index + 1]));
+    } else {
+      nodes.push(ProofNode::L(level[index - 1]));
+    }
+
+    level = level
+      .chunks(2)
+      .map(|pair| hash_node(&pair[0], &pair[1]))
+      .collect();
+
+    index /= 2;
+  }
+
+  MerkleProof::new(nodes)
+}
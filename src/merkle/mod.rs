@@ -0,0 +1,11 @@
+mod key_collection;
+mod merkle_key;
+mod merkle_proof;
+mod merkle_tree;
+mod revocation_bitmap;
+
+pub use self::key_collection::*;
+pub use self::merkle_key::*;
+pub use self::merkle_proof::*;
+pub(crate) use self::merkle_tree::*;
+pub use self::revocation_bitmap::*;
@@ -0,0 +1,144 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::utils::Object;
+use crate::utils::Value;
+
+const ERR_IRB: &str = "Invalid Revocation Bitmap";
+
+/// A set of revoked key indexes of a Merkle key collection.
+///
+/// The bitmap is stored in the `revocation` property of the verification
+/// method as a base64url-encoded bit string, least significant bit first.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct RevocationBitmap {
+  data: Vec<u8>,
+}
+
+impl RevocationBitmap {
+  /// The name of the method property containing the bitmap.
+  pub const PROPERTY: &'static str = "revocation";
+
+  /// Creates a new, empty `RevocationBitmap`.
+  pub const fn new() -> Self {
+    Self { data: Vec::new() }
+  }
+
+  /// Reads the `RevocationBitmap` of a method from its `properties`.
+  ///
+  /// Returns an empty bitmap if the property is not present.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the property is not a valid bitmap.
+  pub fn from_properties(properties: &Object) -> Result<Self> {
+    match properties.get(Self::PROPERTY) {
+      Some(Value::String(data)) => URL_SAFE_NO_PAD
+        .decode(data)
+        .map(|data| Self { data })
+        .map_err(|_| Error::message(ERR_IRB)),
+      Some(_) => Err(Error::message(ERR_IRB)),
+      None => Ok(Self::new()),
+    }
+  }
+
+  /// Writes the `RevocationBitmap` to the method `properties`.
+  ///
+  /// The property is removed if no keys are revoked.
+  pub fn to_properties(&self, properties: &mut Object) {
+    let len: usize = self
+      .data
+      .iter()
+      .rposition(|byte| *byte != 0)
+      .map_or(0, |index| index + 1);
+
+    if len == 0 {
+      properties.remove(Self::PROPERTY);
+    } else {
+      properties.insert(
+        Self::PROPERTY.into(),
+        URL_SAFE_NO_PAD.encode(&self.data[..len]).into(),
+      );
+    }
+  }
+
+  /// Returns `true` if the key at `index` is revoked.
+  pub fn is_revoked(&self, index: usize) -> bool {
+    self
+      .data
+      .get(index / 8)
+      .is_some_and(|byte| byte & (1 << (index % 8)) != 0)
+  }
+
+  /// Revokes the key at `index`; returns `false` if it was already revoked.
+  pub fn revoke(&mut self, index: usize) -> bool {
+    if self.data.len() <= index / 8 {
+      self.data.resize(index / 8 + 1, 0);
+    }
+
+    let revoked: bool = self.is_revoked(index);
+
+    self.data[index / 8] |= 1 << (index % 8);
+
+    !revoked
+  }
+
+  /// Reinstates the key at `index`; returns `false` if it was not revoked.
+  pub fn unrevoke(&mut self, index: usize) -> bool {
+    let revoked: bool = self.is_revoked(index);
+
+    if revoked {
+      self.data[index / 8] &= !(1 << (index % 8));
+    }
+
+    revoked
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_revoke() {
+    let mut bitmap: RevocationBitmap = RevocationBitmap::new();
+
+    assert!(!bitmap.is_revoked(9));
+    assert!(bitmap.revoke(9));
+    assert!(!bitmap.revoke(9));
+    assert!(bitmap.is_revoked(9));
+    assert!(!bitmap.is_revoked(8));
+    assert!(bitmap.unrevoke(9));
+    assert!(!bitmap.unrevoke(9));
+    assert!(!bitmap.unrevoke(1000));
+  }
+
+  #[test]
+  fn test_properties() {
+    let mut properties: Object = Object::new();
+    let mut bitmap: RevocationBitmap = RevocationBitmap::new();
+
+    bitmap.revoke(0);
+    bitmap.revoke(100);
+    bitmap.to_properties(&mut properties);
+
+    let decoded: RevocationBitmap = RevocationBitmap::from_properties(&properties).unwrap();
+
+    assert!(decoded.is_revoked(0));
+    assert!(decoded.is_revoked(100));
+    assert!(!decoded.is_revoked(1));
+
+    bitmap.unrevoke(0);
+    bitmap.unrevoke(100);
+    bitmap.to_properties(&mut properties);
+
+    assert!(properties.is_empty());
+
+    properties.insert(RevocationBitmap::PROPERTY.into(), Value::from(1));
+
+    assert!(RevocationBitmap::from_properties(&properties).is_err());
+  }
+}
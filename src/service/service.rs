@@ -75,7 +75,7 @@ impl<T> Service<T> {
 
   /// Returns a reference to the `Service` type.
   pub fn type_(&self) -> &str {
-    &*self.type_
+    &self.type_
   }
 
   /// Returns a mutable reference to the `Service` type.
@@ -0,0 +1,476 @@
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::signature::ld_suite::decode_key;
+use crate::signature::Clock;
+use crate::signature::LdSuite;
+use crate::signature::Signature;
+use crate::signature::SignatureData;
+use crate::signature::SuiteName;
+use crate::signature::VerificationPolicy;
+use crate::signature::Verify;
+use crate::utils::Value;
+use crate::verifiable::ResolveMethod;
+use crate::verifiable::TrySignature;
+use crate::verification::MethodQuery;
+use crate::verification::MethodWrap;
+
+const ERR_USS: &str = "Unsupported Signature Suite";
+
+/// A resolved verification method and its decoded public key.
+type Resolved<'a, M> = Result<(MethodWrap<'a, M>, Vec<u8>)>;
+
+/// A signature to be checked as part of a batch; see `Verify::verify_batch`.
+#[derive(Clone, Copy, Debug)]
+pub struct BatchItem<'a, T> {
+  /// The signing input, with the signature value removed.
+  pub data: &'a T,
+  /// The signature value.
+  pub signature: &'a SignatureData,
+  /// The decoded public key of the verification method.
+  pub public: &'a [u8],
+}
+
+/// Verifies the signatures of many messages, grouped by signature suite.
+///
+/// Each call to `verify` or `verify_data` handles the messages signed with
+/// the given suite and leaves the rest for subsequent calls; `finish` returns
+/// one result per message, in order.
+///
+/// Signatures of a suite are checked with `Verify::verify_batch`, allowing
+/// suites to use batched signature checks where the scheme allows it.
+#[derive(Debug)]
+pub struct BatchVerifier<'a, T, C> {
+  messages: &'a [T],
+  policy: &'a VerificationPolicy<C>,
+  results: Vec<Option<Result<()>>>,
+}
+
+impl<'a, T, C> BatchVerifier<'a, T, C>
+where
+  T: Serialize + TrySignature,
+  C: Clock,
+{
+  /// Creates a new `BatchVerifier` checking all `messages` against `policy`.
+  pub fn new(messages: &'a [T], policy: &'a VerificationPolicy<C>) -> Self {
+    Self {
+      messages,
+      policy,
+      results: messages.iter().map(|_| None).collect(),
+    }
+  }
+
+  /// Verifies the messages signed with `suite`, resolving verification methods
+  /// from the messages themselves.
+  #[must_use]
+  pub fn verify<S, M>(self, suite: &LdSuite<S>) -> Self
+  where
+    T: ResolveMethod<M>,
+    S: Verify + SuiteName,
+    M: Serialize,
+  {
+    let pending: Vec<usize> = self.pending(suite);
+    let results: Vec<(usize, Result<()>)> =
+      verify_self(suite, self.policy, self.messages, &pending);
+
+    self.update(results)
+  }
+
+  /// Verifies the messages signed with `suite`, resolving verification methods
+  /// from `resolver`.
+  ///
+  /// Methods are resolved once per distinct `verificationMethod` and proof
+  /// purpose, and shared across the batch.
+  #[must_use]
+  pub fn verify_data<S, R, M>(self, suite: &LdSuite<S>, resolver: R) -> Self
+  where
+    S: Verify + SuiteName,
+    R: ResolveMethod<M>,
+    M: Serialize,
+  {
+    let pending: Vec<usize> = self.pending(suite);
+    let results: Vec<(usize, Result<()>)> =
+      verify_with(suite, self.policy, self.messages, &pending, &resolver);
+
+    self.update(results)
+  }
+
+  /// Returns the result of each message, in order.
+  ///
+  /// Messages not handled by any suite fail with an "Unsupported Signature
+  /// Suite" error.
+  pub fn finish(self) -> Vec<Result<()>> {
+    self
+      .results
+      .into_iter()
+      .zip(self.messages)
+      .map(|(result, message)| {
+        result.unwrap_or_else(|| message.try_signature().and(Err(Error::message(ERR_USS))))
+      })
+      .collect()
+  }
+
+  fn pending<S>(&self, suite: &LdSuite<S>) -> Vec<usize>
+  where
+    S: SuiteName,
+  {
+    let name: String = suite.suite().name();
+
+    self
+      .results
+      .iter()
+      .zip(self.messages)
+      .enumerate()
+      .filter(|(_, (result, _))| result.is_none())
+      .filter(|(_, (_, message))| {
+        message
+          .try_signature()
+          .is_ok_and(|signature| signature.type_() == name)
+      })
+      .map(|(index, _)| index)
+      .collect()
+  }
+
+  fn update(mut self, results: Vec<(usize, Result<()>)>) -> Self {
+    for (index, result) in results {
+      self.results[index] = Some(result);
+    }
+
+    self
+  }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T, C> BatchVerifier<'a, T, C>
+where
+  T: Serialize + TrySignature + Sync,
+  C: Clock + Sync,
+{
+  /// Like `BatchVerifier::verify`, but splits the messages across threads.
+  #[must_use]
+  pub fn par_verify<S, M>(self, suite: &LdSuite<S>) -> Self
+  where
+    T: ResolveMethod<M>,
+    S: Verify + SuiteName + Sync,
+    M: Serialize,
+  {
+    let pending: Vec<usize> = self.pending(suite);
+    let (policy, messages) = (self.policy, self.messages);
+    let results: Vec<(usize, Result<()>)> = par_chunks(&pending, |chunk| {
+      verify_self(suite, policy, messages, chunk)
+    });
+
+    self.update(results)
+  }
+
+  /// Like `BatchVerifier::verify_data`, but splits the messages across
+  /// threads; methods are shared across the messages of each thread.
+  #[must_use]
+  pub fn par_verify_data<S, R, M>(self, suite: &LdSuite<S>, resolver: R) -> Self
+  where
+    S: Verify + SuiteName + Sync,
+    R: ResolveMethod<M> + Sync,
+    M: Serialize,
+  {
+    let pending: Vec<usize> = self.pending(suite);
+    let (policy, messages, resolver) = (self.policy, self.messages, &resolver);
+    let results: Vec<(usize, Result<()>)> = par_chunks(&pending, |chunk| {
+      verify_with(suite, policy, messages, chunk, resolver)
+    });
+
+    self.update(results)
+  }
+}
+
+#[cfg(feature = "std")]
+fn par_chunks<F>(pending: &[usize], f: F) -> Vec<(usize, Result<()>)>
+where
+  F: Fn(&[usize]) -> Vec<(usize, Result<()>)> + Sync,
+{
+  let threads: usize = std::thread::available_parallelism().map_or(1, |count| count.get());
+  let size: usize = pending.len().div_ceil(threads).max(1);
+
+  std::thread::scope(|scope| {
+    let f: &F = &f;
+
+    pending
+      .chunks(size)
+      .map(|chunk| scope.spawn(move || f(chunk)))
+      .collect::<Vec<_>>()
+      .into_iter()
+      .flat_map(|handle| handle.join().expect("batch verification thread panicked"))
+      .collect()
+  })
+}
+
+fn verify_self<T, S, C, M>(
+  suite: &LdSuite<S>,
+  policy: &VerificationPolicy<C>,
+  messages: &[T],
+  pending: &[usize],
+) -> Vec<(usize, Result<()>)>
+where
+  T: Serialize + TrySignature + ResolveMethod<M>,
+  S: Verify + SuiteName,
+  C: Clock,
+  M: Serialize,
+{
+  verify_chunk(suite, policy, messages, pending, |message, signature| {
+    let method: MethodWrap<'_, M> = message.try_resolve_method(signature.to_query()?)?;
+
+    suite.check_method(signature, &method)?;
+
+    decode_key(&method)
+  })
+}
+
+fn verify_with<T, S, C, R, M>(
+  suite: &LdSuite<S>,
+  policy: &VerificationPolicy<C>,
+  messages: &[T],
+  pending: &[usize],
+  resolver: &R,
+) -> Vec<(usize, Result<()>)>
+where
+  T: Serialize + TrySignature,
+  S: Verify + SuiteName,
+  C: Clock,
+  R: ResolveMethod<M>,
+  M: Serialize,
+{
+  let mut cache: BTreeMap<MethodQuery<'_>, Resolved<'_, M>> = BTreeMap::new();
+
+  verify_chunk(suite, policy, messages, pending, |_, signature| {
+    let query: MethodQuery<'_> = signature.to_query()?;
+
+    let (method, public) = cache
+      .entry(query)
+      .or_insert_with(|| {
+        let method: MethodWrap<'_, M> = resolver.try_resolve_method(query)?;
+        let public: Vec<u8> = decode_key(&method)?;
+
+        Ok((method, public))
+      })
+      .as_ref()
+      .map_err(Clone::clone)?;
+
+    suite.check_method(signature, method)?;
+
+    Ok(public.clone())
+  })
+}
+
+/// Checks the signatures of `pending` messages with `suite`; `resolve` returns
+/// the decoded public key of the method of each signature.
+fn verify_chunk<'a, T, S, C, F>(
+  suite: &LdSuite<S>,
+  policy: &VerificationPolicy<C>,
+  messages: &'a [T],
+  pending: &[usize],
+  mut resolve: F,
+) -> Vec<(usize, Result<()>)>
+where
+  T: Serialize + TrySignature,
+  S: Verify + SuiteName,
+  C: Clock,
+  F: FnMut(&'a T, &'a Signature) -> Result<Vec<u8>>,
+{
+  let mut results: Vec<(usize, Result<()>)> = Vec::with_capacity(pending.len());
+  let mut prepared: Vec<(usize, &'a Signature, Value, Vec<u8>)> = Vec::new();
+
+  for &index in pending {
+    let message: &'a T = &messages[index];
+
+    let input: Result<(&'a Signature, Value, Vec<u8>)> = (|| {
+      let signature: &'a Signature = suite.check_signature(message, policy)?;
+      let public: Vec<u8> = resolve(message, signature)?;
+      let input: Value = signature.verifiable(message)?;
+
+      Ok((signature, input, public))
+    })();
+
+    match input {
+      Ok((signature, input, public)) => prepared.push((index, signature, input, public)),
+      Err(error) => results.push((index, Err(error))),
+    }
+  }
+
+  let items: Vec<BatchItem<'_, Value>> = prepared
+    .iter()
+    .map(|(_, signature, input, public)| BatchItem {
+      data: input,
+      signature: signature.data(),
+      public,
+    })
+    .collect();
+
+  let checked: Vec<Result<()>> = suite.suite().verify_batch(&items);
+
+  results.extend(
+    prepared
+      .iter()
+      .zip(checked)
+      .map(|((index, signature, ..), result)| {
+        let result: Result<()> = result.map_err(|error| {
+          Error::invalid_signature(&signature.verification_method).with_source(error)
+        });
+
+        (*index, result)
+      }),
+  );
+  results
+}
+
+#[cfg(test)]
+mod tests {
+  use core::sync::atomic::AtomicUsize;
+  use core::sync::atomic::Ordering;
+
+  use super::*;
+  use crate::signature::ld_suite::tests::document;
+  use crate::signature::ld_suite::tests::policy;
+  use crate::signature::ld_suite::tests::signed;
+  use crate::signature::ld_suite::tests::TestSuite;
+  use crate::signature::Sign;
+  use crate::signature::SignatureOptions;
+  use crate::utils::Object;
+  use crate::verifiable::VerifiableDocument;
+  use crate::verification::MethodType;
+
+  // The `TestSuite` under another name.
+  struct OtherSuite;
+
+  impl SuiteName for OtherSuite {
+    fn name(&self) -> String {
+      "OtherSuite".into()
+    }
+  }
+
+  impl Sign for OtherSuite {
+    fn sign<T>(&self, data: &T, secret: &[u8]) -> Result<SignatureData>
+    where
+      T: Serialize,
+    {
+      TestSuite.sign(data, secret)
+    }
+  }
+
+  impl Verify for OtherSuite {
+    const METHODS: &'static [MethodType] = TestSuite::METHODS;
+
+    fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+    where
+      T: Serialize,
+    {
+      TestSuite.verify(data, signature, public)
+    }
+  }
+
+  // A resolver counting the number of resolved methods.
+  struct Resolver(VerifiableDocument, AtomicUsize);
+
+  impl ResolveMethod<Object> for Resolver {
+    fn resolve_method(&self, query: MethodQuery<'_>) -> Option<MethodWrap<'_>> {
+      self.1.fetch_add(1, Ordering::SeqCst);
+      self.0.resolve_method(query)
+    }
+  }
+
+  fn messages() -> Vec<VerifiableDocument> {
+    let mut other: VerifiableDocument = document();
+    let mut options: SignatureOptions =
+      SignatureOptions::with_purpose("#key-1".into(), "assertionMethod".into());
+
+    options.created = Some("2021-01-01T00:00:00Z".parse().unwrap());
+
+    LdSuite::new(OtherSuite)
+      .sign(&mut other, options, b"secret")
+      .unwrap();
+
+    let mut tampered: VerifiableDocument = signed();
+
+    tampered
+      .properties_mut()
+      .insert("tampered".into(), true.into());
+
+    alloc::vec![signed(), tampered, other, document(), signed()]
+  }
+
+  #[test]
+  fn test_verify() {
+    let messages: Vec<VerifiableDocument> = messages();
+    let policy = policy();
+
+    let results: Vec<Result<()>> = BatchVerifier::new(&messages, &policy)
+      .verify(&LdSuite::new(TestSuite))
+      .finish();
+
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert_eq!(results[2], Err(Error::message(ERR_USS)));
+    assert!(results[3].is_err());
+    assert!(results[4].is_ok());
+
+    // Messages are grouped by suite
+    let results: Vec<Result<()>> = BatchVerifier::new(&messages, &policy)
+      .verify(&LdSuite::new(TestSuite))
+      .verify(&LdSuite::new(OtherSuite))
+      .finish();
+
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+    assert!(results[3].is_err());
+    assert!(results[4].is_ok());
+  }
+
+  #[test]
+  fn test_verify_data() {
+    let messages: Vec<VerifiableDocument> = messages();
+    let resolver: Resolver = Resolver(document(), AtomicUsize::new(0));
+    let policy = policy();
+
+    let results: Vec<Result<()>> = BatchVerifier::new(&messages, &policy)
+      .verify_data(&LdSuite::new(TestSuite), &resolver)
+      .verify_data(&LdSuite::new(OtherSuite), &resolver)
+      .finish();
+
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+    assert!(results[3].is_err());
+    assert!(results[4].is_ok());
+
+    // The method is resolved once per suite
+    assert_eq!(resolver.1.load(Ordering::SeqCst), 2);
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_par_verify() {
+    let messages: Vec<VerifiableDocument> = messages().into_iter().cycle().take(20).collect();
+    let resolver: Resolver = Resolver(document(), AtomicUsize::new(0));
+    let policy = policy();
+
+    let expected: Vec<Result<()>> = BatchVerifier::new(&messages, &policy)
+      .verify(&LdSuite::new(TestSuite))
+      .verify(&LdSuite::new(OtherSuite))
+      .finish();
+
+    let results: Vec<Result<()>> = BatchVerifier::new(&messages, &policy)
+      .par_verify(&LdSuite::new(TestSuite))
+      .par_verify(&LdSuite::new(OtherSuite))
+      .finish();
+
+    assert_eq!(results, expected);
+
+    let results: Vec<Result<()>> = BatchVerifier::new(&messages, &policy)
+      .par_verify_data(&LdSuite::new(TestSuite), &resolver)
+      .par_verify_data(&LdSuite::new(OtherSuite), &resolver)
+      .finish();
+
+    assert_eq!(results, expected);
+  }
+}
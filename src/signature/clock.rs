@@ -0,0 +1,32 @@
+/// A source of the current time, used to validate signature timestamps.
+pub trait Clock {
+  /// Returns the current time in seconds since the Unix epoch.
+  fn now(&self) -> i64;
+}
+
+impl<F> Clock for F
+where
+  F: Fn() -> i64,
+{
+  fn now(&self) -> i64 {
+    self()
+  }
+}
+
+/// A `Clock` based on the system time.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+  fn now(&self) -> i64 {
+    use std::time::SystemTime;
+    use std::time::UNIX_EPOCH;
+
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+      Ok(duration) => duration.as_secs() as i64,
+      Err(error) => -(error.duration().as_secs() as i64),
+    }
+  }
+}
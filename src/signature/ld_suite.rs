@@ -1,19 +1,36 @@
+use did_url::DID;
 use serde::Serialize;
 
+use crate::document::Document;
 use crate::error::Error;
 use crate::error::Result;
+use crate::lib::*;
+use crate::signature::AsyncSigner;
+use crate::signature::Clock;
+use crate::signature::Encode;
+use crate::signature::SecretKey;
 use crate::signature::Sign;
 use crate::signature::Signature;
 use crate::signature::SignatureData;
 use crate::signature::SignatureOptions;
+use crate::signature::Signer;
 use crate::signature::SuiteName;
+use crate::signature::VerificationPolicy;
 use crate::signature::Verify;
+use crate::utils::Value;
+use crate::verifiable::ResolveDocument;
 use crate::verifiable::ResolveMethod;
 use crate::verifiable::SetSignature;
 use crate::verifiable::TrySignature;
+use crate::verifiable::VerifiableDocument;
+use crate::verification::MethodIndex;
 use crate::verification::MethodQuery;
 use crate::verification::MethodWrap;
 
+const ERR_IST: &str = "Invalid Signature Type";
+const ERR_UC: &str = "Unauthorized Controller";
+const ERR_CCC: &str = "Circular Controller Chain";
+
 #[derive(Clone, Copy, Debug)]
 pub struct LdSuite<S> {
   suite: S,
@@ -23,6 +40,11 @@ impl<S> LdSuite<S> {
   pub fn new(suite: S) -> Self {
     Self { suite }
   }
+
+  /// Returns the inner signature suite.
+  pub(crate) fn suite(&self) -> &S {
+    &self.suite
+  }
 }
 
 impl<S> LdSuite<S>
@@ -36,7 +58,116 @@ where
   {
     message.set_signature(Signature::new(self.suite.name(), options));
 
-    let value: SignatureData = self.suite.sign(message, secret.as_ref())?;
+    let input: Value = message.try_signature()?.verifiable(message)?;
+    let value: SignatureData = self.suite.sign(&input, secret.as_ref())?;
+
+    message.try_signature_mut()?.set_data(value);
+
+    Ok(())
+  }
+}
+
+impl<S> LdSuite<S>
+where
+  S: Sign + SuiteName + Verify,
+{
+  /// Signs `message` with a `SecretKey`.
+  ///
+  /// Unlike `LdSuite::sign`, fails before signing if the `MethodType` of the
+  /// key is not supported by the signature suite.
+  pub fn sign_key<T>(
+    &self,
+    message: &mut T,
+    options: SignatureOptions,
+    secret: &SecretKey,
+  ) -> Result<()>
+  where
+    T: Serialize + SetSignature,
+  {
+    if !S::METHODS.contains(&secret.key_type()) {
+      return Err(Error::invalid_method_type(
+        &options.verification_method,
+        secret.key_type(),
+      ));
+    }
+
+    self.sign(message, options, secret)
+  }
+}
+
+impl<S> LdSuite<S>
+where
+  S: Encode + SuiteName,
+{
+  /// Signs `message` with a `Signer` holding the private key of the method
+  /// identified by `options`.
+  ///
+  /// The method is resolved from `message` using the `verificationMethod` and
+  /// `proofPurpose` of `options`.
+  pub fn sign_with<T, M, G>(
+    &self,
+    message: &mut T,
+    options: SignatureOptions,
+    signer: &G,
+  ) -> Result<()>
+  where
+    T: Serialize + SetSignature + ResolveMethod<M>,
+    G: Signer<M>,
+  {
+    let input: Vec<u8> = self.prepare(message, options)?;
+
+    let signature: Vec<u8> = {
+      let query: MethodQuery<'_> = message.try_signature()?.to_query()?;
+      let method: MethodWrap<'_, M> = message.try_resolve_method(query)?;
+
+      Signer::sign(signer, &input, method)?
+    };
+
+    self.finish(message, &signature)
+  }
+
+  /// Signs `message` with an `AsyncSigner` holding the private key of the
+  /// method identified by `options`.
+  ///
+  /// See `LdSuite::sign_with`.
+  pub async fn sign_with_async<T, M, G>(
+    &self,
+    message: &mut T,
+    options: SignatureOptions,
+    signer: &G,
+  ) -> Result<()>
+  where
+    T: Serialize + SetSignature + ResolveMethod<M>,
+    G: AsyncSigner<M>,
+  {
+    let input: Vec<u8> = self.prepare(message, options)?;
+
+    let signature: Vec<u8> = {
+      let query: MethodQuery<'_> = message.try_signature()?.to_query()?;
+      let method: MethodWrap<'_, M> = message.try_resolve_method(query)?;
+
+      AsyncSigner::sign(signer, &input, method).await?
+    };
+
+    self.finish(message, &signature)
+  }
+
+  fn prepare<T>(&self, message: &mut T, options: SignatureOptions) -> Result<Vec<u8>>
+  where
+    T: Serialize + SetSignature,
+  {
+    message.set_signature(Signature::new(self.suite.name(), options));
+
+    let input: Value = message.try_signature()?.verifiable(message)?;
+
+    self.suite.encode_input(&input)
+  }
+
+  fn finish<T>(&self, message: &mut T, signature: &[u8]) -> Result<()>
+  where
+    T: SetSignature,
+  {
+    let value: SignatureData = self.suite.encode_signature(signature)?;
 
     message.try_signature_mut()?.set_data(value);
 
@@ -48,35 +179,679 @@ impl<S> LdSuite<S>
 where
   S: Verify + SuiteName,
 {
-  pub fn verify<T, M>(&self, message: &T) -> Result<()>
+  pub fn verify<T, M, C>(&self, message: &T, policy: &VerificationPolicy<C>) -> Result<()>
   where
     T: Serialize + TrySignature + ResolveMethod<M>,
     M: Serialize,
+    C: Clock,
   {
-    self.verify_data(message, message)
+    self.verify_data(message, message, policy)
   }
 
-  pub fn verify_data<T, R, M>(&self, message: &T, resolver: R) -> Result<()>
+  pub fn verify_data<T, R, M, C>(
+    &self,
+    message: &T,
+    resolver: R,
+    policy: &VerificationPolicy<C>,
+  ) -> Result<()>
   where
     T: Serialize + TrySignature,
     R: ResolveMethod<M>,
     M: Serialize,
+    C: Clock,
+  {
+    let signature: &Signature = self.check_signature(message, policy)?;
+    let query: MethodQuery<'_> = signature.to_query()?;
+    let method: MethodWrap<'_, M> = resolver.try_resolve_method(query)?;
+
+    self.check_method(signature, &method)?;
+
+    let public: Vec<u8> = decode_key(&method)?;
+
+    signature
+      .verify(&self.suite, message, &public)
+      .map_err(|error| Error::invalid_signature(&signature.verification_method).with_source(error))
+  }
+
+  /// Verifies a `message` signed with a method of its subject or of its
+  /// controller.
+  ///
+  /// The signer is the DID of the `verificationMethod` of the signature; it
+  /// must be the subject of `message` or its `controller`, as defined by DID
+  /// Core. The controller document is resolved from `resolver`.
+  ///
+  /// Controllers of the controller are not authorized; see
+  /// [`LdSuite::verify_controller_chain`].
+  ///
+  /// # Errors
+  ///
+  /// Fails if the signer is not an authorized controller, or if the
+  /// controller document can not be resolved.
+  pub fn verify_controller<T, U, V, R, A, B, C>(
+    &self,
+    message: &VerifiableDocument<T, U, V>,
+    resolver: R,
+    policy: &VerificationPolicy<C>,
+  ) -> Result<()>
+  where
+    T: Serialize,
+    U: Serialize,
+    V: Serialize,
+    R: ResolveDocument<A, U, B>,
+    C: Clock,
+  {
+    self.verify_controllers(message, resolver, policy, false)
+  }
+
+  /// Verifies a `message` signed with a method of its subject or of any
+  /// controller reachable through the chain of `controller` properties.
+  ///
+  /// DID Core only defines the direct controller of a document; this is an
+  /// opt-in extension for deployments that delegate control transitively.
+  /// Controller documents are resolved from `resolver`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the signer is not an authorized controller, if a controller
+  /// document can not be resolved, or if the controller chain is circular.
+  pub fn verify_controller_chain<T, U, V, R, A, B, C>(
+    &self,
+    message: &VerifiableDocument<T, U, V>,
+    resolver: R,
+    policy: &VerificationPolicy<C>,
+  ) -> Result<()>
+  where
+    T: Serialize,
+    U: Serialize,
+    V: Serialize,
+    R: ResolveDocument<A, U, B>,
+    C: Clock,
+  {
+    self.verify_controllers(message, resolver, policy, true)
+  }
+
+  fn verify_controllers<T, U, V, R, A, B, C>(
+    &self,
+    message: &VerifiableDocument<T, U, V>,
+    resolver: R,
+    policy: &VerificationPolicy<C>,
+    transitive: bool,
+  ) -> Result<()>
+  where
+    T: Serialize,
+    U: Serialize,
+    V: Serialize,
+    R: ResolveDocument<A, U, B>,
+    C: Clock,
+  {
+    let signature: &Signature = self.check_signature(message, policy)?;
+
+    let method: &str = &signature.verification_method;
+
+    let mut signer: DID = if MethodIndex::is_did_url(method) {
+      DID::parse(method)
+    } else {
+      message.id().join(method)
+    }
+    .map_err(|error| Error::invalid_signature(method).with_source(error.into()))?;
+
+    signer.set_fragment(None);
+
+    if signer == *message.id() {
+      return self.verify_data(message, message, policy);
+    }
+
+    let unauthorized = |error: &'static str| -> Error {
+      Error::invalid_signature(method).with_source(Error::message(error))
+    };
+
+    let mut visited: Vec<&DID> = Vec::new();
+    let mut next: Option<&DID> = message.controller();
+
+    let document: &Document<A, U, B> = loop {
+      let did: &DID = next.ok_or_else(|| unauthorized(ERR_UC))?;
+
+      if did == message.id() || visited.contains(&did) {
+        return Err(unauthorized(ERR_CCC));
+      }
+
+      if *did == signer {
+        break resolver.try_resolve_document(did)?;
+      }
+
+      if !transitive {
+        return Err(unauthorized(ERR_UC));
+      }
+
+      visited.push(did);
+      next = resolver.try_resolve_document(did)?.controller();
+    };
+
+    self.verify_data(message, document, policy)
+  }
+
+  /// Returns the signature of `message` if it was created with this suite and
+  /// satisfies the `policy`.
+  pub(crate) fn check_signature<'a, T, C>(
+    &self,
+    message: &'a T,
+    policy: &VerificationPolicy<C>,
+  ) -> Result<&'a Signature>
+  where
+    T: TrySignature,
+    C: Clock,
   {
     let signature: &Signature = message.try_signature()?;
 
     if signature.type_() != self.suite.name() {
-      return Err(Error::message("Invalid Signature Type"));
+      return Err(
+        Error::invalid_signature(&signature.verification_method)
+          .with_source(Error::message(ERR_IST)),
+      );
     }
 
-    let query: MethodQuery<'_> = signature.to_query()?;
-    let method: MethodWrap<'_, M> = resolver.try_resolve_method(query)?;
+    policy.check(signature)?;
 
+    Ok(signature)
+  }
+
+  /// Checks that `signature` can be verified with the resolved `method`.
+  pub(crate) fn check_method<M>(
+    &self,
+    signature: &Signature,
+    method: &MethodWrap<'_, M>,
+  ) -> Result<()>
+  where
+    M: Serialize,
+  {
     if !S::METHODS.contains(&method.key_type()) {
-      return Err(Error::message("Invalid Method Type"));
+      return Err(Error::invalid_method_type(method.id(), method.key_type()));
     }
 
-    signature.verify(&self.suite, message, &method.key_data().try_decode()?)?;
+    self.suite.verify_method(signature, method)
+  }
+}
 
-    Ok(())
+/// Decodes the public key of `method`.
+pub(crate) fn decode_key<M>(method: &MethodWrap<'_, M>) -> Result<Vec<u8>> {
+  method
+    .key_data()
+    .try_decode_key(method.key_type())
+    .map_err(|error| Error::invalid_key(method.id()).with_source(error))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+  use core::convert::TryFrom;
+  use serde::Serialize;
+  use serde_json::to_value;
+  use serde_json::to_vec;
+
+  use did_url::DID;
+
+  use super::*;
+  use crate::document::Document;
+  use crate::document::DocumentBuilder;
+  use crate::verifiable::VerifiableDocument;
+  use crate::verification::MethodBuilder;
+  use crate::verification::MethodData;
+  use crate::verification::MethodScope;
+  use crate::verification::MethodType;
+
+  const SECRET: &[u8] = b"secret";
+  const CREATED: &str = "2021-01-01T00:00:00Z";
+
+  // A "signature" suite that appends the key to the signing input. This is
+  // obviously insecure but allows testing the signing flow without crypto.
+  pub(crate) struct TestSuite;
+
+  impl TestSuite {
+    fn digest<T>(data: &T, key: &[u8]) -> String
+    where
+      T: Serialize,
+    {
+      let mut input: Vec<u8> = to_vec(data).unwrap();
+      input.extend_from_slice(key);
+      hex::encode(input)
+    }
+  }
+
+  impl SuiteName for TestSuite {
+    fn name(&self) -> String {
+      "TestSuite".into()
+    }
+  }
+
+  impl Sign for TestSuite {
+    fn sign<T>(&self, data: &T, secret: &[u8]) -> Result<SignatureData>
+    where
+      T: Serialize,
+    {
+      Ok(SignatureData::Proof(Self::digest(data, secret)))
+    }
+  }
+
+  impl Verify for TestSuite {
+    const METHODS: &'static [MethodType] = &[MethodType::Ed25519VerificationKey2018];
+
+    fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+    where
+      T: Serialize,
+    {
+      if signature.try_proof() == Some(&*Self::digest(data, public)) {
+        Ok(())
+      } else {
+        Err(Error::message("Invalid Signature"))
+      }
+    }
+  }
+
+  pub(crate) fn document() -> VerifiableDocument {
+    let document: Document = DocumentBuilder::default()
+      .id("did:example:123".parse().unwrap())
+      .verification_method(
+        MethodBuilder::default()
+          .id("did:example:123#key-1".parse().unwrap())
+          .controller("did:example:123".parse().unwrap())
+          .key_type(MethodType::Ed25519VerificationKey2018)
+          .key_data(MethodData::new_b58(SECRET))
+          .build()
+          .unwrap(),
+      )
+      .assertion_method("did:example:123#key-1".parse::<DID>().unwrap())
+      .build()
+      .unwrap();
+
+    VerifiableDocument::new(document)
+  }
+
+  pub(crate) fn policy() -> VerificationPolicy<impl Clock + Sync> {
+    // 2021-01-01T01:00:00Z
+    VerificationPolicy::new(|| 1609462800)
+  }
+
+  fn sign(mut options: SignatureOptions) -> VerifiableDocument {
+    let mut document: VerifiableDocument = document();
+
+    options.created = Some(CREATED.parse().unwrap());
+
+    LdSuite::new(TestSuite)
+      .sign(&mut document, options, SECRET)
+      .unwrap();
+
+    document
+  }
+
+  pub(crate) fn signed() -> VerifiableDocument {
+    sign(SignatureOptions::with_purpose(
+      "#key-1".into(),
+      "assertionMethod".into(),
+    ))
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    let document: VerifiableDocument = signed();
+
+    assert!(document.proof().unwrap().data().is_proof());
+    assert!(LdSuite::new(TestSuite).verify(&document, &policy()).is_ok());
+  }
+
+  #[test]
+  fn test_sign_key() {
+    let mut options: SignatureOptions =
+      SignatureOptions::with_purpose("#key-1".into(), "assertionMethod".into());
+
+    options.created = Some(CREATED.parse().unwrap());
+
+    let suite: LdSuite<TestSuite> = LdSuite::new(TestSuite);
+    let mut document: VerifiableDocument = document();
+
+    let secret: SecretKey = SecretKey::from_bytes(MethodType::Ed25519VerificationKey2018, SECRET);
+
+    suite
+      .sign_key(&mut document, options.clone(), &secret)
+      .unwrap();
+
+    assert!(suite.verify(&document, &policy()).is_ok());
+
+    // The key type must be supported by the suite
+    let secret: SecretKey = SecretKey::from_bytes(MethodType::JsonWebKey2020, SECRET);
+    let mut document: VerifiableDocument = self::document();
+
+    assert_eq!(
+      suite.sign_key(&mut document, options, &secret),
+      Err(Error::invalid_method_type(
+        "#key-1",
+        MethodType::JsonWebKey2020
+      ))
+    );
+    assert!(document.proof().is_none());
+  }
+
+  #[test]
+  fn test_verify_serialized_timestamp() {
+    let mut document: VerifiableDocument = document();
+    let mut options: SignatureOptions =
+      SignatureOptions::with_purpose("#key-1".into(), "assertionMethod".into());
+
+    // A timestamp of another implementation, not in the form of `Timestamp::now`
+    options.created = Some("2021-01-01T00:30:00.500+00:30".parse().unwrap());
+
+    LdSuite::new(TestSuite)
+      .sign(&mut document, options, SECRET)
+      .unwrap();
+
+    let json: String = document.to_string();
+    let document: VerifiableDocument = serde_json::from_str(&json).unwrap();
+
+    assert!(json.contains("\"created\":\"2021-01-01T00:30:00.500+00:30\""));
+    assert!(LdSuite::new(TestSuite).verify(&document, &policy()).is_ok());
+  }
+
+  #[test]
+  fn test_verify_tampered() {
+    let mut document: VerifiableDocument = signed();
+
+    document
+      .properties_mut()
+      .insert("tampered".into(), true.into());
+
+    assert_eq!(
+      LdSuite::new(TestSuite).verify(&document, &policy()),
+      Err(Error::invalid_signature("#key-1").with_source(Error::message("Invalid Signature")))
+    );
+
+    let mut document: VerifiableDocument = signed();
+
+    // "#key-1" now resolves against the new DID; the reference to the old DID
+    // is not matched by fragment alone
+    *document.id_mut() = "did:example:456".parse().unwrap();
+
+    assert_eq!(
+      LdSuite::new(TestSuite).verify(&document, &policy()),
+      Err(Error::method_not_found(
+        "#key-1",
+        MethodScope::AssertionMethod
+      ))
+    );
+  }
+
+  #[test]
+  fn test_verify_invalid_method_type() {
+    struct OtherSuite;
+
+    impl SuiteName for OtherSuite {
+      fn name(&self) -> String {
+        TestSuite.name()
+      }
+    }
+
+    impl Verify for OtherSuite {
+      const METHODS: &'static [MethodType] = &[MethodType::JcsEd25519Key2020];
+
+      fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+      where
+        T: Serialize,
+      {
+        TestSuite.verify(data, signature, public)
+      }
+    }
+
+    assert_eq!(
+      LdSuite::new(OtherSuite).verify(&signed(), &policy()),
+      Err(Error::invalid_method_type(
+        "did:example:123#key-1",
+        MethodType::Ed25519VerificationKey2018
+      ))
+    );
+  }
+
+  #[test]
+  fn test_verify_policy() {
+    let mut options: SignatureOptions =
+      SignatureOptions::with_purpose("#key-1".into(), "assertionMethod".into());
+
+    options.challenge = Some("123".into());
+
+    let document: VerifiableDocument = sign(options);
+    let suite: LdSuite<TestSuite> = LdSuite::new(TestSuite);
+
+    assert!(suite.verify(&document, &policy().challenge("123")).is_ok());
+    assert!(suite.verify(&document, &policy().challenge("456")).is_err());
+  }
+
+  #[test]
+  fn test_verify_missing_purpose() {
+    let document: VerifiableDocument = sign(SignatureOptions::new("#key-1".into()));
+
+    assert!(LdSuite::new(TestSuite)
+      .verify(&document, &policy())
+      .is_err());
+  }
+
+  #[test]
+  fn test_verify_unauthorized_purpose() {
+    let document: VerifiableDocument = sign(SignatureOptions::with_purpose(
+      "#key-1".into(),
+      "authentication".into(),
+    ));
+
+    assert!(LdSuite::new(TestSuite)
+      .verify(&document, &policy())
+      .is_err());
+
+    let document: VerifiableDocument = sign(SignatureOptions::with_purpose(
+      "#key-1".into(),
+      "verificationMethod".into(),
+    ));
+
+    assert!(LdSuite::new(TestSuite)
+      .verify(&document, &policy())
+      .is_err());
+  }
+
+  #[test]
+  fn test_resolve_options() {
+    let document: VerifiableDocument = document();
+    let options: SignatureOptions = document
+      .resolve_options(("#key-1", MethodScope::AssertionMethod))
+      .unwrap();
+
+    assert_eq!(options.proof_purpose.as_deref(), Some("assertionMethod"));
+
+    // Options without a proof purpose can not be verified
+    assert_eq!(
+      document.resolve_options("#key-1"),
+      Err(Error::message("Invalid Proof Purpose"))
+    );
+
+    let method = document.resolve("#key-1").unwrap();
+
+    assert!(SignatureOptions::try_from(method).is_err());
+  }
+
+  #[test]
+  fn test_verifiable_input() {
+    let document: VerifiableDocument = signed();
+    let signature: &Signature = document.proof().unwrap();
+    let input: Value = signature.verifiable(&document).unwrap();
+
+    assert!(input["proof"].get("proofValue").is_none());
+    assert_eq!(input["proof"]["verificationMethod"], "#key-1");
+
+    // The signature value is never removed from the document itself.
+    assert!(!signature.data().is_none());
+
+    // Only the `proof` property is projected, not copies of the proof
+    let mut document: VerifiableDocument = document.clone();
+    let proof: Value = to_value(document.proof().unwrap()).unwrap();
+
+    document.properties_mut().insert("copy".into(), proof);
+
+    let input: Value = document.proof().unwrap().verifiable(&document).unwrap();
+
+    assert!(input["proof"].get("proofValue").is_none());
+    assert!(input["copy"].get("proofValue").is_some());
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_verify_concurrent() {
+    fn assert_sync<T: Send + Sync>(_: &T) {}
+
+    let document: VerifiableDocument = signed();
+
+    assert_sync(&document);
+
+    std::thread::scope(|scope| {
+      for _ in 0..4 {
+        scope.spawn(|| {
+          LdSuite::new(TestSuite)
+            .verify(&document, &policy())
+            .unwrap()
+        });
+      }
+    });
+  }
+
+  fn subject(controller: Option<&str>) -> VerifiableDocument {
+    let mut builder: DocumentBuilder =
+      DocumentBuilder::default().id("did:example:123".parse().unwrap());
+
+    if let Some(controller) = controller {
+      builder = builder.controller(controller.parse().unwrap());
+    }
+
+    let mut document: VerifiableDocument = VerifiableDocument::new(builder.build().unwrap());
+    let mut options: SignatureOptions =
+      SignatureOptions::with_purpose("did:example:ctrl#key-1".into(), "assertionMethod".into());
+
+    options.created = Some(CREATED.parse().unwrap());
+
+    LdSuite::new(TestSuite)
+      .sign(&mut document, options, SECRET)
+      .unwrap();
+
+    document
+  }
+
+  fn controller(id: &str, controller: Option<&str>) -> Document {
+    let did: DID = id.parse().unwrap();
+    let mut builder: DocumentBuilder = DocumentBuilder::default()
+      .id(did.clone())
+      .verification_method(
+        MethodBuilder::default()
+          .id(did.join("#key-1").unwrap())
+          .controller(did.clone())
+          .key_type(MethodType::Ed25519VerificationKey2018)
+          .key_data(MethodData::new_b58(SECRET))
+          .build()
+          .unwrap(),
+      )
+      .assertion_method(did.join("#key-1").unwrap());
+
+    if let Some(controller) = controller {
+      builder = builder.controller(controller.parse().unwrap());
+    }
+
+    builder.build().unwrap()
+  }
+
+  fn unauthorized(error: &'static str) -> Result<()> {
+    Err(Error::invalid_signature("did:example:ctrl#key-1").with_source(Error::message(error)))
+  }
+
+  fn verify_controller(document: &VerifiableDocument, resolver: &[Document]) -> Result<()> {
+    LdSuite::new(TestSuite).verify_controller(document, resolver, &policy())
+  }
+
+  #[test]
+  fn test_verify_controller() {
+    let resolver: &[Document] = &[controller("did:example:ctrl", None)];
+
+    assert_eq!(
+      verify_controller(&subject(Some("did:example:ctrl")), resolver),
+      Ok(())
+    );
+
+    // The signer must be a controller of the document
+    assert_eq!(
+      verify_controller(&subject(None), resolver),
+      unauthorized(ERR_UC)
+    );
+
+    // Signatures by the subject are verified with its own methods
+    assert!(verify_controller(&signed(), &[]).is_ok());
+  }
+
+  #[test]
+  fn test_verify_controller_chain() {
+    let resolver: &[Document] = &[
+      controller("did:example:a", Some("did:example:b")),
+      controller("did:example:b", Some("did:example:ctrl")),
+      controller("did:example:ctrl", None),
+    ];
+
+    let suite: LdSuite<TestSuite> = LdSuite::new(TestSuite);
+    let document: VerifiableDocument = subject(Some("did:example:a"));
+
+    assert!(suite
+      .verify_controller_chain(&document, resolver, &policy())
+      .is_ok());
+
+    // Only the direct controller is authorized by default
+    assert_eq!(verify_controller(&document, resolver), unauthorized(ERR_UC));
+
+    assert_eq!(
+      suite.verify_controller_chain(&document, &resolver[..1], &policy()),
+      Err(Error::document_not_found("did:example:b"))
+    );
+  }
+
+  #[test]
+  fn test_verify_controller_resolution_error() {
+    struct Unsupported;
+
+    impl ResolveDocument for Unsupported {
+      fn resolve_document(&self, _: &DID) -> Option<&Document> {
+        None
+      }
+
+      fn try_resolve_document(&self, did: &DID) -> Result<&Document> {
+        Err(Error::unsupported_did_method(did))
+      }
+    }
+
+    // Errors of the resolver are returned as-is
+    assert_eq!(
+      LdSuite::new(TestSuite).verify_controller(
+        &subject(Some("did:example:ctrl")),
+        &Unsupported,
+        &policy()
+      ),
+      Err(Error::unsupported_did_method("did:example:ctrl"))
+    );
+  }
+
+  #[test]
+  fn test_verify_controller_circular() {
+    let suite: LdSuite<TestSuite> = LdSuite::new(TestSuite);
+    let resolver: &[Document] = &[
+      controller("did:example:a", Some("did:example:b")),
+      controller("did:example:b", Some("did:example:a")),
+    ];
+
+    assert_eq!(
+      suite.verify_controller_chain(&subject(Some("did:example:a")), resolver, &policy()),
+      unauthorized(ERR_CCC)
+    );
+
+    let resolver: &[Document] = &[controller("did:example:a", Some("did:example:123"))];
+
+    assert_eq!(
+      suite.verify_controller_chain(&subject(Some("did:example:a")), resolver, &policy()),
+      unauthorized(ERR_CCC)
+    );
   }
 }
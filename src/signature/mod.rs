@@ -1,15 +1,25 @@
 #![allow(clippy::module_inception)]
 
-mod ld_suite;
+mod batch_verifier;
+mod clock;
+pub(crate) mod ld_suite;
+mod secret_key;
 mod signature;
 mod signature_data;
 mod signature_options;
 mod signature_value;
+mod signer;
 mod traits;
+mod verification_policy;
 
+pub use self::batch_verifier::*;
+pub use self::clock::*;
 pub use self::ld_suite::*;
+pub use self::secret_key::*;
 pub use self::signature::*;
 pub use self::signature_data::*;
 pub use self::signature_options::*;
 pub use self::signature_value::*;
+pub use self::signer::*;
 pub use self::traits::*;
+pub use self::verification_policy::*;
@@ -0,0 +1,208 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::utils::decode_multibase;
+use crate::utils::decode_multicodec;
+use crate::utils::Object;
+use crate::utils::Value;
+use crate::verification::MethodType;
+
+const ERR_MJD: &str = "Missing JWK Private Key";
+const ERR_IJD: &str = "Invalid JWK Private Key";
+const ERR_UMC: &str = "Unsupported Private Key Codec";
+
+const CODEC_ED25519: u64 = 0x1300;
+const CODEC_SECP256K1: u64 = 0x1301;
+const CODEC_P256: u64 = 0x1306;
+
+/// A private key of a verification method.
+///
+/// The key material is wiped from memory when the `SecretKey` is dropped, is
+/// redacted from `Debug` output, and is compared in constant time.
+#[derive(Clone)]
+pub struct SecretKey {
+  key_type: MethodType,
+  data: Vec<u8>,
+}
+
+impl SecretKey {
+  /// Creates a new `SecretKey` from raw private key bytes.
+  pub fn from_bytes(key_type: MethodType, data: impl Into<Vec<u8>>) -> Self {
+    Self {
+      key_type,
+      data: data.into(),
+    }
+  }
+
+  /// Creates a new `SecretKey` from the private key (`d`) of a JSON Web Key.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the JWK has no valid private key parameter.
+  pub fn from_jwk(key_type: MethodType, jwk: &Object) -> Result<Self> {
+    let data: &str = match jwk.get("d") {
+      Some(Value::String(data)) => data,
+      Some(_) => return Err(Error::message(ERR_IJD)),
+      None => return Err(Error::message(ERR_MJD)),
+    };
+
+    URL_SAFE_NO_PAD
+      .decode(data)
+      .map(|data| Self::from_bytes(key_type, data))
+      .map_err(|_| Error::message(ERR_IJD))
+  }
+
+  /// Creates a new `SecretKey` from a multibase-encoded, multicodec-prefixed
+  /// private key (e.g. `secretKeyMultibase`).
+  ///
+  /// # Errors
+  ///
+  /// Fails if the input is not valid multibase data or the multicodec does not
+  /// identify a private key of the given `MethodType`.
+  pub fn from_multibase(key_type: MethodType, input: &str) -> Result<Self> {
+    let mut data: Vec<u8> = decode_multibase(input)?;
+
+    let result: Result<Self> = match decode_multicodec(&data) {
+      Ok((codec, key)) if Self::codecs(key_type).contains(&codec) => {
+        Ok(Self::from_bytes(key_type, key))
+      }
+      Ok(_) => Err(Error::message(ERR_UMC)),
+      Err(error) => Err(error),
+    };
+
+    data.zeroize();
+    result
+  }
+
+  /// Returns the `MethodType` of the key.
+  pub const fn key_type(&self) -> MethodType {
+    self.key_type
+  }
+
+  /// Returns the raw private key bytes.
+  pub fn as_bytes(&self) -> &[u8] {
+    &self.data
+  }
+
+  // The multicodec identifiers of private keys for the given method type.
+  const fn codecs(key_type: MethodType) -> &'static [u64] {
+    match key_type {
+      MethodType::JcsEd25519Key2020 | MethodType::Ed25519VerificationKey2018 => &[CODEC_ED25519],
+      MethodType::EcdsaSecp256k1VerificationKey2019
+      | MethodType::EcdsaSecp256k1RecoveryMethod2020 => &[CODEC_SECP256K1],
+      // JSON Web Keys are not tied to a single curve
+      MethodType::JsonWebKey2020 | MethodType::JwsVerificationKey2020 => {
+        &[CODEC_ED25519, CODEC_SECP256K1, CODEC_P256]
+      }
+      _ => &[],
+    }
+  }
+}
+
+impl Drop for SecretKey {
+  fn drop(&mut self) {
+    self.data.zeroize();
+  }
+}
+
+impl Debug for SecretKey {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    f.debug_struct("SecretKey")
+      .field("key_type", &self.key_type)
+      .field("data", &"[REDACTED]")
+      .finish()
+  }
+}
+
+impl PartialEq for SecretKey {
+  fn eq(&self, other: &Self) -> bool {
+    self.key_type == other.key_type && bool::from(self.data.ct_eq(&other.data))
+  }
+}
+
+impl Eq for SecretKey {}
+
+impl AsRef<[u8]> for SecretKey {
+  fn as_ref(&self) -> &[u8] {
+    self.as_bytes()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::utils::encode_multicodec;
+
+  const KEY: [u8; 32] = [7; 32];
+
+  #[test]
+  fn test_from_jwk() {
+    let jwk: Object = serde_json::from_str(
+      r#"{"kty":"OKP","crv":"Ed25519","d":"BwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwc"}"#,
+    )
+    .unwrap();
+    let key: SecretKey = SecretKey::from_jwk(MethodType::Ed25519VerificationKey2018, &jwk).unwrap();
+
+    assert_eq!(key.as_bytes(), &KEY);
+    assert!(SecretKey::from_jwk(MethodType::Ed25519VerificationKey2018, &Object::new()).is_err());
+  }
+
+  #[test]
+  fn test_from_multibase() {
+    let input: &str = "z3u2RhErZEGEPQoX72wwNiAQiFxJjUEx7pmQ6djbr6Lbzgyt";
+    let key: SecretKey =
+      SecretKey::from_multibase(MethodType::Ed25519VerificationKey2018, input).unwrap();
+
+    assert_eq!(
+      key,
+      SecretKey::from_bytes(MethodType::Ed25519VerificationKey2018, KEY)
+    );
+
+    // The multicodec must match the method type
+    let input: &str = "z3vLUUKjdAhodVaF89QsXdcJEmHphtygq6qbufvrBuf6CXzW";
+
+    assert!(SecretKey::from_multibase(MethodType::Ed25519VerificationKey2018, input).is_err());
+  }
+
+  #[test]
+  fn test_from_multibase_p256() {
+    let input: String = alloc::format!(
+      "z{}",
+      bs58::encode(encode_multicodec(CODEC_P256, &KEY)).into_string()
+    );
+    let key: SecretKey = SecretKey::from_multibase(MethodType::JsonWebKey2020, &input).unwrap();
+
+    assert_eq!(key.as_bytes(), &KEY);
+    assert!(
+      SecretKey::from_multibase(MethodType::EcdsaSecp256k1VerificationKey2019, &input).is_err()
+    );
+  }
+
+  #[test]
+  fn test_debug_redacted() {
+    let key: SecretKey = SecretKey::from_bytes(MethodType::Ed25519VerificationKey2018, KEY);
+    let debug: String = alloc::format!("{:?}", key);
+
+    assert!(debug.contains("REDACTED"));
+    assert!(!debug.contains("7, 7"));
+  }
+
+  #[test]
+  fn test_eq() {
+    let a: SecretKey = SecretKey::from_bytes(MethodType::Ed25519VerificationKey2018, KEY);
+    let b: SecretKey = SecretKey::from_bytes(MethodType::JcsEd25519Key2020, KEY);
+    let c: SecretKey = SecretKey::from_bytes(MethodType::Ed25519VerificationKey2018, [8; 32]);
+
+    assert_eq!(a, a.clone());
+    assert_ne!(a, b);
+    assert_ne!(a, c);
+  }
+}
@@ -4,15 +4,24 @@ use core::fmt::Result as FmtResult;
 use core::ops::Deref;
 use core::ops::DerefMut;
 use serde::Serialize;
+use serde_json::to_value;
 
+use crate::error::Error;
 use crate::error::Result;
 use crate::lib::*;
 use crate::signature::SignatureData;
 use crate::signature::SignatureOptions;
 use crate::signature::SignatureValue;
 use crate::signature::Verify;
+use crate::utils::Value;
 use crate::verification::MethodIndex;
 use crate::verification::MethodQuery;
+use crate::verification::MethodScope;
+
+const ERR_SI: &str = "Invalid Signature Input";
+const ERR_MPP: &str = "Missing Proof Purpose";
+const ERR_IPP: &str = "Invalid Proof Purpose";
+const PROOF: &str = "proof";
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
 pub struct Signature {
@@ -34,7 +43,7 @@ impl Signature {
   }
 
   pub fn type_(&self) -> &str {
-    &*self.type_
+    &self.type_
   }
 
   pub const fn data(&self) -> &SignatureValue {
@@ -53,22 +62,34 @@ impl Signature {
     self.data.clear();
   }
 
-  pub fn hide_value(&self) {
-    self.data.hide();
-  }
-
-  pub fn show_value(&self) {
-    self.data.show();
+  /// Returns the verification relationship identified by the `proofPurpose`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the proof purpose is missing or not a verification relationship.
+  pub fn try_purpose(&self) -> Result<MethodScope> {
+    let scope: MethodScope = self
+      .proof_purpose
+      .as_deref()
+      .ok_or_else(|| Error::message(ERR_MPP))?
+      .parse()?;
+
+    if scope.is_relationship() {
+      Ok(scope)
+    } else {
+      Err(Error::message(ERR_IPP))
+    }
   }
 
+  /// Returns a `MethodQuery` for the verification method authorized to create
+  /// this `Signature`.
+  ///
+  /// The query is restricted to the verification relationship of the proof
+  /// purpose.
   pub fn to_query(&self) -> Result<MethodQuery<'_>> {
     let ident: MethodIndex<'_> = (&*self.verification_method).into();
 
-    if let Some(scope) = self.proof_purpose.as_deref() {
-      Ok(MethodQuery::with_scope(ident, scope.parse()?))
-    } else {
-      Ok(MethodQuery::new(ident))
-    }
+    Ok(MethodQuery::with_scope(ident, self.try_purpose()?))
   }
 
   pub fn verify<S, M>(&self, suite: &S, message: &M, public: &[u8]) -> Result<()>
@@ -76,20 +97,31 @@ impl Signature {
     S: Verify,
     M: Serialize,
   {
-    self.verifiable(|data| suite.verify(message, data, public))
+    suite.verify(&self.verifiable(message)?, self.data(), public)
   }
 
-  pub fn verifiable<T, F>(&self, f: F) -> T
+  /// Returns the signing and verification input of the given `message`.
+  ///
+  /// The input is a projection of the serialized `message` with the value
+  /// (`jws`, `proofValue`, or `signatureValue`) of this `Signature` removed
+  /// from the `proof` property; the `message` itself is never modified.
+  pub fn verifiable<M>(&self, message: &M) -> Result<Value>
   where
-    F: FnOnce(&SignatureValue) -> T,
+    M: Serialize,
   {
-    self.data.hide();
+    let mut input: Value = to_value(message).map_err(|_| Error::message(ERR_SI))?;
 
-    let output: T = f(self.data());
+    if !self.data.is_none() {
+      let this: Value = to_value(self).map_err(|_| Error::message(ERR_SI))?;
 
-    self.data.show();
+      if let Some(Value::Object(proof)) = input.get_mut(PROOF).filter(|proof| **proof == this) {
+        for key in SignatureData::KEYS {
+          proof.remove(*key);
+        }
+      }
+    }
 
-    output
+    Ok(input)
   }
 }
 
@@ -101,6 +133,8 @@ impl Debug for Signature {
       .field("verification_method", &self.options.verification_method)
       .field("proof_purpose", &self.options.proof_purpose)
       .field("created", &self.options.created)
+      .field("expires", &self.options.expires)
+      .field("challenge", &self.options.challenge)
       .field("nonce", &self.options.nonce)
       .field("domain", &self.options.domain)
       .finish()
@@ -13,6 +13,9 @@ pub enum SignatureData {
 }
 
 impl SignatureData {
+  /// The serialized property names of all signature value variants.
+  pub const KEYS: &'static [&'static str] = &["jws", "proofValue", "signatureValue"];
+
   pub const fn is_none(&self) -> bool {
     matches!(self, Self::None)
   }
@@ -32,16 +35,16 @@ impl SignatureData {
   pub fn as_str(&self) -> &str {
     match self {
       Self::None => "",
-      Self::Jws(inner) => &*inner,
-      Self::Proof(inner) => &*inner,
-      Self::Signature(inner) => &*inner,
+      Self::Jws(inner) => inner,
+      Self::Proof(inner) => inner,
+      Self::Signature(inner) => inner,
     }
   }
 
   pub fn try_jws(&self) -> Option<&str> {
     match self {
       Self::None => None,
-      Self::Jws(inner) => Some(&*inner),
+      Self::Jws(inner) => Some(inner),
       Self::Proof(_) => None,
       Self::Signature(_) => None,
     }
@@ -51,7 +54,7 @@ impl SignatureData {
     match self {
       Self::None => None,
       Self::Jws(_) => None,
-      Self::Proof(inner) => Some(&*inner),
+      Self::Proof(inner) => Some(inner),
       Self::Signature(_) => None,
     }
   }
@@ -61,7 +64,7 @@ impl SignatureData {
       Self::None => None,
       Self::Jws(_) => None,
       Self::Proof(_) => None,
-      Self::Signature(inner) => Some(&*inner),
+      Self::Signature(inner) => Some(inner),
     }
   }
 }
@@ -1,6 +1,14 @@
+use core::convert::TryFrom;
+
+use crate::error::Error;
+use crate::error::Result;
 use crate::lib::*;
+use crate::utils::Timestamp;
+use crate::verification::MethodScope;
 use crate::verification::MethodWrap;
 
+const ERR_IPP: &str = "Invalid Proof Purpose";
+
 #[derive(Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
 pub struct SignatureOptions {
   #[serde(rename = "verificationMethod")]
@@ -8,7 +16,11 @@ pub struct SignatureOptions {
   #[serde(rename = "proofPurpose", skip_serializing_if = "Option::is_none")]
   pub proof_purpose: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub created: Option<String>,
+  pub created: Option<Timestamp>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expires: Option<Timestamp>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub challenge: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   pub nonce: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -21,6 +33,8 @@ impl SignatureOptions {
       verification_method,
       proof_purpose: None,
       created: None,
+      expires: None,
+      challenge: None,
       nonce: None,
       domain: None,
     }
@@ -31,14 +45,37 @@ impl SignatureOptions {
       verification_method,
       proof_purpose: Some(proof_purpose),
       created: None,
+      expires: None,
+      challenge: None,
       nonce: None,
       domain: None,
     }
   }
+
+  /// Creates a new `SignatureOptions` with the proof purpose of the given
+  /// `MethodScope`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `scope` is not a verification relationship, e.g.
+  /// `MethodScope::VerificationMethod`; signatures without a proof purpose can
+  /// not be verified.
+  pub fn with_scope(verification_method: String, scope: MethodScope) -> Result<Self> {
+    if scope.is_relationship() {
+      Ok(Self::with_purpose(
+        verification_method,
+        scope.as_str().to_string(),
+      ))
+    } else {
+      Err(Error::message(ERR_IPP))
+    }
+  }
 }
 
-impl<T> From<MethodWrap<'_, T>> for SignatureOptions {
-  fn from(other: MethodWrap<'_, T>) -> Self {
-    Self::with_purpose(other.id().to_string(), other.scope().as_str().to_string())
+impl<T> TryFrom<MethodWrap<'_, T>> for SignatureOptions {
+  type Error = Error;
+
+  fn try_from(other: MethodWrap<'_, T>) -> Result<Self, Self::Error> {
+    Self::with_scope(other.id().to_string(), other.scope())
   }
 }
@@ -1,4 +1,3 @@
-use core::cell::Cell;
 use core::fmt::Debug;
 use core::fmt::Formatter;
 use core::fmt::Result;
@@ -7,24 +6,21 @@ use core::ops::DerefMut;
 
 use crate::signature::SignatureData;
 
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
 #[serde(transparent)]
 pub struct SignatureValue {
   data: SignatureData,
-  #[serde(skip)]
-  hide: Cell<bool>,
 }
 
 impl SignatureValue {
   pub const fn new() -> Self {
     Self {
       data: SignatureData::None,
-      hide: Cell::new(false),
     }
   }
 
   pub fn is_none(&self) -> bool {
-    self.data.is_none() || self.hide.get()
+    self.data.is_none()
   }
 
   pub fn set(&mut self, value: SignatureData) {
@@ -34,14 +30,6 @@ impl SignatureValue {
   pub fn clear(&mut self) {
     self.set(SignatureData::None);
   }
-
-  pub fn hide(&self) {
-    self.hide.set(true);
-  }
-
-  pub fn show(&self) {
-    self.hide.set(false);
-  }
 }
 
 impl Debug for SignatureValue {
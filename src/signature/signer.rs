@@ -0,0 +1,50 @@
+use core::future::ready;
+use core::future::Future;
+use core::pin::Pin;
+
+use crate::error::Result;
+use crate::lib::*;
+use crate::utils::Object;
+use crate::verification::MethodWrap;
+
+/// The future returned by `AsyncSigner::sign`.
+pub type SignFuture<'a> = Pin<Box<dyn Future<Output = Result<Vec<u8>>> + Send + 'a>>;
+
+/// A type that creates signatures with the private key of a verification
+/// method, without exposing the key material.
+pub trait Signer<M = Object> {
+  /// Signs the canonicalized `message` with the private key of `method` and
+  /// returns the raw signature bytes.
+  fn sign(&self, message: &[u8], method: MethodWrap<'_, M>) -> Result<Vec<u8>>;
+}
+
+impl<T, M> Signer<M> for &T
+where
+  T: Signer<M>,
+{
+  fn sign(&self, message: &[u8], method: MethodWrap<'_, M>) -> Result<Vec<u8>> {
+    (**self).sign(message, method)
+  }
+}
+
+// =============================================================================
+// =============================================================================
+
+/// An asynchronous `Signer`, e.g. a remote KMS or HSM.
+///
+/// All `Signer`s are also `AsyncSigner`s.
+pub trait AsyncSigner<M = Object> {
+  /// Signs the canonicalized `message` with the private key of `method` and
+  /// returns the raw signature bytes.
+  fn sign<'a>(&'a self, message: &'a [u8], method: MethodWrap<'a, M>) -> SignFuture<'a>;
+}
+
+impl<T, M> AsyncSigner<M> for T
+where
+  T: Signer<M> + Sync,
+  M: Sync,
+{
+  fn sign<'a>(&'a self, message: &'a [u8], method: MethodWrap<'a, M>) -> SignFuture<'a> {
+    Box::pin(ready(Signer::sign(self, message, method)))
+  }
+}
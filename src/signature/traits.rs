@@ -2,14 +2,17 @@ use serde::Serialize;
 
 use crate::error::Result;
 use crate::lib::*;
+use crate::signature::BatchItem;
+use crate::signature::Signature;
 use crate::signature::SignatureData;
 use crate::verification::MethodType;
+use crate::verification::MethodWrap;
 
 pub trait SuiteName {
   fn name(&self) -> String;
 }
 
-impl<'a, T> SuiteName for &'a T
+impl<T> SuiteName for &T
 where
   T: SuiteName,
 {
@@ -27,7 +30,7 @@ pub trait Sign {
     T: Serialize;
 }
 
-impl<'a, T> Sign for &'a T
+impl<T> Sign for &T
 where
   T: Sign,
 {
@@ -48,9 +51,35 @@ pub trait Verify {
   fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
   where
     T: Serialize;
+
+  /// Checks that `signature` can be verified with the resolved `method`, in
+  /// addition to its type being listed in `METHODS`.
+  fn verify_method<M>(&self, signature: &Signature, method: &MethodWrap<'_, M>) -> Result<()>
+  where
+    M: Serialize,
+  {
+    let _ = (signature, method);
+
+    Ok(())
+  }
+
+  /// Verifies many signatures at once; returns one result per item, in order.
+  ///
+  /// The default implementation verifies each item with `Verify::verify`;
+  /// suites override it to use batched signature checks where the scheme
+  /// allows it.
+  fn verify_batch<T>(&self, items: &[BatchItem<'_, T>]) -> Vec<Result<()>>
+  where
+    T: Serialize,
+  {
+    items
+      .iter()
+      .map(|item| self.verify(item.data, item.signature, item.public))
+      .collect()
+  }
 }
 
-impl<'a, T> Verify for &'a T
+impl<T> Verify for &T
 where
   T: Verify,
 {
@@ -62,4 +91,50 @@ where
   {
     (**self).verify(data, signature, public)
   }
+
+  fn verify_method<M>(&self, signature: &Signature, method: &MethodWrap<'_, M>) -> Result<()>
+  where
+    M: Serialize,
+  {
+    (**self).verify_method(signature, method)
+  }
+
+  fn verify_batch<U>(&self, items: &[BatchItem<'_, U>]) -> Vec<Result<()>>
+  where
+    U: Serialize,
+  {
+    (**self).verify_batch(items)
+  }
+}
+
+// =============================================================================
+// =============================================================================
+
+/// A signature suite that separates the encoding of signing input and
+/// signature values from the signing operation, which is delegated to a
+/// `Signer`.
+pub trait Encode {
+  /// Returns the canonicalized signing input of `data`.
+  fn encode_input<T>(&self, data: &T) -> Result<Vec<u8>>
+  where
+    T: Serialize;
+
+  /// Returns the `SignatureData` representation of a raw `signature`.
+  fn encode_signature(&self, signature: &[u8]) -> Result<SignatureData>;
+}
+
+impl<T> Encode for &T
+where
+  T: Encode,
+{
+  fn encode_input<U>(&self, data: &U) -> Result<Vec<u8>>
+  where
+    U: Serialize,
+  {
+    (**self).encode_input(data)
+  }
+
+  fn encode_signature(&self, signature: &[u8]) -> Result<SignatureData> {
+    (**self).encode_signature(signature)
+  }
 }
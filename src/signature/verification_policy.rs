@@ -0,0 +1,183 @@
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::signature::Clock;
+use crate::signature::Signature;
+use crate::utils::Timestamp;
+
+const ERR_IC: &str = "Invalid Signature Challenge";
+const ERR_ID: &str = "Invalid Signature Domain";
+const ERR_MCT: &str = "Missing Signature Created Timestamp";
+const ERR_SCF: &str = "Signature Created In The Future";
+const ERR_SE: &str = "Signature Expired";
+
+/// Conditions a `Signature` must satisfy, in addition to a valid signature
+/// value, to be accepted by `LdSuite::verify`.
+#[derive(Clone, Debug)]
+pub struct VerificationPolicy<C> {
+  clock: C,
+  challenge: Option<String>,
+  domain: Option<String>,
+  skew: i64,
+}
+
+impl<C> VerificationPolicy<C> {
+  /// The default tolerated clock skew, in seconds.
+  pub const DEFAULT_SKEW: i64 = 300;
+
+  /// Creates a new `VerificationPolicy` using the given `Clock`.
+  pub fn new(clock: C) -> Self {
+    Self {
+      clock,
+      challenge: None,
+      domain: None,
+      skew: Self::DEFAULT_SKEW,
+    }
+  }
+
+  /// Sets the challenge (`challenge` or `nonce`) the signature must include.
+  #[must_use]
+  pub fn challenge(mut self, value: impl Into<String>) -> Self {
+    self.challenge = Some(value.into());
+    self
+  }
+
+  /// Sets the domain the signature must be restricted to.
+  #[must_use]
+  pub fn domain(mut self, value: impl Into<String>) -> Self {
+    self.domain = Some(value.into());
+    self
+  }
+
+  /// Sets the tolerated clock skew, in seconds.
+  #[must_use]
+  pub fn skew(mut self, value: i64) -> Self {
+    self.skew = value;
+    self
+  }
+}
+
+impl<C> VerificationPolicy<C>
+where
+  C: Clock,
+{
+  /// Checks the options of the given `Signature` against the policy.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the challenge or domain do not match the expected values, if the
+  /// `created` timestamp is missing or in the future, or if the signature has
+  /// expired.
+  pub fn check(&self, signature: &Signature) -> Result<()> {
+    if let Some(challenge) = self.challenge.as_deref() {
+      let value: Option<&str> = signature
+        .challenge
+        .as_deref()
+        .or_else(|| signature.nonce.as_deref());
+
+      if value != Some(challenge) {
+        return Err(Error::message(ERR_IC));
+      }
+    }
+
+    if let Some(domain) = self.domain.as_deref() {
+      if signature.domain.as_deref() != Some(domain) {
+        return Err(Error::message(ERR_ID));
+      }
+    }
+
+    let now: i64 = self.clock.now();
+
+    let created: &Timestamp = signature
+      .created
+      .as_ref()
+      .ok_or_else(|| Error::message(ERR_MCT))?;
+
+    if created.to_unix() > now.saturating_add(self.skew) {
+      return Err(Error::message(ERR_SCF));
+    }
+
+    if let Some(expires) = signature.expires.as_ref() {
+      if expires.to_unix() < now.saturating_sub(self.skew) {
+        return Err(Error::message(ERR_SE));
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(feature = "std")]
+impl Default for VerificationPolicy<crate::signature::SystemClock> {
+  fn default() -> Self {
+    Self::new(crate::signature::SystemClock)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::signature::SignatureOptions;
+
+  // 2021-01-01T00:00:00Z
+  const NOW: i64 = 1609459200;
+
+  fn policy() -> VerificationPolicy<impl Clock> {
+    VerificationPolicy::new(|| NOW)
+  }
+
+  fn signature(created: Option<&str>, expires: Option<&str>) -> Signature {
+    let mut options: SignatureOptions = SignatureOptions::new("#key-1".into());
+
+    options.created = created.map(|value| value.parse().unwrap());
+    options.expires = expires.map(|value| value.parse().unwrap());
+    options.challenge = Some("abc".into());
+    options.domain = Some("example.com".into());
+
+    Signature::new("TestSuite", options)
+  }
+
+  #[test]
+  fn test_valid() {
+    let signature: Signature =
+      signature(Some("2020-12-31T23:00:00Z"), Some("2021-01-02T00:00:00Z"));
+
+    assert!(policy().check(&signature).is_ok());
+    assert!(policy()
+      .challenge("abc")
+      .domain("example.com")
+      .check(&signature)
+      .is_ok());
+  }
+
+  #[test]
+  fn test_invalid_challenge_domain() {
+    let signature: Signature = signature(Some("2020-12-31T23:00:00Z"), None);
+
+    assert!(policy().challenge("xyz").check(&signature).is_err());
+    assert!(policy().domain("example.org").check(&signature).is_err());
+  }
+
+  #[test]
+  fn test_invalid_created() {
+    assert!(policy().check(&signature(None, None)).is_err());
+    assert!(policy()
+      .check(&signature(Some("2021-01-01T01:00:00Z"), None))
+      .is_err());
+    assert!(policy()
+      .check(&signature(Some("2021-01-01T00:04:00Z"), None))
+      .is_ok());
+    assert!(policy()
+      .skew(0)
+      .check(&signature(Some("2021-01-01T00:04:00Z"), None))
+      .is_err());
+  }
+
+  #[test]
+  fn test_expired() {
+    let signature: Signature =
+      signature(Some("2020-12-01T00:00:00Z"), Some("2020-12-31T00:00:00Z"));
+
+    assert!(policy().check(&signature).is_err());
+  }
+}
@@ -0,0 +1,835 @@
+//! BBS signatures and proofs of knowledge with the `BLS12-381-SHA-256`
+//! ciphersuite of the IETF BBS signature scheme draft
+//! (draft-irtf-cfrg-bbs-signatures), mapping messages to scalars with the
+//! ciphersuite's hash-to-scalar operation.
+//!
+//! Secret keys and scalars are big-endian; points are compressed.
+
+use bls12_381::hash_to_curve::ExpandMessageState as _;
+use bls12_381::hash_to_curve::ExpandMsgXmd;
+use bls12_381::hash_to_curve::HashToCurve;
+use bls12_381::hash_to_curve::HashToField;
+use bls12_381::hash_to_curve::InitExpandMessage;
+use bls12_381::pairing;
+use bls12_381::G1Affine;
+use bls12_381::G1Projective;
+use bls12_381::G2Affine;
+use bls12_381::G2Projective;
+use bls12_381::Scalar;
+use core::convert::TryInto;
+use rand_core::CryptoRng;
+use rand_core::RngCore;
+use sha2_09::Sha256;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+
+const ERR_ISK: &str = "Invalid BBS Secret Key";
+const ERR_IPK: &str = "Invalid BBS Public Key";
+const ERR_IS: &str = "Invalid BBS Signature";
+const ERR_IP: &str = "Invalid BBS Proof";
+
+type Xmd = ExpandMsgXmd<Sha256>;
+
+// The ciphersuite identifier and domain separation tags.
+const API_ID: &[u8] = b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_H2G_HM2S_";
+const H2S_DST: &[u8] = b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_H2G_HM2S_H2S_";
+const MAP_DST: &[u8] = b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_H2G_HM2S_MAP_MSG_TO_SCALAR_AS_HASH_";
+const SEED_DST: &[u8] = b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_H2G_HM2S_SIG_GENERATOR_SEED_";
+const GENERATOR_DST: &[u8] = b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_H2G_HM2S_SIG_GENERATOR_DST_";
+const GENERATOR_SEED: &[u8] = b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_H2G_HM2S_MESSAGE_GENERATOR_SEED";
+
+// The base point `P1` of the ciphersuite.
+const P1: [u8; G1_LEN] = [
+  0xa8, 0xce, 0x25, 0x61, 0x02, 0x84, 0x08, 0x21, 0xa3, 0xe9, 0x4e, 0xa9, 0x02, 0x5e, 0x46, 0x62,
+  0xb2, 0x05, 0x76, 0x2f, 0x97, 0x76, 0xb3, 0xa7, 0x66, 0xc8, 0x72, 0xb9, 0x48, 0xf1, 0xfd, 0x22,
+  0x5e, 0x7c, 0x59, 0x69, 0x85, 0x88, 0xe7, 0x0d, 0x11, 0x40, 0x6d, 0x16, 0x1b, 0x4e, 0x28, 0xc9,
+];
+
+const EXPAND_LEN: usize = 48;
+const G1_LEN: usize = 48;
+const SCALAR_LEN: usize = 32;
+
+/// The length of a signature; `A || e`.
+pub(crate) const SIGNATURE_LEN: usize = G1_LEN + SCALAR_LEN;
+// The size of a proof without the scalars of the undisclosed messages
+const PROOF_LEN: usize = 3 * G1_LEN + 4 * SCALAR_LEN;
+
+// =============================================================================
+// Encoding
+// =============================================================================
+
+fn decode_secret(secret: &[u8]) -> Result<Scalar> {
+  decode_scalar(secret, ERR_ISK)
+}
+
+fn decode_public(public: &[u8]) -> Result<G2Affine> {
+  public
+    .try_into()
+    .ok()
+    .and_then(|bytes| Option::from(G2Affine::from_compressed(bytes)))
+    .filter(|point: &G2Affine| !bool::from(point.is_identity()))
+    .ok_or_else(|| Error::message(ERR_IPK))
+}
+
+fn decode_g1(data: &[u8], error: &'static str) -> Result<G1Affine> {
+  data
+    .try_into()
+    .ok()
+    .and_then(|bytes| Option::from(G1Affine::from_compressed(bytes)))
+    .filter(|point: &G1Affine| !bool::from(point.is_identity()))
+    .ok_or_else(|| Error::message(error))
+}
+
+// Decodes a big-endian scalar; zero is not a valid key, signature, or proof
+// scalar.
+fn decode_scalar(data: &[u8], error: &'static str) -> Result<Scalar> {
+  let mut bytes: [u8; SCALAR_LEN] = data.try_into().map_err(|_| Error::message(error))?;
+
+  bytes.reverse();
+
+  Option::from(Scalar::from_bytes(&bytes))
+    .filter(|scalar: &Scalar| *scalar != Scalar::zero())
+    .ok_or_else(|| Error::message(error))
+}
+
+fn encode_scalar(scalar: &Scalar) -> [u8; SCALAR_LEN] {
+  let mut bytes: [u8; SCALAR_LEN] = scalar.to_bytes();
+
+  bytes.reverse();
+  bytes
+}
+
+fn encode_g1(point: &G1Projective) -> [u8; G1_LEN] {
+  G1Affine::from(point).to_compressed()
+}
+
+// A reader of fixed-size proof components.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+  fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+    if self.0.len() < len {
+      return Err(Error::message(ERR_IP));
+    }
+
+    let (head, tail): (&[u8], &[u8]) = self.0.split_at(len);
+
+    self.0 = tail;
+
+    Ok(head)
+  }
+
+  fn g1(&mut self) -> Result<G1Affine> {
+    self.take(G1_LEN).and_then(|data| decode_g1(data, ERR_IP))
+  }
+
+  fn scalar(&mut self) -> Result<Scalar> {
+    self
+      .take(SCALAR_LEN)
+      .and_then(|data| decode_scalar(data, ERR_IP))
+  }
+}
+
+// =============================================================================
+// Hashing
+// =============================================================================
+
+fn expand_message(message: &[u8], dst: &[u8], output: &mut [u8]) {
+  Xmd::init_expand(message, dst, output.len()).read_into(output);
+}
+
+fn hash_to_scalar(message: &[u8], dst: &[u8]) -> Scalar {
+  let mut output: [Scalar; 1] = [Scalar::zero()];
+
+  Scalar::hash_to_field::<Xmd>(message, dst, &mut output);
+
+  output[0]
+}
+
+/// Maps an arbitrary message to a scalar.
+pub(crate) fn message_scalar(message: &[u8]) -> Scalar {
+  hash_to_scalar(message, MAP_DST)
+}
+
+fn random_scalar<R>(rng: &mut R) -> Scalar
+where
+  R: RngCore + CryptoRng,
+{
+  let mut bytes: [u8; EXPAND_LEN] = [0; EXPAND_LEN];
+
+  rng.fill_bytes(&mut bytes);
+
+  scalar_from_okm(&bytes)
+}
+
+// Reduces big-endian bytes modulo `r` (`OS2IP(bytes) mod r`).
+fn scalar_from_okm(bytes: &[u8; EXPAND_LEN]) -> Scalar {
+  let mut wide: [u8; 64] = [0; 64];
+
+  for (output, byte) in wide.iter_mut().zip(bytes.iter().rev()) {
+    *output = *byte;
+  }
+
+  Scalar::from_bytes_wide(&wide)
+}
+
+// The generators `Q_1` and `H_1, ..., H_L`.
+struct Generators {
+  q1: G1Projective,
+  h: Vec<G1Projective>,
+}
+
+impl Generators {
+  fn new(count: usize) -> Self {
+    let mut seed: [u8; EXPAND_LEN] = [0; EXPAND_LEN];
+    let mut points: Vec<G1Projective> = Vec::with_capacity(count + 1);
+
+    expand_message(GENERATOR_SEED, SEED_DST, &mut seed);
+
+    for index in 1..=count as u64 + 1 {
+      let input: Vec<u8> = [&seed[..], &index.to_be_bytes()].concat();
+
+      expand_message(&input, SEED_DST, &mut seed);
+
+      points.push(<G1Projective as HashToCurve<Xmd>>::hash_to_curve(
+        seed,
+        GENERATOR_DST,
+      ));
+    }
+
+    Self {
+      q1: points.remove(0),
+      h: points,
+    }
+  }
+
+  // Returns `P1 + Q_1 * domain + H_1 * m_1 + ... + H_L * m_L` for the given
+  // subset of indexed messages.
+  fn commit<'a, I>(&self, domain: &Scalar, messages: I) -> G1Projective
+  where
+    I: IntoIterator<Item = (usize, &'a Scalar)>,
+  {
+    let p1: G1Affine = Option::from(G1Affine::from_compressed(&P1)).expect("infallible");
+
+    messages.into_iter().fold(
+      G1Projective::from(p1) + self.q1 * domain,
+      |output, (index, message)| output + self.h[index] * message,
+    )
+  }
+
+  // The signature domain; binds the public key, the generators, and the
+  // `header`.
+  fn domain(&self, public: &G2Affine, header: &[u8]) -> Scalar {
+    let mut input: Vec<u8> = public.to_compressed().to_vec();
+
+    input.extend_from_slice(&(self.h.len() as u64).to_be_bytes());
+    input.extend_from_slice(&encode_g1(&self.q1));
+
+    for point in &self.h {
+      input.extend_from_slice(&encode_g1(point));
+    }
+
+    input.extend_from_slice(API_ID);
+    input.extend_from_slice(&(header.len() as u64).to_be_bytes());
+    input.extend_from_slice(header);
+
+    hash_to_scalar(&input, H2S_DST)
+  }
+}
+
+// =============================================================================
+// Signatures
+// =============================================================================
+
+/// Returns the compressed G2 public key of a big-endian `secret` scalar.
+pub(crate) fn public_key(secret: &[u8]) -> Result<Vec<u8>> {
+  let secret: Scalar = decode_secret(secret)?;
+
+  Ok(
+    G2Affine::from(G2Projective::generator() * secret)
+      .to_compressed()
+      .to_vec(),
+  )
+}
+
+/// Signs a vector of `messages` bound to a `header`.
+pub(crate) fn sign(secret: &[u8], header: &[u8], messages: &[Scalar]) -> Result<Vec<u8>> {
+  let secret_key: Scalar = decode_secret(secret)?;
+  let public: G2Affine = G2Affine::from(G2Projective::generator() * secret_key);
+  let generators: Generators = Generators::new(messages.len());
+  let domain: Scalar = generators.domain(&public, header);
+
+  // e = hash_to_scalar(SK || msg_1 || ... || msg_L || domain)
+  let mut input: Vec<u8> = Vec::with_capacity((messages.len() + 2) * SCALAR_LEN);
+
+  input.extend_from_slice(&encode_scalar(&secret_key));
+
+  for message in messages {
+    input.extend_from_slice(&encode_scalar(message));
+  }
+
+  input.extend_from_slice(&encode_scalar(&domain));
+
+  let e: Scalar = hash_to_scalar(&input, H2S_DST);
+  let b: G1Projective = generators.commit(&domain, messages.iter().enumerate());
+
+  let inverse: Scalar =
+    Option::from((secret_key + e).invert()).ok_or_else(|| Error::message(ERR_ISK))?;
+  let a: G1Projective = b * inverse;
+
+  if bool::from(a.is_identity()) {
+    return Err(Error::message(ERR_ISK));
+  }
+
+  let mut output: Vec<u8> = Vec::with_capacity(SIGNATURE_LEN);
+
+  output.extend_from_slice(&encode_g1(&a));
+  output.extend_from_slice(&encode_scalar(&e));
+
+  Ok(output)
+}
+
+/// Verifies a signature of `messages` bound to a `header`.
+pub(crate) fn verify(
+  public: &[u8],
+  header: &[u8],
+  messages: &[Scalar],
+  signature: &[u8],
+) -> Result<()> {
+  let w: G2Affine = decode_public(public)?;
+
+  let mut reader: Reader<'_> = Reader(signature);
+  let a: G1Affine = reader.g1().map_err(|_| Error::message(ERR_IS))?;
+  let e: Scalar = reader.scalar().map_err(|_| Error::message(ERR_IS))?;
+
+  if !reader.0.is_empty() {
+    return Err(Error::message(ERR_IS));
+  }
+
+  let generators: Generators = Generators::new(messages.len());
+  let domain: Scalar = generators.domain(&w, header);
+  let b: G1Projective = generators.commit(&domain, messages.iter().enumerate());
+
+  // e(A, W + P2 * e) == e(B, P2)
+  let lhs = pairing(&a, &G2Affine::from(G2Projective::generator() * e + w));
+  let rhs = pairing(&G1Affine::from(b), &G2Affine::generator());
+
+  if lhs == rhs {
+    Ok(())
+  } else {
+    Err(Error::message(ERR_IS))
+  }
+}
+
+// =============================================================================
+// Proofs
+// =============================================================================
+
+// Returns `true` if the `indexes` are strictly increasing and below `count`.
+fn is_valid_indexes<I>(indexes: I, count: usize) -> bool
+where
+  I: IntoIterator<Item = usize>,
+{
+  let mut next: usize = 0;
+
+  for index in indexes {
+    if index < next || index >= count {
+      return false;
+    }
+
+    next = index + 1;
+  }
+
+  true
+}
+
+// The proof challenge; binds the disclosed messages, the commitments, the
+// signature domain, and the presentation header.
+fn challenge(
+  points: &[G1Projective; 5],
+  disclosed: &[(usize, &Scalar)],
+  domain: &Scalar,
+  presentation: &[u8],
+) -> Scalar {
+  let mut input: Vec<u8> = Vec::new();
+
+  input.extend_from_slice(&(disclosed.len() as u64).to_be_bytes());
+
+  for (index, message) in disclosed {
+    input.extend_from_slice(&(*index as u64).to_be_bytes());
+    input.extend_from_slice(&encode_scalar(message));
+  }
+
+  for point in points {
+    input.extend_from_slice(&encode_g1(point));
+  }
+
+  input.extend_from_slice(&encode_scalar(domain));
+  input.extend_from_slice(&(presentation.len() as u64).to_be_bytes());
+  input.extend_from_slice(presentation);
+
+  hash_to_scalar(&input, H2S_DST)
+}
+
+/// Creates a zero-knowledge proof of a signature of `messages` that discloses
+/// only the messages at the sorted `disclosed` indexes.
+///
+/// The proof is bound to the `presentation` header.
+pub(crate) fn prove<R>(
+  public: &[u8],
+  signature: &[u8],
+  header: &[u8],
+  presentation: &[u8],
+  messages: &[Scalar],
+  disclosed: &[usize],
+  rng: &mut R,
+) -> Result<Vec<u8>>
+where
+  R: RngCore + CryptoRng,
+{
+  if !is_valid_indexes(disclosed.iter().copied(), messages.len()) {
+    return Err(Error::message(ERR_IP));
+  }
+
+  let count: usize = 5 + messages.len() - disclosed.len();
+  let random: Vec<Scalar> = (0..count).map(|_| random_scalar(rng)).collect();
+
+  prove_with(
+    public,
+    signature,
+    header,
+    presentation,
+    messages,
+    disclosed,
+    &random,
+  )
+}
+
+// Creates a proof with the given random scalars; `r1, r2, e~, r1~, r3~` and
+// one `m~` per undisclosed message.
+fn prove_with(
+  public: &[u8],
+  signature: &[u8],
+  header: &[u8],
+  presentation: &[u8],
+  messages: &[Scalar],
+  disclosed: &[usize],
+  random: &[Scalar],
+) -> Result<Vec<u8>> {
+  verify(public, header, messages, signature)?;
+
+  let w: G2Affine = decode_public(public)?;
+  let a: G1Affine = decode_g1(&signature[..G1_LEN], ERR_IS)?;
+  let e: Scalar = decode_scalar(&signature[G1_LEN..], ERR_IS)?;
+
+  let generators: Generators = Generators::new(messages.len());
+  let domain: Scalar = generators.domain(&w, header);
+  let b: G1Projective = generators.commit(&domain, messages.iter().enumerate());
+
+  let undisclosed: Vec<usize> = (0..messages.len())
+    .filter(|index| disclosed.binary_search(index).is_err())
+    .collect();
+
+  let (r1, r2, e_tilde, r1_tilde, r3_tilde, m_tilde) = match random {
+    [r1, r2, e_tilde, r1_tilde, r3_tilde, m_tilde @ ..] if m_tilde.len() == undisclosed.len() => {
+      (r1, r2, e_tilde, r1_tilde, r3_tilde, m_tilde)
+    }
+    _ => return Err(Error::message(ERR_IP)),
+  };
+
+  let r3: Scalar = Option::from(r2.invert()).ok_or_else(|| Error::message(ERR_IP))?;
+
+  let d: G1Projective = b * r2;
+  let a_bar: G1Projective = a * (r1 * r2);
+  let b_bar: G1Projective = d * r1 - a_bar * e;
+
+  let t1: G1Projective = a_bar * e_tilde + d * r1_tilde;
+  let t2: G1Projective = undisclosed
+    .iter()
+    .zip(m_tilde.iter())
+    .fold(d * r3_tilde, |output, (index, m)| {
+      output + generators.h[*index] * m
+    });
+
+  let revealed: Vec<(usize, &Scalar)> = disclosed
+    .iter()
+    .map(|index| (*index, &messages[*index]))
+    .collect();
+  let c: Scalar = challenge(&[a_bar, b_bar, d, t1, t2], &revealed, &domain, presentation);
+
+  let mut output: Vec<u8> = Vec::new();
+
+  for point in [a_bar, b_bar, d] {
+    output.extend_from_slice(&encode_g1(&point));
+  }
+
+  for scalar in [e_tilde + e * c, r1_tilde - r1 * c, r3_tilde - r3 * c] {
+    output.extend_from_slice(&encode_scalar(&scalar));
+  }
+
+  for (index, m) in undisclosed.iter().zip(m_tilde.iter()) {
+    output.extend_from_slice(&encode_scalar(&(m + messages[*index] * c)));
+  }
+
+  output.extend_from_slice(&encode_scalar(&c));
+
+  Ok(output)
+}
+
+/// Verifies a proof of a signature of `count` messages, of which the indexed
+/// `disclosed` messages are revealed.
+pub(crate) fn verify_proof(
+  public: &[u8],
+  proof: &[u8],
+  header: &[u8],
+  presentation: &[u8],
+  count: usize,
+  disclosed: &[(usize, Scalar)],
+) -> Result<()> {
+  // `count` is untrusted; it must match the number of scalars in the proof
+  // before anything is allocated or derived for `count` messages
+  let scalars: Option<usize> = proof
+    .len()
+    .checked_sub(PROOF_LEN)
+    .filter(|len| len % SCALAR_LEN == 0)
+    .map(|len| len / SCALAR_LEN);
+
+  if scalars.is_none() || scalars != count.checked_sub(disclosed.len()) {
+    return Err(Error::message(ERR_IP));
+  }
+
+  let w: G2Affine = decode_public(public)?;
+
+  if !is_valid_indexes(disclosed.iter().map(|(index, _)| *index), count) {
+    return Err(Error::message(ERR_IP));
+  }
+
+  let mut reader: Reader<'_> = Reader(proof);
+
+  let a_bar: G1Projective = reader.g1()?.into();
+  let b_bar: G1Projective = reader.g1()?.into();
+  let d: G1Projective = reader.g1()?.into();
+  let e_hat: Scalar = reader.scalar()?;
+  let r1_hat: Scalar = reader.scalar()?;
+  let r3_hat: Scalar = reader.scalar()?;
+
+  let undisclosed: Vec<usize> = (0..count)
+    .filter(|index| !disclosed.iter().any(|(disclosed, _)| disclosed == index))
+    .collect();
+
+  let m_hat: Vec<Scalar> = undisclosed
+    .iter()
+    .map(|_| reader.scalar())
+    .collect::<Result<_>>()?;
+
+  let c: Scalar = reader.scalar()?;
+
+  if !reader.0.is_empty() {
+    return Err(Error::message(ERR_IP));
+  }
+
+  let generators: Generators = Generators::new(count);
+  let domain: Scalar = generators.domain(&w, header);
+
+  let t1: G1Projective = b_bar * c + a_bar * e_hat + d * r1_hat;
+  let bv: G1Projective = generators.commit(&domain, disclosed.iter().map(|(index, m)| (*index, m)));
+  let t2: G1Projective = undisclosed
+    .iter()
+    .zip(m_hat.iter())
+    .fold(bv * c + d * r3_hat, |output, (index, m)| {
+      output + generators.h[*index] * m
+    });
+
+  let revealed: Vec<(usize, &Scalar)> = disclosed.iter().map(|(index, m)| (*index, m)).collect();
+
+  if challenge(&[a_bar, b_bar, d, t1, t2], &revealed, &domain, presentation) != c {
+    return Err(Error::message(ERR_IP));
+  }
+
+  // e(Abar, W) == e(Bbar, P2)
+  let lhs = pairing(&G1Affine::from(a_bar), &w);
+  let rhs = pairing(&G1Affine::from(b_bar), &G2Affine::generator());
+
+  if lhs == rhs {
+    Ok(())
+  } else {
+    Err(Error::message(ERR_IP))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Test vectors from draft-irtf-cfrg-bbs-signatures, Section 8.2 (BLS12-381-SHA-256)
+  const SECRET: &str = "60e55110f76883a13d030b2f6bd11883422d5abde717569fc0731f51237169fc";
+  const PUBLIC: &str = "a820f230f6ae38503b86c70dc50b61c58a77e45c39ab25c0652bbaa8fa136f2851bd4781c9dcde39fc9d1d52c9e60268061e7d7632171d91aa8d460acee0e96f1e7c4cfb12d3ff9ab5d5dc91c277db75c845d649ef3c4f63aebc364cd55ded0c";
+  const HEADER: &str = "11223344556677889900aabbccddeeff";
+  const PRESENTATION: &str = "bed231d880675ed101ead304512e043ade9958dd0241ea70b4b3957fba941501";
+
+  const MESSAGES: [&str; 10] = [
+    "9872ad089e452c7b6e283dfac2a80d58e8d0ff71cc4d5e310a1debdda4a45f02",
+    "c344136d9ab02da4dd5908bbba913ae6f58c2cc844b802a6f811f5fb075f9b80",
+    "7372e9daa5ed31e6cd5c825eac1b855e84476a1d94932aa348e07b73",
+    "77fe97eb97a1ebe2e81e4e3597a3ee740a66e9ef2412472c",
+    "496694774c5604ab1b2544eababcf0f53278ff50",
+    "515ae153e22aae04ad16f759e07237b4",
+    "d183ddc6e2665aa4e2f088af",
+    "ac55fb33a75909ed",
+    "96012096",
+    "",
+  ];
+
+  const SCALARS: [&str; 10] = [
+    "1cb5bb86114b34dc438a911617655a1db595abafac92f47c5001799cf624b430",
+    "154249d503c093ac2df516d4bb88b510d54fd97e8d7121aede420a25d9521952",
+    "0c7c4c85cdab32e6fdb0de267b16fa3212733d4e3a3f0d0f751657578b26fe22",
+    "4a196deafee5c23f630156ae13be3e46e53b7e39094d22877b8cba7f14640888",
+    "34c5ea4f2ba49117015a02c711bb173c11b06b3f1571b88a2952b93d0ed4cf7e",
+    "4045b39b83055cd57a4d0203e1660800fabe434004dbdc8730c21ce3f0048b08",
+    "064621da4377b6b1d05ecc37cf3b9dfc94b9498d7013dc5c4a82bf3bb1750743",
+    "34ac9196ace0a37e147e32319ea9b3d8cc7d21870d3c3ba071246859cca49b02",
+    "57eb93f417c43200e9784fa5ea5a59168d3dbc38df707a13bb597c871b2a5f74",
+    "08e3afeb2b4f2b5f907924ef42856616e6f2d5f1fb373736db1cca32707a7d16",
+  ];
+
+  const SIGNATURE_SINGLE: &str = "84773160b824e194073a57493dac1a20b667af70cd2352d8af241c77658da5253aa8458317cca0eae615690d55b1f27164657dcafee1d5c1973947aa70e2cfbb4c892340be5969920d0916067b4565a0";
+  const SIGNATURE_MULTI: &str = "8339b285a4acd89dec7777c09543a43e3cc60684b0a6f8ab335da4825c96e1463e28f8c5f4fd0641d19cec5920d3a8ff4bedb6c9691454597bbd298288abed3632078557b2ace7d44caed846e1a0a1e8";
+
+  fn decode(data: &str) -> Vec<u8> {
+    hex::decode(data).unwrap()
+  }
+
+  fn scalar(data: &str) -> Scalar {
+    let mut bytes: [u8; SCALAR_LEN] = decode(data).try_into().unwrap();
+
+    bytes.reverse();
+
+    Scalar::from_bytes(&bytes).unwrap()
+  }
+
+  fn messages(count: usize) -> Vec<Scalar> {
+    MESSAGES[..count]
+      .iter()
+      .map(|message| message_scalar(&decode(message)))
+      .collect()
+  }
+
+  #[test]
+  fn test_hash_to_scalar() {
+    assert_eq!(
+      hash_to_scalar(&decode(MESSAGES[0]), H2S_DST),
+      scalar("0f90cbee27beb214e6545becb8404640d3612da5d6758dffeccd77ed7169807c"),
+    );
+
+    for (message, expected) in MESSAGES.iter().zip(SCALARS.iter()) {
+      assert_eq!(message_scalar(&decode(message)), scalar(expected));
+    }
+  }
+
+  #[test]
+  fn test_generators() {
+    let generators: Generators = Generators::new(2);
+
+    assert_eq!(
+      hex::encode(encode_g1(&generators.q1)),
+      "a9ec65b70a7fbe40c874c9eb041c2cb0a7af36ccec1bea48fa2ba4c2eb67ef7f9ecb17ed27d38d27cdeddff44c8137be",
+    );
+    assert_eq!(
+      hex::encode(encode_g1(&generators.h[0])),
+      "98cd5313283aaf5db1b3ba8611fe6070d19e605de4078c38df36019fbaad0bd28dd090fd24ed27f7f4d22d5ff5dea7d4",
+    );
+    assert_eq!(
+      hex::encode(encode_g1(&generators.h[1])),
+      "a31fbe20c5c135bcaa8d9fc4e4ac665cc6db0226f35e737507e803044093f37697a9d452490a970eea6f9ad6c3dcaa3a",
+    );
+  }
+
+  #[test]
+  fn test_mocked_random_scalars() {
+    let dst: &[u8] = b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_H2G_HM2S_MOCK_RANDOM_SCALARS_DST_";
+    let mut output: [u8; 10 * EXPAND_LEN] = [0; 10 * EXPAND_LEN];
+
+    expand_message(b"3.141592653589793238462643383279", dst, &mut output);
+
+    let chunk = |index: usize| -> Scalar {
+      scalar_from_okm(
+        output[index * EXPAND_LEN..][..EXPAND_LEN]
+          .try_into()
+          .unwrap(),
+      )
+    };
+
+    assert_eq!(
+      chunk(0),
+      scalar("04f8e2518993c4383957ad14eb13a023c4ad0c67d01ec86eeb902e732ed6df3f"),
+    );
+    assert_eq!(
+      chunk(1),
+      scalar("5d87c1ba64c320ad601d227a1b74188a41a100325cecf00223729863966392b1"),
+    );
+  }
+
+  #[test]
+  fn test_public_key() {
+    assert_eq!(hex::encode(public_key(&decode(SECRET)).unwrap()), PUBLIC);
+  }
+
+  #[test]
+  fn test_sign_single() {
+    let messages: Vec<Scalar> = messages(1);
+    let signature: Vec<u8> = sign(&decode(SECRET), &decode(HEADER), &messages).unwrap();
+
+    assert_eq!(hex::encode(&signature), SIGNATURE_SINGLE);
+    assert!(verify(&decode(PUBLIC), &decode(HEADER), &messages, &signature).is_ok());
+
+    // Modified message
+    let modified: Vec<Scalar> = alloc::vec![message_scalar(b"")];
+
+    assert!(verify(&decode(PUBLIC), &decode(HEADER), &modified, &signature).is_err());
+  }
+
+  #[test]
+  fn test_sign_multi() {
+    let messages: Vec<Scalar> = messages(10);
+    let signature: Vec<u8> = sign(&decode(SECRET), &decode(HEADER), &messages).unwrap();
+
+    assert_eq!(hex::encode(&signature), SIGNATURE_MULTI);
+    assert!(verify(&decode(PUBLIC), &decode(HEADER), &messages, &signature).is_ok());
+
+    // Truncated messages
+    assert!(verify(&decode(PUBLIC), &decode(HEADER), &messages[..2], &signature).is_err());
+  }
+
+  #[test]
+  fn test_proof_single() {
+    let messages: Vec<Scalar> = messages(1);
+    let random: Vec<Scalar> = [
+      "60ca409f6b0563f687fc471c63d2819f446f39c23bb540925d9d4254ac58f337",
+      "2ceff4982de0c913090f75f081df5ec594c310bb48c17cfdaab5332a682ef811",
+      "6101c4404895f3dff87ab39c34cb995af07e7139e6b3847180ffdd1bc8c313cd",
+      "0dfcffd97a6ecdebef3c9c114b99d7a030c998d938905f357df62822dee072e8",
+      "639e3417007d38e5d34ba8c511e836768ddc2669fdd3faff5c14ad27ac2b2da1",
+    ]
+    .iter()
+    .map(|data| scalar(data))
+    .collect();
+
+    let proof: Vec<u8> = prove_with(
+      &decode(PUBLIC),
+      &decode(SIGNATURE_SINGLE),
+      &decode(HEADER),
+      &decode(PRESENTATION),
+      &messages,
+      &[0],
+      &random,
+    )
+    .unwrap();
+
+    assert_eq!(
+      hex::encode(&proof),
+      concat!(
+        "94916292a7a6bade28456c601d3af33fcf39278d6594b467e128a3f83686a104ef2b2fcf72df0215eeaf69262ffe8194",
+        "a19fab31a82ddbe06908985abc4c9825788b8a1610942d12b7f5debbea8985296361206dbace7af0cc834c80f33e0aad",
+        "aeea5597befbb651827b5eed5a66f1a959bb46cfd5ca1a817a14475960f69b32c54db7587b5ee3ab665fbd37b506830a",
+        "49f21d592f5e634f47cee05a025a2f8f94e73a6c15f02301d1178a92873b6e86",
+        "34bafe4983c3e15a663d64080678dbf29417519b78af042be2b3e1c4d08b8d52",
+        "0ffab008cbaaca5671a15b22c239b38e940cfeaa5e72104576a9ec4a6fad78c5",
+        "32381aeaa6fb56409cef56ee5c140d455feeb04426193c57086c9b6d397d9418",
+      ),
+    );
+
+    let disclosed: Vec<(usize, Scalar)> = alloc::vec![(0, messages[0])];
+
+    assert!(verify_proof(
+      &decode(PUBLIC),
+      &proof,
+      &decode(HEADER),
+      &decode(PRESENTATION),
+      1,
+      &disclosed
+    )
+    .is_ok());
+  }
+
+  #[test]
+  fn test_proof_multi() {
+    let messages: Vec<Scalar> = messages(10);
+    let random: Vec<Scalar> = [
+      "44679831fe60eca50938ef0e812e2a9284ad7971b6932a38c7303538b712e457",
+      "6481692f89086cce11779e847ff884db8eebb85a13e81b2d0c79d6c1062069d8",
+      "721ce4c4c148a1d5826f326af6fd6ac2844f29533ba4127c3a43d222d51b7081",
+      "1ecfaf5a079b0504b00a1f0d6fe8857291dd798291d7ad7454b398114393f37f",
+      "0a4b3d59b34707bb9999bc6e2a6d382a2d2e214bff36ecd88639a14124b1622e",
+      "7217411a9e329c7a5705e8db552274646e2949d62c288d7537dd62bc284715e4",
+      "67d4d43660746759f598caac106a2b5f58ccd1c3eefaec31841a4f77d2548870",
+      "715d965b1c3912d20505b381470ff1a528700b673e50ba89fd287e13171cc137",
+      "4d3281a149674e58c9040fc7a10dd92cb9c7f76f6f0815a1afc3b09d74b92fe4",
+      "438feebaa5894ca0da49992df2c97d872bf153eab07e08ff73b28131c46ff415",
+      "602b723c8bbaec1b057d70f18269ae5e6de6197a5884967b03b933fa80006121",
+    ]
+    .iter()
+    .map(|data| scalar(data))
+    .collect();
+
+    let proof: Vec<u8> = prove_with(
+      &decode(PUBLIC),
+      &decode(SIGNATURE_MULTI),
+      &decode(HEADER),
+      &decode(PRESENTATION),
+      &messages,
+      &[0, 2, 4, 6],
+      &random,
+    )
+    .unwrap();
+
+    assert_eq!(
+      hex::encode(&proof),
+      concat!(
+        "a2ed608e8e12ed21abc2bf154e462d744a367c7f1f969bdbf784a2a134c7db2d340394223a5397a3011b1c340ebc4151",
+        "99462ba6f31106d8a6da8b513b37a47afe93c9b3474d0d7a354b2edc1b88818b063332df774c141f7a07c48fe50d452f",
+        "897739228c88afc797916dca01e8f03bd9c5375c7a7c59996e514bb952a436afd24457658acbaba5ddac2e693ac48135",
+        "6918cd38025d86b28650e909defe9604a7259f44386b861608be742af7775a2e",
+        "71a6070e5836f5f54dc43c60096834a5b6da295bf8f081f72b7cdf7f3b4347fb",
+        "3ff19edaa9e74055c8ba46dbcb7594fb2b06633bb5324192eb9be91be0d33e45",
+        "3b4d3127459de59a5e2193c900816f049a02cb9127dac894418105fa1641d5a2",
+        "06ec9c42177af9316f433417441478276ca0303da8f941bf2e0222a43251cf5c",
+        "2bf6eac1961890aa740534e519c1767e1223392a3a286b0f4d91f7f25217a786",
+        "2b8fcc1810cdcfddde2a01c80fcc90b632585fec12dc4ae8fea1918e9ddeb941",
+        "4623a457e88f53f545841f9d5dcb1f8e160d1560770aa79d65e2eca8edeaecb7",
+        "3fb7e995608b820c4a64de6313a370ba05dc25ed7c1d185192084963652f2870",
+        "341bdaa4b1a37f8c06348f38a4f80c5a2650a21d59f09e8305dcd3fc3ac30e2a",
+      ),
+    );
+
+    let disclosed: Vec<(usize, Scalar)> = [0, 2, 4, 6]
+      .iter()
+      .map(|index| (*index, messages[*index]))
+      .collect();
+
+    assert!(verify_proof(
+      &decode(PUBLIC),
+      &proof,
+      &decode(HEADER),
+      &decode(PRESENTATION),
+      10,
+      &disclosed
+    )
+    .is_ok());
+
+    // Different presentation header
+    let presentation: &str = "011594ba7f95b3b470ea4102dd5899de3a042e5104d3ea01d15e6780d831d2be";
+
+    assert!(verify_proof(
+      &decode(PUBLIC),
+      &proof,
+      &decode(HEADER),
+      &decode(presentation),
+      10,
+      &disclosed
+    )
+    .is_err());
+  }
+}
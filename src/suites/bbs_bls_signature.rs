@@ -0,0 +1,400 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use bls12_381::Scalar;
+use serde::Serialize;
+use serde_json::to_value;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::signature::Sign;
+use crate::signature::SignatureData;
+use crate::signature::SuiteName;
+use crate::signature::Verify;
+use crate::suites::bbs;
+use crate::utils::canonicalize;
+use crate::utils::Value;
+use crate::verification::MethodType;
+
+const ERR_SI: &str = "Invalid Signature Input";
+const ERR_MSP: &str = "Missing Signature Proof";
+const ERR_MPV: &str = "Missing Proof Value";
+const ERR_IPV: &str = "Invalid Proof Value";
+const ERR_UN: &str = "Unexpected Nonce";
+
+// The property of the signature in signed documents.
+const PROOF: &str = "proof";
+
+/// An implementation of the `BbsBlsSignature2020` signature suite.
+///
+/// Documents are signed as a vector of messages, one per canonical statement
+/// (see `BbsBlsSignature2020::statements`), with a `Bls12381G2Key2020` key.
+/// The proof options are bound to the signature as a header, not as messages.
+///
+/// Signed documents can be presented with a `BbsBlsSignatureProof2020` that
+/// discloses only selected statements.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BbsBlsSignature2020;
+
+impl BbsBlsSignature2020 {
+  /// The name of the signature suite.
+  pub const NAME: &'static str = "BbsBlsSignature2020";
+
+  /// Returns the compressed `Bls12381G2Key2020` public key of a 32-byte,
+  /// big-endian `secret` key.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the secret key is invalid.
+  pub fn public_key(secret: &[u8]) -> Result<Vec<u8>> {
+    bbs::public_key(secret)
+  }
+
+  /// Returns the JSON pointers of the canonical statements of a document.
+  ///
+  /// Each member of a (non-empty) object is a separate statement; all other
+  /// values, including arrays, are disclosed as a whole.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the document cannot be represented as a JSON object.
+  pub fn statements<T>(document: &T) -> Result<Vec<String>>
+  where
+    T: Serialize,
+  {
+    let input: Input = Input::unsigned(document)?;
+
+    Ok(
+      statements(&input.document)
+        .iter()
+        .map(Statement::pointer)
+        .collect(),
+    )
+  }
+}
+
+impl SuiteName for BbsBlsSignature2020 {
+  fn name(&self) -> String {
+    Self::NAME.to_string()
+  }
+}
+
+impl Sign for BbsBlsSignature2020 {
+  fn sign<T>(&self, data: &T, secret: &[u8]) -> Result<SignatureData>
+  where
+    T: Serialize,
+  {
+    let input: Input = Input::signature(data)?;
+    let messages: Vec<Scalar> = input.messages()?;
+    let signature: Vec<u8> = bbs::sign(secret, &input.header, &messages)?;
+
+    Ok(SignatureData::Proof(URL_SAFE_NO_PAD.encode(signature)))
+  }
+}
+
+impl Verify for BbsBlsSignature2020 {
+  const METHODS: &'static [MethodType] = &[MethodType::Bls12381G2Key2020];
+
+  fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+  where
+    T: Serialize,
+  {
+    let input: Input = Input::signature(data)?;
+    let messages: Vec<Scalar> = input.messages()?;
+
+    bbs::verify(public, &input.header, &messages, &decode_proof(signature)?)
+  }
+}
+
+// =============================================================================
+// =============================================================================
+
+/// Returns the decoded `proofValue` of a BBS signature or proof.
+pub(crate) fn decode_proof(signature: &SignatureData) -> Result<Vec<u8>> {
+  let value: &str = signature
+    .try_proof()
+    .ok_or_else(|| Error::message(ERR_MPV))?;
+
+  URL_SAFE_NO_PAD
+    .decode(value)
+    .map_err(|_| Error::message(ERR_IPV))
+}
+
+/// The input of a BBS signature or proof.
+pub(crate) struct Input {
+  /// The document without the proof.
+  pub(crate) document: Value,
+  /// The canonicalized proof options, without the type, value, and nonce.
+  pub(crate) header: Vec<u8>,
+  /// The nonce of the proof, bound to derived proofs.
+  pub(crate) nonce: Option<String>,
+}
+
+impl Input {
+  /// Returns the input of an unsigned document.
+  pub(crate) fn unsigned<T>(data: &T) -> Result<Self>
+  where
+    T: Serialize + ?Sized,
+  {
+    Self::new(data, None)
+  }
+
+  /// Returns the input of a document signed with `BbsBlsSignature2020`.
+  ///
+  /// Signatures are not bound to a nonce; proofs with a nonce are rejected.
+  pub(crate) fn signature<T>(data: &T) -> Result<Self>
+  where
+    T: Serialize + ?Sized,
+  {
+    Self::new(data, Some(false))
+  }
+
+  /// Returns the input of a document with a proof derived with
+  /// `BbsBlsSignatureProof2020`.
+  ///
+  /// The nonce is bound to the proof and not part of the header, which must
+  /// match the header of the signature the proof is derived from.
+  pub(crate) fn derived<T>(data: &T) -> Result<Self>
+  where
+    T: Serialize + ?Sized,
+  {
+    Self::new(data, Some(true))
+  }
+
+  fn new<T>(data: &T, derived: Option<bool>) -> Result<Self>
+  where
+    T: Serialize + ?Sized,
+  {
+    let mut document: Value = to_value(data).map_err(|_| Error::message(ERR_SI))?;
+
+    let object = document
+      .as_object_mut()
+      .ok_or_else(|| Error::message(ERR_SI))?;
+
+    let (header, nonce): (Vec<u8>, Option<String>) = match (object.remove(PROOF), derived) {
+      (Some(Value::Object(mut options)), Some(derived)) => {
+        options.remove("type");
+
+        for key in SignatureData::KEYS {
+          options.remove(*key);
+        }
+
+        let nonce: Option<String> = match options.remove("nonce") {
+          Some(Value::String(nonce)) if derived => Some(nonce),
+          Some(Value::String(_)) => return Err(Error::message(ERR_UN)),
+          Some(_) => return Err(Error::message(ERR_SI)),
+          None => None,
+        };
+
+        (canonicalize(&options)?, nonce)
+      }
+      (_, Some(_)) => return Err(Error::message(ERR_MSP)),
+      (_, None) => (Vec::new(), None),
+    };
+
+    Ok(Self {
+      document,
+      header,
+      nonce,
+    })
+  }
+
+  pub(crate) fn messages(&self) -> Result<Vec<Scalar>> {
+    statements(&self.document)
+      .iter()
+      .map(|statement| statement.to_bytes().map(|data| bbs::message_scalar(&data)))
+      .collect()
+  }
+}
+
+/// A canonical statement of a document; a path and its value.
+pub(crate) struct Statement<'a> {
+  pub(crate) path: Vec<&'a str>,
+  pub(crate) value: &'a Value,
+}
+
+impl Statement<'_> {
+  /// Returns the JSON pointer (RFC 6901) of the statement.
+  pub(crate) fn pointer(&self) -> String {
+    let mut output: String = String::new();
+
+    for segment in self.path.iter() {
+      output.push('/');
+      output.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+    }
+
+    output
+  }
+
+  /// Returns `true` if the statement is disclosed by revealing `pointer`.
+  pub(crate) fn is_revealed_by(&self, pointer: &str) -> bool {
+    let this: String = self.pointer();
+
+    this == pointer
+      || this
+        .strip_prefix(pointer)
+        .is_some_and(|tail| tail.starts_with('/'))
+  }
+
+  /// Returns the signed message of the statement.
+  pub(crate) fn to_bytes(&self) -> Result<Vec<u8>> {
+    let mut output: Vec<u8> = self.pointer().into_bytes();
+
+    output.push(b' ');
+    output.extend(canonicalize(self.value)?);
+
+    Ok(output)
+  }
+}
+
+/// Returns the canonical statements of a document, sorted by path.
+pub(crate) fn statements(document: &Value) -> Vec<Statement<'_>> {
+  fn visit<'a>(path: &mut Vec<&'a str>, value: &'a Value, output: &mut Vec<Statement<'a>>) {
+    match value {
+      // Empty objects are statements themselves, except for the document root
+      Value::Object(object) if !object.is_empty() || path.is_empty() => {
+        for (key, value) in object {
+          path.push(key);
+          visit(path, value, output);
+          path.pop();
+        }
+      }
+      _ => output.push(Statement {
+        path: path.clone(),
+        value,
+      }),
+    }
+  }
+
+  let mut output: Vec<Statement<'_>> = Vec::new();
+
+  visit(&mut Vec::new(), document, &mut output);
+
+  output
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+  use did_url::DID;
+  use serde_json::json;
+
+  use super::*;
+  use crate::document::Document;
+  use crate::document::DocumentBuilder;
+  use crate::signature::LdSuite;
+  use crate::signature::SignatureOptions;
+  use crate::signature::VerificationPolicy;
+  use crate::utils::Object;
+  use crate::verifiable::VerifiableProperties;
+  use crate::verification::MethodBuilder;
+  use crate::verification::MethodData;
+
+  const SECRET: [u8; 32] = [7; 32];
+
+  pub(crate) fn issuer() -> Document {
+    DocumentBuilder::default()
+      .id("did:example:issuer".parse().unwrap())
+      .verification_method(
+        MethodBuilder::default()
+          .id("did:example:issuer#bbs".parse().unwrap())
+          .controller("did:example:issuer".parse().unwrap())
+          .key_type(MethodType::Bls12381G2Key2020)
+          .key_data(MethodData::new_b58(
+            BbsBlsSignature2020::public_key(&SECRET).unwrap(),
+          ))
+          .build()
+          .unwrap(),
+      )
+      .assertion_method("did:example:issuer#bbs".parse::<DID>().unwrap())
+      .build()
+      .unwrap()
+  }
+
+  pub(crate) fn credential() -> VerifiableProperties {
+    let properties: Object = serde_json::from_value(json!({
+      "id": "urn:uuid:1234",
+      "credentialSubject": {
+        "id": "did:example:subject",
+        "name": "Alice",
+        "birthDate": "1990-01-01",
+        "address": { "country": "CH", "city": "Zurich" },
+        "degrees": ["BSc", "MSc"],
+      },
+    }))
+    .unwrap();
+
+    let mut credential: VerifiableProperties = VerifiableProperties::new(properties);
+    let mut options: SignatureOptions =
+      SignatureOptions::with_purpose("did:example:issuer#bbs".into(), "assertionMethod".into());
+
+    options.created = Some("2021-01-01T00:00:00Z".parse().unwrap());
+
+    LdSuite::new(BbsBlsSignature2020)
+      .sign(&mut credential, options, &SECRET)
+      .unwrap();
+
+    credential
+  }
+
+  pub(crate) fn policy() -> VerificationPolicy<impl crate::signature::Clock> {
+    VerificationPolicy::new(|| 1609459200)
+  }
+
+  #[test]
+  fn test_statements() {
+    let statements: Vec<String> = BbsBlsSignature2020::statements(&credential()).unwrap();
+
+    assert_eq!(
+      statements,
+      [
+        "/credentialSubject/address/city",
+        "/credentialSubject/address/country",
+        "/credentialSubject/birthDate",
+        "/credentialSubject/degrees",
+        "/credentialSubject/id",
+        "/credentialSubject/name",
+        "/id",
+      ]
+    );
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    let suite: LdSuite<BbsBlsSignature2020> = LdSuite::new(BbsBlsSignature2020);
+    let mut credential: VerifiableProperties = credential();
+
+    assert!(suite.verify_data(&credential, issuer(), &policy()).is_ok());
+
+    credential.insert("extra".into(), "claim".into());
+
+    assert!(suite.verify_data(&credential, issuer(), &policy()).is_err());
+
+    let mut credential: VerifiableProperties = self::credential();
+
+    credential.proof_mut().unwrap().domain = Some("example.com".into());
+
+    assert!(suite.verify_data(&credential, issuer(), &policy()).is_err());
+  }
+
+  #[test]
+  fn test_nonce() {
+    let suite: LdSuite<BbsBlsSignature2020> = LdSuite::new(BbsBlsSignature2020);
+
+    // Base signatures are not bound to a nonce, so any nonce is rejected
+    let mut credential: VerifiableProperties = credential();
+
+    credential.proof_mut().unwrap().nonce = Some("abc".into());
+
+    assert!(suite
+      .verify_data(&credential, issuer(), &policy().challenge("abc"))
+      .is_err());
+
+    let mut credential: VerifiableProperties = VerifiableProperties::new(Object::new());
+    let mut options: SignatureOptions =
+      SignatureOptions::with_purpose("did:example:issuer#bbs".into(), "assertionMethod".into());
+
+    options.nonce = Some("abc".into());
+
+    assert!(suite.sign(&mut credential, options, &SECRET).is_err());
+  }
+}
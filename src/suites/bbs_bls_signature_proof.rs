@@ -0,0 +1,345 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use bls12_381::Scalar;
+use core::convert::TryInto;
+use rand_core::CryptoRng;
+use rand_core::RngCore;
+use serde::Serialize;
+use serde_json::from_value;
+use serde_json::Map;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::signature::Signature;
+use crate::signature::SignatureData;
+use crate::signature::SignatureOptions;
+use crate::signature::SuiteName;
+use crate::signature::Verify;
+use crate::suites::bbs;
+use crate::suites::decode_proof;
+use crate::suites::statements;
+use crate::suites::BbsBlsSignature2020;
+use crate::suites::Input;
+use crate::suites::Statement;
+use crate::utils::Object;
+use crate::utils::Value;
+use crate::verifiable::TrySignature;
+use crate::verifiable::VerifiableProperties;
+use crate::verification::MethodType;
+
+const ERR_IST: &str = "Invalid Signature Type";
+const ERR_ISDP: &str = "Invalid Selective Disclosure Pointer";
+const ERR_IPV: &str = "Invalid Proof Value";
+
+/// An implementation of the `BbsBlsSignatureProof2020` signature suite.
+///
+/// A proof is derived from a document signed with `BbsBlsSignature2020` and
+/// discloses only selected statements, proving knowledge of a signature of the
+/// complete document without revealing it. Proofs are bound to a `nonce`
+/// chosen by the verifier.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BbsBlsSignatureProof2020;
+
+impl BbsBlsSignatureProof2020 {
+  /// The name of the signature suite.
+  pub const NAME: &'static str = "BbsBlsSignatureProof2020";
+
+  /// Derives a proof from a `message` signed with `BbsBlsSignature2020` by the
+  /// owner of the `public` key.
+  ///
+  /// The derived document contains the statements identified by the `reveal`
+  /// JSON pointers, or any of their children, and a proof bound to `nonce`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the signature is invalid, or a pointer does not identify any
+  /// statements.
+  pub fn derive<T, R>(
+    message: &T,
+    reveal: &[&str],
+    nonce: impl Into<String>,
+    public: &[u8],
+    rng: &mut R,
+  ) -> Result<VerifiableProperties>
+  where
+    T: Serialize + TrySignature,
+    R: RngCore + CryptoRng,
+  {
+    let signature: &Signature = message.try_signature()?;
+
+    if signature.type_() != BbsBlsSignature2020::NAME {
+      return Err(Error::message(ERR_IST));
+    }
+
+    let input: Input = Input::signature(&signature.verifiable(message)?)?;
+    let statements: Vec<Statement<'_>> = statements(&input.document);
+    let messages: Vec<Scalar> = input.messages()?;
+
+    let is_revealed = |pointer: &&str| {
+      statements
+        .iter()
+        .any(|statement| statement.is_revealed_by(pointer))
+    };
+
+    if !reveal.iter().all(is_revealed) {
+      return Err(Error::message(ERR_ISDP));
+    }
+
+    let disclosed: Vec<usize> = statements
+      .iter()
+      .enumerate()
+      .filter(|(_, statement)| {
+        reveal
+          .iter()
+          .any(|pointer| statement.is_revealed_by(pointer))
+      })
+      .map(|(index, _)| index)
+      .collect();
+
+    let mut options: SignatureOptions = (**signature).clone();
+
+    options.nonce = Some(nonce.into());
+
+    let proof: Vec<u8> = bbs::prove(
+      public,
+      &decode_proof(signature.data())?,
+      &input.header,
+      options.nonce.as_deref().unwrap_or_default().as_bytes(),
+      &messages,
+      &disclosed,
+      rng,
+    )?;
+
+    let mut document: Value = Value::Object(Map::new());
+
+    for index in disclosed.iter() {
+      insert(&mut document, &statements[*index]);
+    }
+
+    let document: Object = from_value(document).map_err(|_| Error::message(ERR_IPV))?;
+    let mut derived: Signature = Signature::new(Self::NAME, options);
+
+    derived.set_data(SignatureData::Proof(encode(
+      messages.len(),
+      &disclosed,
+      &proof,
+    )));
+
+    Ok(VerifiableProperties::with_proof(document, derived))
+  }
+}
+
+impl SuiteName for BbsBlsSignatureProof2020 {
+  fn name(&self) -> String {
+    Self::NAME.to_string()
+  }
+}
+
+impl Verify for BbsBlsSignatureProof2020 {
+  const METHODS: &'static [MethodType] = &[MethodType::Bls12381G2Key2020];
+
+  fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+  where
+    T: Serialize,
+  {
+    let input: Input = Input::derived(data)?;
+    let messages: Vec<Scalar> = input.messages()?;
+    let value: Vec<u8> = decode_proof(signature)?;
+    let (count, disclosed, proof): (usize, Vec<usize>, &[u8]) = decode(&value)?;
+
+    if disclosed.len() != messages.len() {
+      return Err(Error::message(ERR_IPV));
+    }
+
+    let disclosed: Vec<(usize, Scalar)> = disclosed.into_iter().zip(messages).collect();
+
+    bbs::verify_proof(
+      public,
+      proof,
+      &input.header,
+      input.nonce.as_deref().unwrap_or_default().as_bytes(),
+      count,
+      &disclosed,
+    )
+  }
+}
+
+// Inserts the value of a statement into a document.
+fn insert(document: &mut Value, statement: &Statement<'_>) {
+  let mut target: &mut Value = document;
+
+  for segment in statement.path.iter() {
+    target = match target {
+      Value::Object(object) => object
+        .entry(segment.to_string())
+        .or_insert_with(|| Value::Object(Map::new())),
+      _ => unreachable!("statements only descend into objects"),
+    };
+  }
+
+  *target = statement.value.clone();
+}
+
+// Proof values are encoded as `count || disclosed count || disclosed indexes
+// || proof`, with 32-bit big-endian integers.
+fn encode(count: usize, disclosed: &[usize], proof: &[u8]) -> String {
+  let mut output: Vec<u8> = Vec::with_capacity(8 + disclosed.len() * 4 + proof.len());
+
+  output.extend_from_slice(&(count as u32).to_be_bytes());
+  output.extend_from_slice(&(disclosed.len() as u32).to_be_bytes());
+
+  for index in disclosed {
+    output.extend_from_slice(&(*index as u32).to_be_bytes());
+  }
+
+  output.extend_from_slice(proof);
+
+  URL_SAFE_NO_PAD.encode(output)
+}
+
+fn decode(data: &[u8]) -> Result<(usize, Vec<usize>, &[u8])> {
+  let read = |data: &[u8], offset: usize| -> Result<usize> {
+    data
+      .get(offset..offset + 4)
+      .and_then(|bytes| bytes.try_into().ok())
+      .map(|bytes| u32::from_be_bytes(bytes) as usize)
+      .ok_or_else(|| Error::message(ERR_IPV))
+  };
+
+  let count: usize = read(data, 0)?;
+  let length: usize = read(data, 4)?;
+
+  // Both counts are untrusted; the indexes must be present in `data`
+  if length > count || length > (data.len() - 8) / 4 {
+    return Err(Error::message(ERR_IPV));
+  }
+
+  let disclosed: Vec<usize> = (0..length)
+    .map(|index| read(data, 8 + index * 4))
+    .collect::<Result<_>>()?;
+
+  Ok((count, disclosed, &data[8 + length * 4..]))
+}
+
+#[cfg(test)]
+mod tests {
+  use rand_chacha::rand_core::SeedableRng;
+  use rand_chacha::ChaCha20Rng;
+  use serde_json::json;
+
+  use super::*;
+  use crate::document::Document;
+  use crate::signature::LdSuite;
+  use crate::suites::bbs_bls_signature::tests::credential;
+  use crate::suites::bbs_bls_signature::tests::issuer;
+  use crate::suites::bbs_bls_signature::tests::policy;
+
+  fn derive(reveal: &[&str], nonce: &str) -> Result<VerifiableProperties> {
+    let issuer: Document = issuer();
+    let public: Vec<u8> = issuer
+      .try_resolve("#bbs")
+      .unwrap()
+      .key_data()
+      .try_decode()
+      .unwrap();
+
+    BbsBlsSignatureProof2020::derive(
+      &credential(),
+      reveal,
+      nonce,
+      &public,
+      &mut ChaCha20Rng::seed_from_u64(0),
+    )
+  }
+
+  fn verify(derived: &VerifiableProperties) -> Result<()> {
+    LdSuite::new(BbsBlsSignatureProof2020).verify_data(
+      derived,
+      issuer(),
+      &policy().challenge("abc"),
+    )
+  }
+
+  #[test]
+  fn test_derive_verify() {
+    let derived: VerifiableProperties = derive(
+      &["/credentialSubject/name", "/credentialSubject/address"],
+      "abc",
+    )
+    .unwrap();
+
+    assert_eq!(
+      serde_json::to_value(&*derived).unwrap(),
+      json!({
+        "credentialSubject": {
+          "name": "Alice",
+          "address": { "country": "CH", "city": "Zurich" },
+        },
+      })
+    );
+    assert!(verify(&derived).is_ok());
+  }
+
+  #[test]
+  fn test_derive_all_and_none() {
+    assert!(verify(&derive(&[""], "abc").unwrap()).is_ok());
+    assert!(verify(&derive(&[], "abc").unwrap()).is_ok());
+  }
+
+  #[test]
+  fn test_verify_tampered() {
+    let mut derived: VerifiableProperties = derive(&["/credentialSubject/name"], "abc").unwrap();
+
+    derived.insert("credentialSubject".into(), json!({ "name": "Mallory" }));
+
+    assert!(verify(&derived).is_err());
+
+    let mut derived: VerifiableProperties = derive(&["/credentialSubject/name"], "abc").unwrap();
+
+    derived.insert("id".into(), "urn:uuid:1234".into());
+
+    assert!(verify(&derived).is_err());
+  }
+
+  #[test]
+  fn test_verify_nonce() {
+    let derived: VerifiableProperties = derive(&["/id"], "xyz").unwrap();
+
+    assert!(verify(&derived).is_err());
+
+    let mut derived: VerifiableProperties = derive(&["/id"], "abc").unwrap();
+
+    derived.proof_mut().unwrap().nonce = Some("xyz".into());
+
+    assert!(LdSuite::new(BbsBlsSignatureProof2020)
+      .verify_data(&derived, issuer(), &policy())
+      .is_err());
+  }
+
+  #[test]
+  fn test_verify_invalid_count() {
+    let mut derived: VerifiableProperties = derive(&["/credentialSubject/name"], "abc").unwrap();
+    let value: Vec<u8> = decode_proof(derived.proof().unwrap().data()).unwrap();
+    let (count, disclosed, proof): (usize, Vec<usize>, &[u8]) = decode(&value).unwrap();
+
+    // Counts not matching the size of the proof are rejected before any
+    // generators are derived
+    for count in [u32::MAX as usize, count + 1, count - 1] {
+      derived
+        .proof_mut()
+        .unwrap()
+        .set_data(SignatureData::Proof(encode(count, &disclosed, proof)));
+
+      assert!(verify(&derived).is_err());
+    }
+
+    assert!(decode(&[0, 0, 0, 9, 0, 0, 0, 9, 0, 0, 0, 1]).is_err());
+  }
+
+  #[test]
+  fn test_derive_invalid() {
+    assert!(derive(&["/credentialSubject/email"], "abc").is_err());
+    assert!(derive(&["/credentialSubject/degrees/0"], "abc").is_err());
+  }
+}
@@ -0,0 +1,278 @@
+use k256::ecdsa::signature::Signer as _;
+use k256::ecdsa::signature::Verifier as _;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jws::JwsAlgorithm;
+use crate::lib::*;
+
+const ERR_ISK: &str = "Invalid ECDSA Secret Key";
+const ERR_IPK: &str = "Invalid ECDSA Public Key";
+const ERR_IS: &str = "Invalid ECDSA Signature";
+
+macro_rules! ecdsa_impl {
+  ($name:ident, $curve:ident) => {
+    mod $name {
+      use ::$curve::ecdsa::Signature;
+      use ::$curve::ecdsa::SigningKey;
+      use ::$curve::ecdsa::VerifyingKey;
+
+      use super::*;
+
+      fn signing_key(secret: &[u8]) -> Result<SigningKey> {
+        SigningKey::from_slice(secret).map_err(|_| Error::message(ERR_ISK))
+      }
+
+      fn verifying_key(public: &[u8]) -> Result<VerifyingKey> {
+        VerifyingKey::from_sec1_bytes(public).map_err(|_| Error::message(ERR_IPK))
+      }
+
+      pub(super) fn matches(secret: &[u8], public: &[u8]) -> Result<bool> {
+        Ok(signing_key(secret)?.verifying_key() == &verifying_key(public)?)
+      }
+
+      pub(super) fn sign(secret: &[u8], message: &[u8]) -> Result<Vec<u8>> {
+        let signature: Signature = signing_key(secret)?.sign(message);
+        let signature: Signature = signature.normalize_s().unwrap_or(signature);
+
+        Ok(signature.to_bytes().to_vec())
+      }
+
+      pub(super) fn verify(public: &[u8], message: &[u8], signature: &[u8]) -> Result<()> {
+        let signature: Signature =
+          Signature::from_slice(signature).map_err(|_| Error::message(ERR_IS))?;
+
+        // Reject malleable (high-S) signatures
+        if signature.normalize_s().is_some() {
+          return Err(Error::message(ERR_IS));
+        }
+
+        verifying_key(public)?
+          .verify(message, &signature)
+          .map_err(|_| Error::message(ERR_IS))
+      }
+    }
+  };
+}
+
+ecdsa_impl!(secp256k1, k256);
+ecdsa_impl!(nistp256, p256);
+
+// secp256k1 signatures with an appended recovery id (`r || s || v`).
+mod recovery {
+  use ::k256::ecdsa::RecoveryId;
+  use ::k256::ecdsa::Signature;
+  use ::k256::ecdsa::SigningKey;
+  use ::k256::ecdsa::VerifyingKey;
+  use sha3::Digest as _;
+  use sha3::Keccak256;
+
+  use super::*;
+
+  // The length of an Ethereum account address.
+  const ADDRESS_LEN: usize = 20;
+
+  fn signing_key(secret: &[u8]) -> Result<SigningKey> {
+    SigningKey::from_slice(secret).map_err(|_| Error::message(ERR_ISK))
+  }
+
+  // Returns the Ethereum address of a public key; the last 20 bytes of the
+  // Keccak-256 digest of the uncompressed point, without the SEC1 tag.
+  fn address(key: &VerifyingKey) -> Vec<u8> {
+    let point = key.to_encoded_point(false);
+    let digest = Keccak256::digest(&point.as_bytes()[1..]);
+
+    digest[digest.len() - ADDRESS_LEN..].to_vec()
+  }
+
+  // Returns `true` if `public` is the address or public key of `key`.
+  fn compare(key: &VerifyingKey, public: &[u8]) -> Result<bool> {
+    if public.len() == ADDRESS_LEN {
+      Ok(address(key) == public)
+    } else {
+      VerifyingKey::from_sec1_bytes(public)
+        .map(|public| &public == key)
+        .map_err(|_| Error::message(ERR_IPK))
+    }
+  }
+
+  pub(super) fn matches(secret: &[u8], public: &[u8]) -> Result<bool> {
+    compare(signing_key(secret)?.verifying_key(), public)
+  }
+
+  pub(super) fn sign(secret: &[u8], message: &[u8]) -> Result<Vec<u8>> {
+    let (signature, recovery): (Signature, RecoveryId) = signing_key(secret)?
+      .sign_recoverable(message)
+      .map_err(|_| Error::message(ERR_IS))?;
+
+    let mut output: Vec<u8> = signature.to_bytes().to_vec();
+
+    output.push(recovery.to_byte());
+
+    Ok(output)
+  }
+
+  pub(super) fn verify(public: &[u8], message: &[u8], signature: &[u8]) -> Result<()> {
+    let (signature, recovery): (&[u8], u8) = match signature {
+      [signature @ .., recovery] if signature.len() == 64 => (signature, *recovery),
+      _ => return Err(Error::message(ERR_IS)),
+    };
+
+    // Accept both raw (0-3) and Ethereum-style (27-30) recovery ids
+    let recovery: RecoveryId = RecoveryId::from_byte(recovery)
+      .or_else(|| RecoveryId::from_byte(recovery.wrapping_sub(27)))
+      .ok_or_else(|| Error::message(ERR_IS))?;
+
+    let signature: Signature =
+      Signature::from_slice(signature).map_err(|_| Error::message(ERR_IS))?;
+
+    // Reject malleable (high-S) signatures
+    if signature.normalize_s().is_some() {
+      return Err(Error::message(ERR_IS));
+    }
+
+    let key: VerifyingKey = VerifyingKey::recover_from_msg(message, &signature, recovery)
+      .map_err(|_| Error::message(ERR_IS))?;
+
+    if compare(&key, public)? {
+      Ok(())
+    } else {
+      Err(Error::message(ERR_IS))
+    }
+  }
+}
+
+/// Returns `true` if the `secret` key is the private key of `public`.
+///
+/// With `ES256K-R`, `public` may also be a 20-byte Ethereum address.
+pub(crate) fn matches(alg: JwsAlgorithm, secret: &[u8], public: &[u8]) -> Result<bool> {
+  match alg {
+    JwsAlgorithm::ES256 => nistp256::matches(secret, public),
+    JwsAlgorithm::ES256K => secp256k1::matches(secret, public),
+    JwsAlgorithm::ES256KR => recovery::matches(secret, public),
+  }
+}
+
+/// Signs the SHA-256 digest of `message`; returns a low-S `r || s` signature,
+/// followed by the recovery id with `ES256K-R`.
+pub(crate) fn sign(alg: JwsAlgorithm, secret: &[u8], message: &[u8]) -> Result<Vec<u8>> {
+  match alg {
+    JwsAlgorithm::ES256 => nistp256::sign(secret, message),
+    JwsAlgorithm::ES256K => secp256k1::sign(secret, message),
+    JwsAlgorithm::ES256KR => recovery::sign(secret, message),
+  }
+}
+
+/// Verifies a low-S `r || s` signature of the SHA-256 digest of `message`.
+///
+/// With `ES256K-R`, the public key is recovered from an `r || s || v`
+/// signature and compared to `public`, which may be a 20-byte Ethereum
+/// address.
+pub(crate) fn verify(
+  alg: JwsAlgorithm,
+  public: &[u8],
+  message: &[u8],
+  signature: &[u8],
+) -> Result<()> {
+  match alg {
+    JwsAlgorithm::ES256 => nistp256::verify(public, message, signature),
+    JwsAlgorithm::ES256K => secp256k1::verify(public, message, signature),
+    JwsAlgorithm::ES256KR => recovery::verify(public, message, signature),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SECRET: [u8; 32] = [7; 32];
+
+  macro_rules! high_s {
+    ($curve:ident, $signature:expr) => {{
+      let signature = ::$curve::ecdsa::Signature::from_slice(&$signature).unwrap();
+      let signature =
+        ::$curve::ecdsa::Signature::from_scalars(signature.r(), -*signature.s()).unwrap();
+
+      signature.to_bytes().to_vec()
+    }};
+  }
+
+  fn public(alg: JwsAlgorithm) -> Vec<u8> {
+    match alg {
+      JwsAlgorithm::ES256 => ::p256::ecdsa::SigningKey::from_slice(&SECRET)
+        .unwrap()
+        .verifying_key()
+        .to_encoded_point(true)
+        .as_bytes()
+        .to_vec(),
+      JwsAlgorithm::ES256K | JwsAlgorithm::ES256KR => {
+        ::k256::ecdsa::SigningKey::from_slice(&SECRET)
+          .unwrap()
+          .verifying_key()
+          .to_encoded_point(true)
+          .as_bytes()
+          .to_vec()
+      }
+    }
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    for alg in [JwsAlgorithm::ES256, JwsAlgorithm::ES256K] {
+      let signature: Vec<u8> = sign(alg, &SECRET, b"message").unwrap();
+
+      assert_eq!(signature.len(), 64);
+      assert!(matches(alg, &SECRET, &public(alg)).unwrap());
+      assert!(verify(alg, &public(alg), b"message", &signature).is_ok());
+      assert!(verify(alg, &public(alg), b"massage", &signature).is_err());
+    }
+  }
+
+  #[test]
+  fn test_reject_high_s() {
+    let signature: Vec<u8> = sign(JwsAlgorithm::ES256, &SECRET, b"message").unwrap();
+    let signature: Vec<u8> = high_s!(p256, signature);
+
+    assert!(verify(
+      JwsAlgorithm::ES256,
+      &public(JwsAlgorithm::ES256),
+      b"message",
+      &signature
+    )
+    .is_err());
+
+    let signature: Vec<u8> = sign(JwsAlgorithm::ES256K, &SECRET, b"message").unwrap();
+    let signature: Vec<u8> = high_s!(k256, signature);
+
+    assert!(verify(
+      JwsAlgorithm::ES256K,
+      &public(JwsAlgorithm::ES256K),
+      b"message",
+      &signature
+    )
+    .is_err());
+  }
+
+  #[test]
+  fn test_recover_address() {
+    // The well-known address of the private key `1`
+    let mut secret: [u8; 32] = [0; 32];
+    let address: Vec<u8> = hex::decode("7e5f4552091a69125d5dfcb7b8c2659029395bdf").unwrap();
+
+    secret[31] = 1;
+
+    let signature: Vec<u8> = sign(JwsAlgorithm::ES256KR, &secret, b"message").unwrap();
+
+    assert_eq!(signature.len(), 65);
+    assert!(matches(JwsAlgorithm::ES256KR, &secret, &address).unwrap());
+    assert!(verify(JwsAlgorithm::ES256KR, &address, b"message", &signature).is_ok());
+    assert!(verify(JwsAlgorithm::ES256KR, &address, b"massage", &signature).is_err());
+    assert!(verify(JwsAlgorithm::ES256KR, &[0; 20], b"message", &signature).is_err());
+
+    let mut legacy: Vec<u8> = signature.clone();
+
+    legacy[64] += 27;
+
+    assert!(verify(JwsAlgorithm::ES256KR, &address, b"message", &legacy).is_ok());
+  }
+}
@@ -0,0 +1,357 @@
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld;
+use crate::jws::DecodedJws;
+use crate::jws::JwsAlgorithm;
+use crate::jws::JwsEncoder;
+use crate::jws::JwsHeader;
+use crate::lib::*;
+use crate::signature::Encode;
+use crate::signature::Sign;
+use crate::signature::Signature;
+use crate::signature::SignatureData;
+use crate::signature::SuiteName;
+use crate::signature::Verify;
+use crate::suites::ecdsa;
+use crate::utils::canonicalize;
+use crate::verification::MethodType;
+use crate::verification::MethodWrap;
+
+const ERR_MJWS: &str = "Missing JWS Signature Value";
+const ERR_IJA: &str = "Invalid JWS Algorithm";
+
+/// A signature suite signing with ES256K, modelled on `EcdsaSecp256k1Signature2019`.
+///
+/// Messages are canonicalized with the JSON Canonicalization Scheme (RFC 8785)
+/// and signed with ES256K; signatures are detached JWS with an unencoded
+/// payload.
+///
+/// Note: [`EcdsaSecp256k1Signature2019`] canonicalizes with URDNA2015, so
+/// proofs of this suite are not interoperable with it and use a distinct name.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct JcsEcdsaSecp256k1Signature2019;
+
+impl JcsEcdsaSecp256k1Signature2019 {
+  /// The name of the signature suite.
+  pub const NAME: &'static str = "JcsEcdsaSecp256k1Signature2019";
+
+  /// The algorithm of the signature suite.
+  pub const ALG: JwsAlgorithm = JwsAlgorithm::ES256K;
+}
+
+impl SuiteName for JcsEcdsaSecp256k1Signature2019 {
+  fn name(&self) -> String {
+    Self::NAME.to_string()
+  }
+}
+
+impl Encode for JcsEcdsaSecp256k1Signature2019 {
+  fn encode_input<T>(&self, data: &T) -> Result<Vec<u8>>
+  where
+    T: Serialize,
+  {
+    let input: Vec<u8> = canonicalize(data)?;
+
+    encoder().map(|encoder| encoder.signing_input(&input))
+  }
+
+  fn encode_signature(&self, signature: &[u8]) -> Result<SignatureData> {
+    encoder().map(|encoder| SignatureData::Jws(encoder.encode_detached(signature)))
+  }
+}
+
+impl Sign for JcsEcdsaSecp256k1Signature2019 {
+  fn sign<T>(&self, data: &T, secret: &[u8]) -> Result<SignatureData>
+  where
+    T: Serialize,
+  {
+    let input: Vec<u8> = self.encode_input(data)?;
+    let signature: Vec<u8> = ecdsa::sign(Self::ALG, secret, &input)?;
+
+    self.encode_signature(&signature)
+  }
+}
+
+impl Verify for JcsEcdsaSecp256k1Signature2019 {
+  const METHODS: &'static [MethodType] = &[MethodType::EcdsaSecp256k1VerificationKey2019];
+
+  fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+  where
+    T: Serialize,
+  {
+    decode(signature)?.verify(Some(&canonicalize(data)?), public)
+  }
+
+  fn verify_method<M>(&self, signature: &Signature, method: &MethodWrap<'_, M>) -> Result<()>
+  where
+    M: Serialize,
+  {
+    decode(signature.data())?
+      .header()
+      .check_method(&signature.verification_method, method)
+  }
+}
+
+/// The `EcdsaSecp256k1Signature2019` signature suite.
+///
+/// Messages are JSON-LD documents canonicalized with URDNA2015; the SHA-256
+/// hashes of the canonical proof options and document are signed with ES256K
+/// as a detached JWS with an unencoded payload.
+///
+/// Documents must declare an `@context` and may only reference the contexts
+/// embedded in this crate, such as `https://www.w3.org/ns/did/v1` and
+/// `https://w3id.org/security/suites/secp256k1-2019/v1`. Terms that are not
+/// defined by the context are rejected instead of being left unsigned.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EcdsaSecp256k1Signature2019;
+
+impl EcdsaSecp256k1Signature2019 {
+  /// The name of the signature suite.
+  pub const NAME: &'static str = "EcdsaSecp256k1Signature2019";
+
+  /// The algorithm of the signature suite.
+  pub const ALG: JwsAlgorithm = JwsAlgorithm::ES256K;
+}
+
+impl SuiteName for EcdsaSecp256k1Signature2019 {
+  fn name(&self) -> String {
+    Self::NAME.to_string()
+  }
+}
+
+impl Encode for EcdsaSecp256k1Signature2019 {
+  fn encode_input<T>(&self, data: &T) -> Result<Vec<u8>>
+  where
+    T: Serialize,
+  {
+    let input: Vec<u8> = jsonld::signing_input(data)?;
+
+    encoder().map(|encoder| encoder.signing_input(&input))
+  }
+
+  fn encode_signature(&self, signature: &[u8]) -> Result<SignatureData> {
+    encoder().map(|encoder| SignatureData::Jws(encoder.encode_detached(signature)))
+  }
+}
+
+impl Sign for EcdsaSecp256k1Signature2019 {
+  fn sign<T>(&self, data: &T, secret: &[u8]) -> Result<SignatureData>
+  where
+    T: Serialize,
+  {
+    let input: Vec<u8> = self.encode_input(data)?;
+    let signature: Vec<u8> = ecdsa::sign(Self::ALG, secret, &input)?;
+
+    self.encode_signature(&signature)
+  }
+}
+
+impl Verify for EcdsaSecp256k1Signature2019 {
+  const METHODS: &'static [MethodType] = &[MethodType::EcdsaSecp256k1VerificationKey2019];
+
+  fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+  where
+    T: Serialize,
+  {
+    decode(signature)?.verify(Some(&jsonld::signing_input(data)?), public)
+  }
+
+  fn verify_method<M>(&self, signature: &Signature, method: &MethodWrap<'_, M>) -> Result<()>
+  where
+    M: Serialize,
+  {
+    decode(signature.data())?
+      .header()
+      .check_method(&signature.verification_method, method)
+  }
+}
+
+fn encoder() -> Result<JwsEncoder> {
+  JwsEncoder::new(JwsHeader::new_unencoded(JwsAlgorithm::ES256K))
+}
+
+fn decode(signature: &SignatureData) -> Result<DecodedJws<'_>> {
+  let jws: &str = signature
+    .try_jws()
+    .ok_or_else(|| Error::message(ERR_MJWS))?;
+  let jws: DecodedJws<'_> = DecodedJws::decode_detached(jws)?;
+
+  if jws.header().alg != JwsAlgorithm::ES256K {
+    return Err(Error::message(ERR_IJA));
+  }
+
+  Ok(jws)
+}
+
+#[cfg(test)]
+mod tests {
+  use did_url::DID;
+
+  use super::*;
+  use crate::document::Document;
+  use crate::document::DocumentBuilder;
+  use crate::jsonld::contexts;
+  use crate::signature::LdSuite;
+  use crate::signature::SecretKey;
+  use crate::signature::SignatureOptions;
+  use crate::signature::VerificationPolicy;
+  use crate::suites::LocalSigner;
+  use crate::utils::Value;
+  use crate::verifiable::VerifiableDocument;
+  use crate::verification::MethodBuilder;
+  use crate::verification::MethodData;
+
+  const SECRET: [u8; 32] = [7; 32];
+
+  fn document() -> VerifiableDocument {
+    let public = k256::ecdsa::SigningKey::from_slice(&SECRET)
+      .unwrap()
+      .verifying_key()
+      .to_encoded_point(true);
+
+    let document: Document = DocumentBuilder::default()
+      .id("did:example:123".parse().unwrap())
+      .verification_method(
+        MethodBuilder::default()
+          .id("did:example:123#key-1".parse().unwrap())
+          .controller("did:example:123".parse().unwrap())
+          .key_type(MethodType::EcdsaSecp256k1VerificationKey2019)
+          .key_data(MethodData::new_b16(public.as_bytes()))
+          .build()
+          .unwrap(),
+      )
+      .assertion_method("did:example:123#key-1".parse::<DID>().unwrap())
+      .build()
+      .unwrap();
+
+    VerifiableDocument::new(document)
+  }
+
+  fn ld_document() -> VerifiableDocument {
+    let public = k256::ecdsa::SigningKey::from_slice(&SECRET)
+      .unwrap()
+      .verifying_key()
+      .to_encoded_point(true);
+
+    let context: Value = Value::Array(alloc::vec![
+      Value::from(contexts::DID_V1),
+      Value::from(contexts::SECP256K1_2019_V1),
+    ]);
+
+    let document: Document = DocumentBuilder::default()
+      .id("did:example:123".parse().unwrap())
+      .verification_method(
+        MethodBuilder::default()
+          .id("did:example:123#key-1".parse().unwrap())
+          .controller("did:example:123".parse().unwrap())
+          .key_type(MethodType::EcdsaSecp256k1VerificationKey2019)
+          .key_data(MethodData::new_b58(public.as_bytes()))
+          .build()
+          .unwrap(),
+      )
+      .assertion_method("did:example:123#key-1".parse::<DID>().unwrap())
+      .property("@context", context)
+      .build()
+      .unwrap();
+
+    VerifiableDocument::new(document)
+  }
+
+  fn options() -> SignatureOptions {
+    let mut options: SignatureOptions =
+      SignatureOptions::with_purpose("#key-1".into(), "assertionMethod".into());
+
+    options.created = Some("2021-01-01T00:00:00Z".parse().unwrap());
+    options
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    let suite: LdSuite<JcsEcdsaSecp256k1Signature2019> =
+      LdSuite::new(JcsEcdsaSecp256k1Signature2019);
+    let policy = VerificationPolicy::new(|| 1609459200);
+    let mut document: VerifiableDocument = document();
+
+    suite.sign(&mut document, options(), &SECRET).unwrap();
+
+    assert!(suite.verify(&document, &policy).is_ok());
+
+    let secret: SecretKey =
+      SecretKey::from_bytes(MethodType::EcdsaSecp256k1VerificationKey2019, SECRET);
+    let mut document: VerifiableDocument = self::document();
+
+    suite
+      .sign_with(&mut document, options(), &LocalSigner::new(secret))
+      .unwrap();
+
+    assert!(suite.verify(&document, &policy).is_ok());
+
+    document
+      .also_known_as_mut()
+      .push("https://example.com".parse().unwrap());
+
+    assert!(suite.verify(&document, &policy).is_err());
+  }
+
+  #[test]
+  fn test_sign_verify_ld() {
+    let suite: LdSuite<EcdsaSecp256k1Signature2019> = LdSuite::new(EcdsaSecp256k1Signature2019);
+    let policy = VerificationPolicy::new(|| 1609459200);
+    let mut document: VerifiableDocument = ld_document();
+
+    suite.sign(&mut document, options(), &SECRET).unwrap();
+
+    let jws: &str = document.proof().unwrap().data().try_jws().unwrap();
+
+    assert!(jws.contains(".."));
+    assert!(suite.verify(&document, &policy).is_ok());
+
+    let secret: SecretKey =
+      SecretKey::from_bytes(MethodType::EcdsaSecp256k1VerificationKey2019, SECRET);
+    let mut document: VerifiableDocument = ld_document();
+
+    suite
+      .sign_with(&mut document, options(), &LocalSigner::new(secret))
+      .unwrap();
+
+    assert!(suite.verify(&document, &policy).is_ok());
+
+    document
+      .also_known_as_mut()
+      .push("https://example.com".parse().unwrap());
+
+    assert!(suite.verify(&document, &policy).is_err());
+  }
+
+  #[test]
+  fn test_sign_ld_invalid() {
+    let suite: LdSuite<EcdsaSecp256k1Signature2019> = LdSuite::new(EcdsaSecp256k1Signature2019);
+
+    // Documents without a JSON-LD context
+    assert!(suite.sign(&mut document(), options(), &SECRET).is_err());
+
+    // Properties not defined by the context
+    let mut document: VerifiableDocument = ld_document();
+
+    document
+      .properties_mut()
+      .insert("undefined".into(), Value::from("value"));
+
+    assert!(suite.sign(&mut document, options(), &SECRET).is_err());
+  }
+
+  #[test]
+  fn test_verify_jcs_ld() {
+    let jcs: LdSuite<JcsEcdsaSecp256k1Signature2019> = LdSuite::new(JcsEcdsaSecp256k1Signature2019);
+    let suite: LdSuite<EcdsaSecp256k1Signature2019> = LdSuite::new(EcdsaSecp256k1Signature2019);
+    let policy = VerificationPolicy::new(|| 1609459200);
+    let mut document: VerifiableDocument = ld_document();
+
+    jcs.sign(&mut document, options(), &SECRET).unwrap();
+
+    assert!(jcs.verify(&document, &policy).is_ok());
+    assert!(suite.verify(&document, &policy).is_err());
+  }
+}
@@ -0,0 +1,227 @@
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jws::DecodedJws;
+use crate::jws::JwsAlgorithm;
+use crate::jws::JwsEncoder;
+use crate::jws::JwsHeader;
+use crate::lib::*;
+use crate::signature::Encode;
+use crate::signature::Sign;
+use crate::signature::Signature;
+use crate::signature::SignatureData;
+use crate::signature::SuiteName;
+use crate::signature::Verify;
+use crate::suites::ecdsa;
+use crate::utils::canonicalize;
+use crate::verification::MethodType;
+use crate::verification::MethodWrap;
+
+const ERR_MJWS: &str = "Missing JWS Signature Value";
+const ERR_IJA: &str = "Invalid JWS Algorithm";
+
+/// An implementation of the `EcdsaSecp256k1RecoverySignature2020` signature suite.
+///
+/// Messages are canonicalized with the JSON Canonicalization Scheme and signed
+/// with ES256K-R; signatures are detached JWS with an unencoded payload.
+///
+/// The public key is recovered from the signature, so methods may identify the
+/// signer by a `blockchainAccountId` or `ethereumAddress` instead of a key.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EcdsaSecp256k1RecoverySignature2020;
+
+impl EcdsaSecp256k1RecoverySignature2020 {
+  /// The name of the signature suite.
+  pub const NAME: &'static str = "EcdsaSecp256k1RecoverySignature2020";
+
+  /// The algorithm of the signature suite.
+  pub const ALG: JwsAlgorithm = JwsAlgorithm::ES256KR;
+
+  fn encoder(&self) -> Result<JwsEncoder> {
+    JwsEncoder::new(JwsHeader::new_unencoded(Self::ALG))
+  }
+
+  fn decode(signature: &SignatureData) -> Result<DecodedJws<'_>> {
+    let jws: &str = signature
+      .try_jws()
+      .ok_or_else(|| Error::message(ERR_MJWS))?;
+    let jws: DecodedJws<'_> = DecodedJws::decode_detached(jws)?;
+
+    if jws.header().alg != Self::ALG {
+      return Err(Error::message(ERR_IJA));
+    }
+
+    Ok(jws)
+  }
+}
+
+impl SuiteName for EcdsaSecp256k1RecoverySignature2020 {
+  fn name(&self) -> String {
+    Self::NAME.to_string()
+  }
+}
+
+impl Encode for EcdsaSecp256k1RecoverySignature2020 {
+  fn encode_input<T>(&self, data: &T) -> Result<Vec<u8>>
+  where
+    T: Serialize,
+  {
+    let input: Vec<u8> = canonicalize(data)?;
+
+    self.encoder().map(|encoder| encoder.signing_input(&input))
+  }
+
+  fn encode_signature(&self, signature: &[u8]) -> Result<SignatureData> {
+    self
+      .encoder()
+      .map(|encoder| SignatureData::Jws(encoder.encode_detached(signature)))
+  }
+}
+
+impl Sign for EcdsaSecp256k1RecoverySignature2020 {
+  fn sign<T>(&self, data: &T, secret: &[u8]) -> Result<SignatureData>
+  where
+    T: Serialize,
+  {
+    let input: Vec<u8> = self.encode_input(data)?;
+    let signature: Vec<u8> = ecdsa::sign(Self::ALG, secret, &input)?;
+
+    self.encode_signature(&signature)
+  }
+}
+
+impl Verify for EcdsaSecp256k1RecoverySignature2020 {
+  const METHODS: &'static [MethodType] = &[
+    MethodType::EcdsaSecp256k1RecoveryMethod2020,
+    MethodType::EcdsaSecp256k1VerificationKey2019,
+  ];
+
+  fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+  where
+    T: Serialize,
+  {
+    Self::decode(signature)?.verify(Some(&canonicalize(data)?), public)
+  }
+
+  fn verify_method<M>(&self, signature: &Signature, method: &MethodWrap<'_, M>) -> Result<()>
+  where
+    M: Serialize,
+  {
+    Self::decode(signature.data())?
+      .header()
+      .check_method(&signature.verification_method, method)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use did_url::DID;
+
+  use super::*;
+  use crate::document::Document;
+  use crate::document::DocumentBuilder;
+  use crate::signature::LdSuite;
+  use crate::signature::SecretKey;
+  use crate::signature::SignatureOptions;
+  use crate::signature::VerificationPolicy;
+  use crate::suites::LocalSigner;
+  use crate::verifiable::VerifiableDocument;
+  use crate::verification::MethodBuilder;
+  use crate::verification::MethodData;
+
+  const SECRET: [u8; 32] = [7; 32];
+
+  // The Ethereum address of `SECRET`.
+  const ADDRESS: &str = "0x4a62316623ad457f02cdc5d997ded67a383ec569";
+
+  fn document(key_data: MethodData) -> VerifiableDocument {
+    let document: Document = DocumentBuilder::default()
+      .id("did:example:123".parse().unwrap())
+      .verification_method(
+        MethodBuilder::default()
+          .id("did:example:123#key-1".parse().unwrap())
+          .controller("did:example:123".parse().unwrap())
+          .key_type(MethodType::EcdsaSecp256k1RecoveryMethod2020)
+          .key_data(key_data)
+          .build()
+          .unwrap(),
+      )
+      .assertion_method("did:example:123#key-1".parse::<DID>().unwrap())
+      .build()
+      .unwrap();
+
+    VerifiableDocument::new(document)
+  }
+
+  fn options() -> SignatureOptions {
+    let mut options: SignatureOptions =
+      SignatureOptions::with_purpose("#key-1".into(), "assertionMethod".into());
+
+    options.created = Some("2021-01-01T00:00:00Z".parse().unwrap());
+    options
+  }
+
+  #[test]
+  fn test_sign_verify_address() {
+    let suite: LdSuite<EcdsaSecp256k1RecoverySignature2020> =
+      LdSuite::new(EcdsaSecp256k1RecoverySignature2020);
+    let policy = VerificationPolicy::new(|| 1609459200);
+
+    for key_data in [
+      MethodData::EthereumAddress(ADDRESS.into()),
+      MethodData::BlockchainAccountId(alloc::format!("eip155:1:{}", ADDRESS)),
+    ] {
+      let mut document: VerifiableDocument = document(key_data);
+
+      suite.sign(&mut document, options(), &SECRET).unwrap();
+
+      assert!(suite.verify(&document, &policy).is_ok());
+
+      document
+        .also_known_as_mut()
+        .push("https://example.com".parse().unwrap());
+
+      assert!(suite.verify(&document, &policy).is_err());
+    }
+  }
+
+  #[test]
+  fn test_sign_signer() {
+    let suite: LdSuite<EcdsaSecp256k1RecoverySignature2020> =
+      LdSuite::new(EcdsaSecp256k1RecoverySignature2020);
+    let policy = VerificationPolicy::new(|| 1609459200);
+    let secret: SecretKey =
+      SecretKey::from_bytes(MethodType::EcdsaSecp256k1RecoveryMethod2020, SECRET);
+    let mut document: VerifiableDocument = document(MethodData::EthereumAddress(ADDRESS.into()));
+
+    suite
+      .sign_with(&mut document, options(), &LocalSigner::new(secret))
+      .unwrap();
+
+    assert!(suite.verify(&document, &policy).is_ok());
+
+    let secret: SecretKey =
+      SecretKey::from_bytes(MethodType::EcdsaSecp256k1RecoveryMethod2020, [8; 32]);
+    let mut document: VerifiableDocument =
+      self::document(MethodData::EthereumAddress(ADDRESS.into()));
+
+    assert!(suite
+      .sign_with(&mut document, options(), &LocalSigner::new(secret))
+      .is_err());
+  }
+
+  #[test]
+  fn test_verify_wrong_address() {
+    let suite: LdSuite<EcdsaSecp256k1RecoverySignature2020> =
+      LdSuite::new(EcdsaSecp256k1RecoverySignature2020);
+    let policy = VerificationPolicy::new(|| 1609459200);
+    let mut document: VerifiableDocument = document(MethodData::EthereumAddress(
+      "0x0000000000000000000000000000000000000001".into(),
+    ));
+
+    suite.sign(&mut document, options(), &SECRET).unwrap();
+
+    assert!(suite.verify(&document, &policy).is_err());
+  }
+}
@@ -0,0 +1,192 @@
+use core::convert::TryInto;
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use curve25519_dalek::edwards::EdwardsPoint;
+use curve25519_dalek::scalar::Scalar;
+use ed25519_dalek::Signature;
+use ed25519_dalek::Signer as _;
+use ed25519_dalek::SigningKey;
+use ed25519_dalek::VerifyingKey;
+use ed25519_dalek::PUBLIC_KEY_LENGTH;
+use ed25519_dalek::SECRET_KEY_LENGTH;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+
+const ERR_ISK: &str = "Invalid Ed25519 Secret Key";
+const ERR_IPK: &str = "Invalid Ed25519 Public Key";
+const ERR_IS: &str = "Invalid Ed25519 Signature";
+
+// Accepts a 32-byte seed or a 64-byte seed/public key pair.
+fn signing_key(secret: &[u8]) -> Result<SigningKey> {
+  let seed: &[u8] = match secret.len() {
+    SECRET_KEY_LENGTH => secret,
+    len if len == SECRET_KEY_LENGTH + PUBLIC_KEY_LENGTH => &secret[..SECRET_KEY_LENGTH],
+    _ => return Err(Error::message(ERR_ISK)),
+  };
+
+  let key: SigningKey = seed
+    .try_into()
+    .map(|seed| SigningKey::from_bytes(&seed))
+    .map_err(|_| Error::message(ERR_ISK))?;
+
+  if secret.len() > SECRET_KEY_LENGTH
+    && secret[SECRET_KEY_LENGTH..] != key.verifying_key().to_bytes()
+  {
+    return Err(Error::message(ERR_ISK));
+  }
+
+  Ok(key)
+}
+
+pub(crate) fn public_key(secret: &[u8]) -> Result<Vec<u8>> {
+  signing_key(secret).map(|key| key.verifying_key().to_bytes().to_vec())
+}
+
+pub(crate) fn sign(secret: &[u8], message: &[u8]) -> Result<Vec<u8>> {
+  signing_key(secret).map(|key| key.sign(message).to_bytes().to_vec())
+}
+
+fn verifying_key(public: &[u8]) -> Result<VerifyingKey> {
+  public
+    .try_into()
+    .map_err(|_| Error::message(ERR_IPK))
+    .and_then(|public| VerifyingKey::from_bytes(public).map_err(|_| Error::message(ERR_IPK)))
+}
+
+pub(crate) fn verify(public: &[u8], message: &[u8], signature: &[u8]) -> Result<()> {
+  let signature: Signature =
+    Signature::from_slice(signature).map_err(|_| Error::message(ERR_IS))?;
+
+  verifying_key(public)?
+    .verify_strict(message, &signature)
+    .map_err(|_| Error::message(ERR_IS))
+}
+
+// Returns `true` if the batch equation gives the same result as
+// `verify_strict` for the given key and signature; i.e. `S` is canonical and
+// both `R` and the public key are in the prime-order subgroup.
+fn is_batchable(public: &VerifyingKey, signature: &Signature) -> bool {
+  let r: Option<EdwardsPoint> = CompressedEdwardsY(*signature.r_bytes()).decompress();
+
+  bool::from(Scalar::from_canonical_bytes(*signature.s_bytes()).is_some())
+    && r.is_some_and(|r| !r.is_small_order() && r.is_torsion_free())
+    && !public.is_weak()
+    && public.to_edwards().is_torsion_free()
+}
+
+/// Verifies many `(public, message, signature)` triples; returns one result
+/// per item, in order.
+///
+/// Results are the same as with `verify`. The batch equation combines items
+/// with random coefficients, which cancel small order components of `R` and
+/// the public key about as often as not; items with such components, or a
+/// non-canonical `S`, are verified individually. The others are verified as
+/// a batch, and individually if the batch fails.
+pub(crate) fn verify_batch(items: &[(&[u8], &[u8], &[u8])]) -> Vec<Result<()>> {
+  let mut output: Vec<Result<()>> = Vec::with_capacity(items.len());
+  let mut indexes: Vec<usize> = Vec::new();
+  let mut messages: Vec<&[u8]> = Vec::new();
+  let mut signatures: Vec<Signature> = Vec::new();
+  let mut publics: Vec<VerifyingKey> = Vec::new();
+
+  for (index, (public, message, signature)) in items.iter().enumerate() {
+    let parsed: Option<(VerifyingKey, Signature)> = verifying_key(public)
+      .ok()
+      .zip(Signature::from_slice(signature).ok());
+
+    match parsed {
+      Some((public, signature)) if is_batchable(&public, &signature) => {
+        indexes.push(index);
+        messages.push(message);
+        signatures.push(signature);
+        publics.push(public);
+        output.push(Ok(()));
+      }
+      _ => output.push(verify(public, message, signature)),
+    }
+  }
+
+  if !indexes.is_empty() && ed25519_dalek::verify_batch(&messages, &signatures, &publics).is_err() {
+    for index in indexes {
+      let (public, message, signature): (&[u8], &[u8], &[u8]) = items[index];
+
+      output[index] = verify(public, message, signature);
+    }
+  }
+
+  output
+}
+
+#[cfg(test)]
+mod tests {
+  use sha2::Digest as _;
+  use sha2::Sha512;
+
+  use super::*;
+
+  const SECRET: [u8; 32] = [1; 32];
+
+  // A point of order 2, `(0, -1)`
+  const SMALL_ORDER: [u8; 32] = [
+    0xEC, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F,
+  ];
+
+  #[test]
+  fn test_verify_batch() {
+    let public: Vec<u8> = public_key(&SECRET).unwrap();
+    let signature: Vec<u8> = sign(&SECRET, b"message").unwrap();
+
+    let results: Vec<Result<()>> = verify_batch(&[
+      (&public, b"message", &signature),
+      (&public, b"massage", &signature),
+      (&public, b"message", &signature),
+    ]);
+
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+  }
+
+  #[test]
+  fn test_small_order_r() {
+    // With `R` of small order and `S = k * a`, `[S]B - R - [k]A` is the
+    // small order `-R`, which the batch equation cancels for some messages
+    let key: SigningKey = signing_key(&SECRET).unwrap();
+    let public: Vec<u8> = public_key(&SECRET).unwrap();
+    let public_key: VerifyingKey = verifying_key(&public).unwrap();
+
+    let forge = |message: &[u8]| -> Vec<u8> {
+      let k: Scalar = Scalar::from_bytes_mod_order_wide(
+        &Sha512::new()
+          .chain_update(SMALL_ORDER)
+          .chain_update(&public)
+          .chain_update(message)
+          .finalize()
+          .into(),
+      );
+
+      let mut signature: Vec<u8> = SMALL_ORDER.to_vec();
+
+      signature.extend_from_slice(&(k * key.to_scalar()).to_bytes());
+      signature
+    };
+
+    let (message, signature): (Vec<u8>, Vec<u8>) = (0..64_u8)
+      .map(|index| alloc::vec![index])
+      .map(|message| {
+        let signature: Vec<u8> = forge(&message);
+        (message, signature)
+      })
+      .find(|(message, signature)| {
+        let parsed: Signature = Signature::from_slice(signature).unwrap();
+
+        ed25519_dalek::verify_batch(&[message], &[parsed], &[public_key]).is_ok()
+      })
+      .unwrap();
+
+    assert!(verify(&public, &message, &signature).is_err());
+    assert!(verify_batch(&[(&public, &message, &signature)])[0].is_err());
+  }
+}
@@ -0,0 +1,306 @@
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::signature::BatchItem;
+use crate::signature::Encode;
+use crate::signature::Sign;
+use crate::signature::SignatureData;
+use crate::signature::SuiteName;
+use crate::signature::Verify;
+use crate::suites::ed25519;
+use crate::utils::canonicalize;
+use crate::verification::MethodType;
+
+const ERR_MSV: &str = "Missing Signature Value";
+const ERR_ISV: &str = "Invalid Signature Value";
+
+/// An implementation of the `JcsEd25519Signature2020` signature suite.
+///
+/// Messages are canonicalized with the JSON Canonicalization Scheme and signed
+/// with Ed25519; signatures are base58-encoded `signatureValue`s.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct JcsEd25519Signature2020;
+
+impl JcsEd25519Signature2020 {
+  /// The name of the signature suite.
+  pub const NAME: &'static str = "JcsEd25519Signature2020";
+}
+
+impl SuiteName for JcsEd25519Signature2020 {
+  fn name(&self) -> String {
+    Self::NAME.to_string()
+  }
+}
+
+impl Encode for JcsEd25519Signature2020 {
+  fn encode_input<T>(&self, data: &T) -> Result<Vec<u8>>
+  where
+    T: Serialize,
+  {
+    canonicalize(data)
+  }
+
+  fn encode_signature(&self, signature: &[u8]) -> Result<SignatureData> {
+    Ok(SignatureData::Signature(
+      bs58::encode(signature).into_string(),
+    ))
+  }
+}
+
+impl Sign for JcsEd25519Signature2020 {
+  fn sign<T>(&self, data: &T, secret: &[u8]) -> Result<SignatureData>
+  where
+    T: Serialize,
+  {
+    let signature: Vec<u8> = ed25519::sign(secret, &self.encode_input(data)?)?;
+
+    self.encode_signature(&signature)
+  }
+}
+
+impl Verify for JcsEd25519Signature2020 {
+  const METHODS: &'static [MethodType] = &[
+    MethodType::JcsEd25519Key2020,
+    MethodType::Ed25519VerificationKey2018,
+  ];
+
+  fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+  where
+    T: Serialize,
+  {
+    let signature: Vec<u8> = decode_signature(signature)?;
+
+    ed25519::verify(public, &self.encode_input(data)?, &signature)
+  }
+
+  fn verify_batch<T>(&self, items: &[BatchItem<'_, T>]) -> Vec<Result<()>>
+  where
+    T: Serialize,
+  {
+    let decoded: Vec<Result<(Vec<u8>, Vec<u8>)>> = items
+      .iter()
+      .map(|item| {
+        Ok((
+          self.encode_input(item.data)?,
+          decode_signature(item.signature)?,
+        ))
+      })
+      .collect();
+
+    let batch: Vec<(&[u8], &[u8], &[u8])> = items
+      .iter()
+      .zip(decoded.iter())
+      .filter_map(|(item, decoded)| {
+        let (message, signature) = decoded.as_ref().ok()?;
+
+        Some((item.public, &message[..], &signature[..]))
+      })
+      .collect();
+
+    let mut checked = ed25519::verify_batch(&batch).into_iter();
+
+    decoded
+      .into_iter()
+      .map(|item| item.and_then(|_| checked.next().expect("one result per item")))
+      .collect()
+  }
+}
+
+fn decode_signature(signature: &SignatureData) -> Result<Vec<u8>> {
+  signature
+    .try_signature()
+    .ok_or_else(|| Error::message(ERR_MSV))
+    .and_then(|value| {
+      bs58::decode(value)
+        .into_vec()
+        .map_err(|_| Error::message(ERR_ISV))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+  use did_url::DID;
+
+  use super::*;
+  use crate::document::Document;
+  use crate::document::DocumentBuilder;
+  use crate::signature::AsyncSigner;
+  use crate::signature::BatchVerifier;
+  use crate::signature::LdSuite;
+  use crate::signature::SecretKey;
+  use crate::signature::SignFuture;
+  use crate::signature::SignatureOptions;
+  use crate::signature::Signer;
+  use crate::signature::VerificationPolicy;
+  use crate::suites::LocalSigner;
+  use crate::verifiable::VerifiableDocument;
+  use crate::verification::MethodBuilder;
+  use crate::verification::MethodData;
+  use crate::verification::MethodWrap;
+
+  const SECRET: [u8; 32] = [7; 32];
+
+  // An asynchronous signer, standing in for a remote KMS or HSM.
+  struct RemoteSigner(LocalSigner);
+
+  impl AsyncSigner for RemoteSigner {
+    fn sign<'a>(&'a self, message: &'a [u8], method: MethodWrap<'a>) -> SignFuture<'a> {
+      Box::pin(async move { Signer::sign(&self.0, message, method) })
+    }
+  }
+
+  fn document() -> VerifiableDocument {
+    let document: Document = DocumentBuilder::default()
+      .id("did:example:123".parse().unwrap())
+      .verification_method(
+        MethodBuilder::default()
+          .id("did:example:123#key-1".parse().unwrap())
+          .controller("did:example:123".parse().unwrap())
+          .key_type(MethodType::Ed25519VerificationKey2018)
+          .key_data(MethodData::new_b58(ed25519::public_key(&SECRET).unwrap()))
+          .build()
+          .unwrap(),
+      )
+      .assertion_method("did:example:123#key-1".parse::<DID>().unwrap())
+      .build()
+      .unwrap();
+
+    VerifiableDocument::new(document)
+  }
+
+  fn secret(data: [u8; 32]) -> SecretKey {
+    SecretKey::from_bytes(MethodType::Ed25519VerificationKey2018, data)
+  }
+
+  fn options() -> SignatureOptions {
+    let mut options: SignatureOptions =
+      SignatureOptions::with_purpose("#key-1".into(), "assertionMethod".into());
+
+    options.created = Some("2021-01-01T00:00:00Z".parse().unwrap());
+    options
+  }
+
+  fn verify(document: &VerifiableDocument) -> Result<()> {
+    LdSuite::new(JcsEd25519Signature2020).verify(document, &VerificationPolicy::new(|| 1609459200))
+  }
+
+  #[test]
+  fn test_sign_secret() {
+    let mut document: VerifiableDocument = document();
+
+    LdSuite::new(JcsEd25519Signature2020)
+      .sign(&mut document, options(), &secret(SECRET))
+      .unwrap();
+
+    assert!(document.proof().unwrap().data().is_signature());
+    assert!(verify(&document).is_ok());
+  }
+
+  #[test]
+  fn test_sign_signer() {
+    let mut document: VerifiableDocument = document();
+
+    LdSuite::new(JcsEd25519Signature2020)
+      .sign_with(&mut document, options(), &LocalSigner::new(secret(SECRET)))
+      .unwrap();
+
+    assert!(verify(&document).is_ok());
+
+    // Signatures are deterministic, regardless of the signing path
+    let mut other: VerifiableDocument = self::document();
+
+    LdSuite::new(JcsEd25519Signature2020)
+      .sign(&mut other, options(), &SECRET)
+      .unwrap();
+
+    assert_eq!(document.proof(), other.proof());
+  }
+
+  #[test]
+  fn test_sign_async_signer() {
+    let mut document: VerifiableDocument = document();
+    let signer: RemoteSigner = RemoteSigner(LocalSigner::new(secret(SECRET)));
+    let suite: LdSuite<JcsEd25519Signature2020> = LdSuite::new(JcsEd25519Signature2020);
+
+    pollster::block_on(suite.sign_with_async(&mut document, options(), &signer)).unwrap();
+
+    assert!(verify(&document).is_ok());
+  }
+
+  #[test]
+  fn test_sign_signer_wrong_key() {
+    let mut document: VerifiableDocument = document();
+
+    let result: Result<()> = LdSuite::new(JcsEd25519Signature2020).sign_with(
+      &mut document,
+      options(),
+      &LocalSigner::new(secret([8; 32])),
+    );
+
+    assert!(result.is_err());
+
+    // The key must be of the same type as the method
+    let result: Result<()> = LdSuite::new(JcsEd25519Signature2020).sign_with(
+      &mut document,
+      options(),
+      &LocalSigner::new(SecretKey::from_bytes(MethodType::JcsEd25519Key2020, SECRET)),
+    );
+
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn test_verify_tampered() {
+    let mut document: VerifiableDocument = document();
+
+    LdSuite::new(JcsEd25519Signature2020)
+      .sign(&mut document, options(), &SECRET)
+      .unwrap();
+
+    document
+      .also_known_as_mut()
+      .push("https://example.com".parse().unwrap());
+
+    assert!(verify(&document).is_err());
+  }
+
+  #[test]
+  fn test_verify_batch() {
+    let suite: LdSuite<JcsEd25519Signature2020> = LdSuite::new(JcsEd25519Signature2020);
+    let policy: VerificationPolicy<fn() -> i64> = VerificationPolicy::new(|| 1609459200);
+
+    let mut documents: Vec<VerifiableDocument> = (0..4).map(|_| document()).collect();
+
+    for (index, document) in documents.iter_mut().enumerate() {
+      document.also_known_as_mut().push(
+        alloc::format!("https://example.com/{}", index)
+          .parse()
+          .unwrap(),
+      );
+
+      suite.sign(document, options(), &SECRET).unwrap();
+    }
+
+    let results: Vec<Result<()>> = BatchVerifier::new(&documents, &policy)
+      .verify(&suite)
+      .finish();
+
+    assert!(results.iter().all(Result::is_ok));
+
+    // Invalid signatures are reported individually
+    documents[2]
+      .also_known_as_mut()
+      .push("https://example.com".parse().unwrap());
+
+    let results: Vec<Result<()>> = BatchVerifier::new(&documents, &policy)
+      .verify(&suite)
+      .finish();
+
+    assert!(results[0].is_ok());
+    assert!(results[1].is_ok());
+    assert!(results[2].is_err());
+    assert!(results[3].is_ok());
+  }
+}
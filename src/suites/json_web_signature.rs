@@ -0,0 +1,423 @@
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld;
+use crate::jws::DecodedJws;
+use crate::jws::JwsAlgorithm;
+use crate::jws::JwsEncoder;
+use crate::jws::JwsHeader;
+use crate::lib::*;
+use crate::signature::Encode;
+use crate::signature::Sign;
+use crate::signature::Signature;
+use crate::signature::SignatureData;
+use crate::signature::SuiteName;
+use crate::signature::Verify;
+use crate::suites::ecdsa;
+use crate::utils::canonicalize;
+use crate::verification::MethodType;
+use crate::verification::MethodWrap;
+
+const ERR_MJWS: &str = "Missing JWS Signature Value";
+
+/// A signature suite signing with JWS algorithms, modelled on
+/// `JsonWebSignature2020`.
+///
+/// Messages are canonicalized with the JSON Canonicalization Scheme (RFC 8785)
+/// and signed with the configured `JwsAlgorithm`; signatures are detached JWS
+/// with an unencoded payload. Verification uses the algorithm of the JWS
+/// header.
+///
+/// Note: [`JsonWebSignature2020`] canonicalizes with URDNA2015, so proofs of
+/// this suite are not interoperable with it and use a distinct name.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct JcsJsonWebSignature2020 {
+  alg: JwsAlgorithm,
+}
+
+impl JcsJsonWebSignature2020 {
+  /// The name of the signature suite.
+  pub const NAME: &'static str = "JcsJsonWebSignature2020";
+
+  /// Creates a new `JcsJsonWebSignature2020` suite signing with `alg`.
+  pub const fn new(alg: JwsAlgorithm) -> Self {
+    Self { alg }
+  }
+
+  /// Returns the `JwsAlgorithm` used for signing.
+  pub const fn alg(&self) -> JwsAlgorithm {
+    self.alg
+  }
+
+  fn encoder(&self) -> Result<JwsEncoder> {
+    JwsEncoder::new(JwsHeader::new_unencoded(self.alg))
+  }
+}
+
+impl SuiteName for JcsJsonWebSignature2020 {
+  fn name(&self) -> String {
+    Self::NAME.to_string()
+  }
+}
+
+impl Encode for JcsJsonWebSignature2020 {
+  fn encode_input<T>(&self, data: &T) -> Result<Vec<u8>>
+  where
+    T: Serialize,
+  {
+    let input: Vec<u8> = canonicalize(data)?;
+
+    self.encoder().map(|encoder| encoder.signing_input(&input))
+  }
+
+  fn encode_signature(&self, signature: &[u8]) -> Result<SignatureData> {
+    self
+      .encoder()
+      .map(|encoder| SignatureData::Jws(encoder.encode_detached(signature)))
+  }
+}
+
+impl Sign for JcsJsonWebSignature2020 {
+  fn sign<T>(&self, data: &T, secret: &[u8]) -> Result<SignatureData>
+  where
+    T: Serialize,
+  {
+    let input: Vec<u8> = self.encode_input(data)?;
+    let signature: Vec<u8> = ecdsa::sign(self.alg, secret, &input)?;
+
+    self.encode_signature(&signature)
+  }
+}
+
+impl Verify for JcsJsonWebSignature2020 {
+  const METHODS: &'static [MethodType] = &[
+    MethodType::JsonWebKey2020,
+    MethodType::JwsVerificationKey2020,
+  ];
+
+  fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+  where
+    T: Serialize,
+  {
+    decode(signature)?.verify(Some(&canonicalize(data)?), public)
+  }
+
+  fn verify_method<M>(&self, signature: &Signature, method: &MethodWrap<'_, M>) -> Result<()>
+  where
+    M: Serialize,
+  {
+    decode(signature.data())?
+      .header()
+      .check_method(&signature.verification_method, method)
+  }
+}
+
+/// The `JsonWebSignature2020` signature suite.
+///
+/// Messages are JSON-LD documents canonicalized with URDNA2015; the SHA-256
+/// hashes of the canonical proof options and document are signed with the
+/// configured `JwsAlgorithm` as a detached JWS with an unencoded payload.
+/// Verification uses the algorithm of the JWS header.
+///
+/// Documents must declare an `@context` and may only reference the contexts
+/// embedded in this crate, such as `https://www.w3.org/ns/did/v1` and
+/// `https://w3id.org/security/suites/jws-2020/v1`. Terms that are not defined
+/// by the context are rejected instead of being left unsigned.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct JsonWebSignature2020 {
+  alg: JwsAlgorithm,
+}
+
+impl JsonWebSignature2020 {
+  /// The name of the signature suite.
+  pub const NAME: &'static str = "JsonWebSignature2020";
+
+  /// Creates a new `JsonWebSignature2020` suite signing with `alg`.
+  pub const fn new(alg: JwsAlgorithm) -> Self {
+    Self { alg }
+  }
+
+  /// Returns the `JwsAlgorithm` used for signing.
+  pub const fn alg(&self) -> JwsAlgorithm {
+    self.alg
+  }
+
+  fn encoder(&self) -> Result<JwsEncoder> {
+    JwsEncoder::new(JwsHeader::new_unencoded(self.alg))
+  }
+}
+
+impl SuiteName for JsonWebSignature2020 {
+  fn name(&self) -> String {
+    Self::NAME.to_string()
+  }
+}
+
+impl Encode for JsonWebSignature2020 {
+  fn encode_input<T>(&self, data: &T) -> Result<Vec<u8>>
+  where
+    T: Serialize,
+  {
+    let input: Vec<u8> = jsonld::signing_input(data)?;
+
+    self.encoder().map(|encoder| encoder.signing_input(&input))
+  }
+
+  fn encode_signature(&self, signature: &[u8]) -> Result<SignatureData> {
+    self
+      .encoder()
+      .map(|encoder| SignatureData::Jws(encoder.encode_detached(signature)))
+  }
+}
+
+impl Sign for JsonWebSignature2020 {
+  fn sign<T>(&self, data: &T, secret: &[u8]) -> Result<SignatureData>
+  where
+    T: Serialize,
+  {
+    let input: Vec<u8> = self.encode_input(data)?;
+    let signature: Vec<u8> = ecdsa::sign(self.alg, secret, &input)?;
+
+    self.encode_signature(&signature)
+  }
+}
+
+impl Verify for JsonWebSignature2020 {
+  const METHODS: &'static [MethodType] = &[
+    MethodType::JsonWebKey2020,
+    MethodType::JwsVerificationKey2020,
+  ];
+
+  fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+  where
+    T: Serialize,
+  {
+    decode(signature)?.verify(Some(&jsonld::signing_input(data)?), public)
+  }
+
+  fn verify_method<M>(&self, signature: &Signature, method: &MethodWrap<'_, M>) -> Result<()>
+  where
+    M: Serialize,
+  {
+    decode(signature.data())?
+      .header()
+      .check_method(&signature.verification_method, method)
+  }
+}
+
+fn decode(signature: &SignatureData) -> Result<DecodedJws<'_>> {
+  signature
+    .try_jws()
+    .ok_or_else(|| Error::message(ERR_MJWS))
+    .and_then(DecodedJws::decode_detached)
+}
+
+#[cfg(test)]
+mod tests {
+  use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+  use base64::Engine as _;
+  use did_url::DID;
+
+  use super::*;
+  use crate::document::Document;
+  use crate::document::DocumentBuilder;
+  use crate::jsonld::contexts;
+  use crate::signature::LdSuite;
+  use crate::signature::SecretKey;
+  use crate::signature::SignatureOptions;
+  use crate::signature::VerificationPolicy;
+  use crate::suites::LocalSigner;
+  use crate::utils::Object;
+  use crate::utils::Value;
+  use crate::verifiable::VerifiableDocument;
+  use crate::verification::MethodBuilder;
+  use crate::verification::MethodData;
+
+  const SECRET: [u8; 32] = [7; 32];
+
+  fn jwk(alg: JwsAlgorithm) -> Object {
+    let (crv, point) = match alg {
+      JwsAlgorithm::ES256 => {
+        let key = p256::ecdsa::SigningKey::from_slice(&SECRET).unwrap();
+        (
+          "P-256",
+          key
+            .verifying_key()
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec(),
+        )
+      }
+      JwsAlgorithm::ES256K | JwsAlgorithm::ES256KR => {
+        let key = k256::ecdsa::SigningKey::from_slice(&SECRET).unwrap();
+        (
+          "secp256k1",
+          key
+            .verifying_key()
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec(),
+        )
+      }
+    };
+
+    let mut jwk: Object = Object::new();
+
+    jwk.insert("kty".into(), Value::from("EC"));
+    jwk.insert("crv".into(), Value::from(crv));
+    jwk.insert(
+      "x".into(),
+      Value::from(URL_SAFE_NO_PAD.encode(&point[1..33])),
+    );
+    jwk.insert(
+      "y".into(),
+      Value::from(URL_SAFE_NO_PAD.encode(&point[33..])),
+    );
+    jwk
+  }
+
+  fn document(alg: JwsAlgorithm) -> VerifiableDocument {
+    let context: Value = Value::Array(alloc::vec![
+      Value::from(contexts::DID_V1),
+      Value::from(contexts::JWS_2020_V1),
+    ]);
+
+    let mut document: VerifiableDocument = jcs_document(alg);
+
+    document.properties_mut().insert("@context".into(), context);
+    document
+  }
+
+  fn jcs_document(alg: JwsAlgorithm) -> VerifiableDocument {
+    let document: Document = DocumentBuilder::default()
+      .id("did:example:123".parse().unwrap())
+      .verification_method(
+        MethodBuilder::default()
+          .id("did:example:123#key-1".parse().unwrap())
+          .controller("did:example:123".parse().unwrap())
+          .key_type(MethodType::JsonWebKey2020)
+          .key_data(MethodData::PublicKeyJwk(jwk(alg)))
+          .build()
+          .unwrap(),
+      )
+      .assertion_method("did:example:123#key-1".parse::<DID>().unwrap())
+      .build()
+      .unwrap();
+
+    VerifiableDocument::new(document)
+  }
+
+  fn options() -> SignatureOptions {
+    let mut options: SignatureOptions =
+      SignatureOptions::with_purpose("#key-1".into(), "assertionMethod".into());
+
+    options.created = Some("2021-01-01T00:00:00Z".parse().unwrap());
+    options
+  }
+
+  fn policy() -> VerificationPolicy<impl crate::signature::Clock> {
+    VerificationPolicy::new(|| 1609459200)
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    for alg in [JwsAlgorithm::ES256, JwsAlgorithm::ES256K] {
+      let suite: LdSuite<JcsJsonWebSignature2020> = LdSuite::new(JcsJsonWebSignature2020::new(alg));
+      let mut document: VerifiableDocument = jcs_document(alg);
+
+      suite.sign(&mut document, options(), &SECRET).unwrap();
+
+      let jws: &str = document.proof().unwrap().data().try_jws().unwrap();
+
+      assert!(jws.contains(".."));
+      assert!(suite.verify(&document, &policy()).is_ok());
+    }
+  }
+
+  #[test]
+  fn test_sign_signer() {
+    for alg in [JwsAlgorithm::ES256, JwsAlgorithm::ES256K] {
+      let suite: LdSuite<JcsJsonWebSignature2020> = LdSuite::new(JcsJsonWebSignature2020::new(alg));
+      let signer: LocalSigner =
+        LocalSigner::new(SecretKey::from_bytes(MethodType::JsonWebKey2020, SECRET));
+      let mut document: VerifiableDocument = jcs_document(alg);
+
+      suite.sign_with(&mut document, options(), &signer).unwrap();
+
+      assert!(suite.verify(&document, &policy()).is_ok());
+    }
+  }
+
+  #[test]
+  fn test_verify_wrong_curve() {
+    let suite: LdSuite<JcsJsonWebSignature2020> =
+      LdSuite::new(JcsJsonWebSignature2020::new(JwsAlgorithm::ES256));
+    let mut document: VerifiableDocument = jcs_document(JwsAlgorithm::ES256K);
+
+    suite.sign(&mut document, options(), &SECRET).unwrap();
+
+    assert!(suite.verify(&document, &policy()).is_err());
+  }
+
+  #[test]
+  fn test_sign_verify_ld() {
+    for alg in [JwsAlgorithm::ES256, JwsAlgorithm::ES256K] {
+      let suite: LdSuite<JsonWebSignature2020> = LdSuite::new(JsonWebSignature2020::new(alg));
+      let mut document: VerifiableDocument = document(alg);
+
+      suite.sign(&mut document, options(), &SECRET).unwrap();
+
+      let jws: &str = document.proof().unwrap().data().try_jws().unwrap();
+
+      assert!(jws.contains(".."));
+      assert!(suite.verify(&document, &policy()).is_ok());
+
+      let signer: LocalSigner =
+        LocalSigner::new(SecretKey::from_bytes(MethodType::JsonWebKey2020, SECRET));
+      let mut document: VerifiableDocument = self::document(alg);
+
+      suite.sign_with(&mut document, options(), &signer).unwrap();
+
+      assert!(suite.verify(&document, &policy()).is_ok());
+
+      document
+        .also_known_as_mut()
+        .push("https://example.com".parse().unwrap());
+
+      assert!(suite.verify(&document, &policy()).is_err());
+    }
+  }
+
+  #[test]
+  fn test_sign_ld_invalid() {
+    let suite: LdSuite<JsonWebSignature2020> =
+      LdSuite::new(JsonWebSignature2020::new(JwsAlgorithm::ES256));
+
+    // Documents without a JSON-LD context
+    let mut document: VerifiableDocument = jcs_document(JwsAlgorithm::ES256);
+
+    assert!(suite.sign(&mut document, options(), &SECRET).is_err());
+
+    // Contexts that are not embedded
+    let mut document: VerifiableDocument = jcs_document(JwsAlgorithm::ES256);
+
+    document.properties_mut().insert(
+      "@context".into(),
+      Value::from("https://example.com/context"),
+    );
+
+    assert!(suite.sign(&mut document, options(), &SECRET).is_err());
+  }
+
+  #[test]
+  fn test_verify_ld_wrong_curve() {
+    let suite: LdSuite<JsonWebSignature2020> =
+      LdSuite::new(JsonWebSignature2020::new(JwsAlgorithm::ES256));
+    let mut document: VerifiableDocument = document(JwsAlgorithm::ES256K);
+
+    suite.sign(&mut document, options(), &SECRET).unwrap();
+
+    assert!(suite.verify(&document, &policy()).is_err());
+  }
+}
@@ -0,0 +1,124 @@
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::signature::ld_suite::decode_key;
+use crate::signature::SecretKey;
+use crate::signature::Signer;
+use crate::verification::MethodType;
+use crate::verification::MethodWrap;
+
+const ERR_KM: &str = "Key Mismatch";
+
+/// A `Signer` using a private key held in memory.
+///
+/// The signing algorithm is selected by the `MethodType` of the key, which must
+/// match the type of the target method.
+#[derive(Clone, Debug)]
+pub struct LocalSigner {
+  secret: SecretKey,
+}
+
+impl LocalSigner {
+  /// Creates a new `LocalSigner` with the given private key.
+  pub const fn new(secret: SecretKey) -> Self {
+    Self { secret }
+  }
+
+  #[cfg(feature = "ed25519")]
+  fn sign_ed25519(&self, message: &[u8], public: &[u8]) -> Result<Vec<u8>> {
+    use crate::suites::ed25519;
+
+    if ed25519::public_key(self.secret.as_bytes())? != public {
+      return Err(Error::message(ERR_KM));
+    }
+
+    ed25519::sign(self.secret.as_bytes(), message)
+  }
+
+  #[cfg(feature = "ecdsa")]
+  fn sign_ecdsa(
+    &self,
+    message: &[u8],
+    public: &[u8],
+    alg: crate::jws::JwsAlgorithm,
+  ) -> Result<Vec<u8>> {
+    use crate::suites::ecdsa;
+
+    if !ecdsa::matches(alg, self.secret.as_bytes(), public)? {
+      return Err(Error::message(ERR_KM));
+    }
+
+    ecdsa::sign(alg, self.secret.as_bytes(), message)
+  }
+
+  #[cfg(feature = "pq")]
+  fn sign_ml_dsa(&self, message: &[u8], public: &[u8]) -> Result<Vec<u8>> {
+    use crate::suites::ml_dsa;
+
+    if ml_dsa::public_key(self.secret.as_bytes())? != public {
+      return Err(Error::message(ERR_KM));
+    }
+
+    ml_dsa::sign(self.secret.as_bytes(), message)
+  }
+
+  #[cfg(feature = "pq")]
+  fn sign_slh_dsa(&self, message: &[u8], public: &[u8]) -> Result<Vec<u8>> {
+    use crate::suites::slh_dsa;
+
+    if slh_dsa::public_key(self.secret.as_bytes())? != public {
+      return Err(Error::message(ERR_KM));
+    }
+
+    slh_dsa::sign(self.secret.as_bytes(), message)
+  }
+}
+
+impl<M> Signer<M> for LocalSigner {
+  fn sign(&self, message: &[u8], method: MethodWrap<'_, M>) -> Result<Vec<u8>> {
+    if self.secret.key_type() != method.key_type() {
+      return Err(Error::invalid_key(method.id()).with_source(Error::message(ERR_KM)));
+    }
+
+    let public: Vec<u8> = decode_key(&method)?;
+
+    let signature: Result<Vec<u8>> = match method.key_type() {
+      #[cfg(feature = "ed25519")]
+      MethodType::JcsEd25519Key2020 | MethodType::Ed25519VerificationKey2018 => {
+        self.sign_ed25519(message, &public)
+      }
+      #[cfg(feature = "ecdsa")]
+      MethodType::EcdsaSecp256k1VerificationKey2019 => {
+        self.sign_ecdsa(message, &public, crate::jws::JwsAlgorithm::ES256K)
+      }
+      #[cfg(feature = "ecdsa")]
+      MethodType::EcdsaSecp256k1RecoveryMethod2020 => {
+        self.sign_ecdsa(message, &public, crate::jws::JwsAlgorithm::ES256KR)
+      }
+      #[cfg(feature = "ecdsa")]
+      MethodType::JsonWebKey2020 | MethodType::JwsVerificationKey2020 => {
+        use crate::jws::JwsAlgorithm;
+        use crate::utils::Value;
+        use crate::verification::MethodData;
+
+        let alg: JwsAlgorithm = match method.key_data() {
+          MethodData::PublicKeyJwk(jwk) => match jwk.get("crv") {
+            Some(Value::String(crv)) if crv == "secp256k1" => JwsAlgorithm::ES256K,
+            Some(Value::String(crv)) if crv == "P-256" => JwsAlgorithm::ES256,
+            _ => return Err(Error::invalid_method_type(method.id(), method.key_type())),
+          },
+          _ => return Err(Error::invalid_method_type(method.id(), method.key_type())),
+        };
+
+        self.sign_ecdsa(message, &public, alg)
+      }
+      #[cfg(feature = "pq")]
+      MethodType::MlDsa65VerificationKey2024 => self.sign_ml_dsa(message, &public),
+      #[cfg(feature = "pq")]
+      MethodType::SlhDsaSha2128sVerificationKey2024 => self.sign_slh_dsa(message, &public),
+      _ => return Err(Error::invalid_method_type(method.id(), method.key_type())),
+    };
+
+    signature.map_err(|error| Error::invalid_key(method.id()).with_source(error))
+  }
+}
@@ -0,0 +1,369 @@
+use serde::Serialize;
+use serde_json::from_value;
+use serde_json::to_value;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::merkle::hash_leaf;
+use crate::merkle::KeyCollection;
+use crate::merkle::MerkleKey;
+use crate::merkle::MerkleProof;
+use crate::merkle::RevocationBitmap;
+use crate::signature::BatchItem;
+use crate::signature::Sign;
+use crate::signature::Signature;
+use crate::signature::SignatureData;
+use crate::signature::SuiteName;
+use crate::signature::Verify;
+use crate::suites::ed25519;
+use crate::utils::canonicalize;
+use crate::utils::Object;
+use crate::verification::MethodType;
+use crate::verification::MethodWrap;
+
+const ERR_MMK: &str = "Missing Merkle Key";
+const ERR_IKI: &str = "Invalid Key Collection Index";
+const ERR_KM: &str = "Key Mismatch";
+const ERR_MSV: &str = "Missing Signature Value";
+const ERR_ISV: &str = "Invalid Signature Value";
+const ERR_IMP: &str = "Invalid Merkle Proof";
+const ERR_RMK: &str = "Revoked Merkle Key";
+
+/// A public key, signing input, and signature to be checked in a batch.
+type Decoded = Result<(Vec<u8>, Vec<u8>, Vec<u8>)>;
+
+/// An implementation of the `MerkleKeySignature2021` signature suite.
+///
+/// Messages are canonicalized with the JSON Canonicalization Scheme and signed
+/// with an Ed25519 key of a `KeyCollection`. The signature value carries the
+/// inclusion proof and public key of the signing key, which are verified
+/// against the collection root of a `MerkleKeyCollection2021` method.
+///
+/// Keys revoked in the `RevocationBitmap` of the method are rejected.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MerkleKeySignature2021 {
+  key: Option<(Vec<u8>, MerkleProof)>,
+}
+
+impl MerkleKeySignature2021 {
+  /// The name of the signature suite.
+  pub const NAME: &'static str = "MerkleKeySignature2021";
+
+  /// Creates a new `MerkleKeySignature2021` suite for verification.
+  pub const fn new() -> Self {
+    Self { key: None }
+  }
+
+  /// Creates a new `MerkleKeySignature2021` suite signing with the key at
+  /// `index` of the `collection`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `index` is out of bounds.
+  pub fn with_key(collection: &KeyCollection, index: usize) -> Result<Self> {
+    let public: &[u8] = collection
+      .public(index)
+      .ok_or_else(|| Error::message(ERR_IKI))?;
+    let proof: MerkleProof = collection
+      .proof(index)
+      .ok_or_else(|| Error::message(ERR_IKI))?;
+
+    Ok(Self {
+      key: Some((public.to_vec(), proof)),
+    })
+  }
+
+  // Signature values are encoded as `<proof>.<public key>.<signature>`, each
+  // base58-encoded.
+  fn encode(proof: &MerkleProof, public: &[u8], signature: &[u8]) -> SignatureData {
+    let mut output: String = bs58::encode(proof.to_bytes()).into_string();
+
+    output.push('.');
+    output.push_str(&bs58::encode(public).into_string());
+    output.push('.');
+    output.push_str(&bs58::encode(signature).into_string());
+
+    SignatureData::Signature(output)
+  }
+
+  fn decode(signature: &SignatureData) -> Result<(MerkleProof, Vec<u8>, Vec<u8>)> {
+    let value: &str = signature
+      .try_signature()
+      .ok_or_else(|| Error::message(ERR_MSV))?;
+
+    let decode = |data: &str| -> Result<Vec<u8>> {
+      bs58::decode(data)
+        .into_vec()
+        .map_err(|_| Error::message(ERR_ISV))
+    };
+
+    let mut split: core::str::Split<'_, char> = value.split('.');
+
+    match (split.next(), split.next(), split.next(), split.next()) {
+      (Some(proof), Some(public), Some(signature), None) => Ok((
+        MerkleProof::from_bytes(&decode(proof)?)?,
+        decode(public)?,
+        decode(signature)?,
+      )),
+      _ => Err(Error::message(ERR_ISV)),
+    }
+  }
+}
+
+impl SuiteName for MerkleKeySignature2021 {
+  fn name(&self) -> String {
+    Self::NAME.to_string()
+  }
+}
+
+impl Sign for MerkleKeySignature2021 {
+  fn sign<T>(&self, data: &T, secret: &[u8]) -> Result<SignatureData>
+  where
+    T: Serialize,
+  {
+    let (public, proof): &(Vec<u8>, MerkleProof) =
+      self.key.as_ref().ok_or_else(|| Error::message(ERR_MMK))?;
+
+    if ed25519::public_key(secret)? != *public {
+      return Err(Error::message(ERR_KM));
+    }
+
+    let signature: Vec<u8> = ed25519::sign(secret, &canonicalize(data)?)?;
+
+    Ok(Self::encode(proof, public, &signature))
+  }
+}
+
+impl Verify for MerkleKeySignature2021 {
+  const METHODS: &'static [MethodType] = &[MethodType::MerkleKeyCollection2021];
+
+  fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+  where
+    T: Serialize,
+  {
+    let (proof, key, signature): (MerkleProof, Vec<u8>, Vec<u8>) = Self::decode(signature)?;
+
+    if proof.root(hash_leaf(&key)) != MerkleKey::decode(public)? {
+      return Err(Error::message(ERR_IMP));
+    }
+
+    ed25519::verify(&key, &canonicalize(data)?, &signature)
+  }
+
+  fn verify_batch<T>(&self, items: &[BatchItem<'_, T>]) -> Vec<Result<()>>
+  where
+    T: Serialize,
+  {
+    let decoded: Vec<Decoded> = items
+      .iter()
+      .map(|item| {
+        let (proof, key, signature): (MerkleProof, Vec<u8>, Vec<u8>) =
+          Self::decode(item.signature)?;
+
+        if proof.root(hash_leaf(&key)) != MerkleKey::decode(item.public)? {
+          return Err(Error::message(ERR_IMP));
+        }
+
+        Ok((key, canonicalize(item.data)?, signature))
+      })
+      .collect();
+
+    let batch: Vec<(&[u8], &[u8], &[u8])> = decoded
+      .iter()
+      .flatten()
+      .map(|(key, message, signature)| (&key[..], &message[..], &signature[..]))
+      .collect();
+
+    let mut checked = ed25519::verify_batch(&batch).into_iter();
+
+    decoded
+      .into_iter()
+      .map(|item| item.and_then(|_| checked.next().expect("one result per item")))
+      .collect()
+  }
+
+  fn verify_method<M>(&self, signature: &Signature, method: &MethodWrap<'_, M>) -> Result<()>
+  where
+    M: Serialize,
+  {
+    let (proof, _, _): (MerkleProof, Vec<u8>, Vec<u8>) = Self::decode(signature.data())?;
+
+    // Properties that do not serialize to an object cannot hold a bitmap
+    let revocation: RevocationBitmap =
+      match to_value(method.properties()).and_then(from_value::<Object>) {
+        Ok(properties) => RevocationBitmap::from_properties(&properties)?,
+        Err(_) => RevocationBitmap::new(),
+      };
+
+    if revocation.is_revoked(proof.index()) {
+      return Err(Error::invalid_key(method.id()).with_source(Error::message(ERR_RMK)));
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use did_url::DID;
+
+  use super::*;
+  use crate::document::Document;
+  use crate::document::DocumentBuilder;
+  use crate::signature::BatchVerifier;
+  use crate::signature::LdSuite;
+  use crate::signature::SignatureOptions;
+  use crate::signature::VerificationPolicy;
+  use crate::verifiable::VerifiableDocument;
+  use crate::verification::Method;
+
+  fn secret(index: u8) -> [u8; 32] {
+    [index; 32]
+  }
+
+  fn collection() -> KeyCollection {
+    KeyCollection::new(
+      (0..4)
+        .map(|index| ed25519::public_key(&secret(index)).unwrap())
+        .collect(),
+    )
+    .unwrap()
+  }
+
+  fn document(revocation: RevocationBitmap) -> VerifiableDocument {
+    let mut properties: Object = Object::new();
+
+    revocation.to_properties(&mut properties);
+
+    let document: Document = DocumentBuilder::default()
+      .id("did:example:123".parse().unwrap())
+      .verification_method(
+        Method::builder(properties)
+          .id("did:example:123#keys".parse().unwrap())
+          .controller("did:example:123".parse().unwrap())
+          .key_type(MethodType::MerkleKeyCollection2021)
+          .key_data(collection().to_method_data())
+          .build()
+          .unwrap(),
+      )
+      .assertion_method("did:example:123#keys".parse::<DID>().unwrap())
+      .build()
+      .unwrap();
+
+    VerifiableDocument::new(document)
+  }
+
+  fn options() -> SignatureOptions {
+    let mut options: SignatureOptions =
+      SignatureOptions::with_purpose("#keys".into(), "assertionMethod".into());
+
+    options.created = Some("2021-01-01T00:00:00Z".parse().unwrap());
+    options
+  }
+
+  fn sign(document: &mut VerifiableDocument, index: usize, secret: [u8; 32]) -> Result<()> {
+    let suite: MerkleKeySignature2021 = MerkleKeySignature2021::with_key(&collection(), index)?;
+
+    LdSuite::new(suite).sign(document, options(), &secret)
+  }
+
+  fn verify(document: &VerifiableDocument) -> Result<()> {
+    LdSuite::new(MerkleKeySignature2021::new())
+      .verify(document, &VerificationPolicy::new(|| 1609459200))
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    for index in 0..4 {
+      let mut document: VerifiableDocument = document(RevocationBitmap::new());
+
+      sign(&mut document, index, secret(index as u8)).unwrap();
+
+      assert!(verify(&document).is_ok());
+
+      document
+        .also_known_as_mut()
+        .push("https://example.com".parse().unwrap());
+
+      assert!(verify(&document).is_err());
+    }
+  }
+
+  #[test]
+  fn test_sign_invalid() {
+    let mut document: VerifiableDocument = document(RevocationBitmap::new());
+
+    assert!(sign(&mut document, 4, secret(0)).is_err());
+    assert!(sign(&mut document, 1, secret(0)).is_err());
+    assert!(LdSuite::new(MerkleKeySignature2021::new())
+      .sign(&mut document, options(), &secret(0))
+      .is_err());
+  }
+
+  #[test]
+  fn test_verify_foreign_key() {
+    let other: KeyCollection =
+      KeyCollection::new(alloc::vec![ed25519::public_key(&secret(9)).unwrap()]).unwrap();
+    let suite: MerkleKeySignature2021 = MerkleKeySignature2021::with_key(&other, 0).unwrap();
+    let mut document: VerifiableDocument = document(RevocationBitmap::new());
+
+    LdSuite::new(suite)
+      .sign(&mut document, options(), &secret(9))
+      .unwrap();
+
+    assert!(verify(&document).is_err());
+  }
+
+  #[test]
+  fn test_verify_revoked() {
+    let mut revocation: RevocationBitmap = RevocationBitmap::new();
+
+    revocation.revoke(2);
+
+    let mut document: VerifiableDocument = document(revocation);
+
+    sign(&mut document, 1, secret(1)).unwrap();
+    assert!(verify(&document).is_ok());
+
+    sign(&mut document, 2, secret(2)).unwrap();
+    assert!(verify(&document).is_err());
+  }
+
+  #[test]
+  fn test_verify_batch() {
+    let mut documents: Vec<VerifiableDocument> = (0..4)
+      .map(|index| {
+        let mut document: VerifiableDocument = document(RevocationBitmap::new());
+
+        sign(&mut document, index, secret(index as u8)).unwrap();
+        document
+      })
+      .collect();
+
+    documents[1]
+      .also_known_as_mut()
+      .push("https://example.com".parse().unwrap());
+
+    let other: KeyCollection =
+      KeyCollection::new(alloc::vec![ed25519::public_key(&secret(9)).unwrap()]).unwrap();
+
+    LdSuite::new(MerkleKeySignature2021::with_key(&other, 0).unwrap())
+      .sign(&mut documents[3], options(), &secret(9))
+      .unwrap();
+
+    let policy: VerificationPolicy<_> = VerificationPolicy::new(|| 1609459200);
+    let results: Vec<Result<()>> = BatchVerifier::new(&documents, &policy)
+      .verify(&LdSuite::new(MerkleKeySignature2021::new()))
+      .finish();
+
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+    assert!(results[3].is_err());
+
+    for (document, result) in documents.iter().zip(results) {
+      assert_eq!(verify(document).is_ok(), result.is_ok());
+    }
+  }
+}
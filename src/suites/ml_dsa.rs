@@ -0,0 +1,87 @@
+// ML-DSA-65 (FIPS 204) with deterministic signing and an empty context string.
+//
+// Secret keys are 32-byte seeds (`ξ`); the expanded private key is derived on
+// demand and never stored.
+use core::convert::TryFrom;
+use ml_dsa::EncodedSignature;
+use ml_dsa::EncodedVerifyingKey;
+use ml_dsa::MlDsa65;
+use ml_dsa::Seed;
+use ml_dsa::Signature;
+use ml_dsa::SigningKey;
+use ml_dsa::VerifyingKey;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+
+const ERR_ISK: &str = "Invalid ML-DSA Secret Key";
+const ERR_IPK: &str = "Invalid ML-DSA Public Key";
+const ERR_IS: &str = "Invalid ML-DSA Signature";
+
+fn signing_key(secret: &[u8]) -> Result<SigningKey<MlDsa65>> {
+  Seed::try_from(secret)
+    .map(|seed| SigningKey::from_seed(&seed))
+    .map_err(|_| Error::message(ERR_ISK))
+}
+
+pub(crate) fn public_key(secret: &[u8]) -> Result<Vec<u8>> {
+  signing_key(secret).map(|key| key.expanded_key().verifying_key().encode().to_vec())
+}
+
+pub(crate) fn sign(secret: &[u8], message: &[u8]) -> Result<Vec<u8>> {
+  signing_key(secret)?
+    .expanded_key()
+    .sign_deterministic(message, &[])
+    .map(|signature| signature.encode().to_vec())
+    .map_err(|_| Error::message(ERR_ISK))
+}
+
+pub(crate) fn verify(public: &[u8], message: &[u8], signature: &[u8]) -> Result<()> {
+  let public: VerifyingKey<MlDsa65> = EncodedVerifyingKey::<MlDsa65>::try_from(public)
+    .map(|public| VerifyingKey::decode(&public))
+    .map_err(|_| Error::message(ERR_IPK))?;
+
+  let signature: Signature<MlDsa65> = EncodedSignature::<MlDsa65>::try_from(signature)
+    .ok()
+    .and_then(|signature| Signature::decode(&signature))
+    .ok_or_else(|| Error::message(ERR_IS))?;
+
+  if public.verify_with_context(message, &[], &signature) {
+    Ok(())
+  } else {
+    Err(Error::message(ERR_IS))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SECRET: [u8; 32] = [1; 32];
+
+  #[test]
+  fn test_sign_verify() {
+    let public: Vec<u8> = public_key(&SECRET).unwrap();
+    let signature: Vec<u8> = sign(&SECRET, b"message").unwrap();
+
+    assert_eq!(public.len(), 1952);
+    assert_eq!(signature.len(), 3309);
+
+    // Signing is deterministic
+    assert_eq!(sign(&SECRET, b"message").unwrap(), signature);
+
+    assert!(verify(&public, b"message", &signature).is_ok());
+    assert!(verify(&public, b"massage", &signature).is_err());
+    assert!(verify(&public_key(&[2; 32]).unwrap(), b"message", &signature).is_err());
+    assert!(verify(&public, b"message", &signature[1..]).is_err());
+    assert!(verify(&public[1..], b"message", &signature).is_err());
+    assert!(sign(&SECRET[1..], b"message").is_err());
+
+    let mut invalid: Vec<u8> = signature;
+
+    invalid[0] ^= 1;
+
+    assert!(verify(&public, b"message", &invalid).is_err());
+  }
+}
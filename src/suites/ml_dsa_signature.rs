@@ -0,0 +1,199 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::signature::Encode;
+use crate::signature::Sign;
+use crate::signature::SignatureData;
+use crate::signature::SuiteName;
+use crate::signature::Verify;
+use crate::suites::ml_dsa;
+use crate::utils::canonicalize;
+use crate::verification::MethodType;
+
+const ERR_MPV: &str = "Missing Proof Value";
+const ERR_IPV: &str = "Invalid Proof Value";
+
+/// An implementation of the `MlDsa65Signature2024` signature suite.
+///
+/// Messages are canonicalized with the JSON Canonicalization Scheme and signed
+/// with deterministic ML-DSA-65 (FIPS 204); signatures are base64url-encoded
+/// `proofValue`s.
+///
+/// Secret keys are 32-byte ML-DSA seeds.
+///
+/// Experimental: this suite is only available with the `pq` feature, which
+/// is not covered by semver guarantees.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MlDsa65Signature2024;
+
+impl MlDsa65Signature2024 {
+  /// The name of the signature suite.
+  pub const NAME: &'static str = "MlDsa65Signature2024";
+
+  /// The multicodec identifier of ML-DSA-65 public keys (`mldsa-65-pub`).
+  pub const CODEC: u64 = 0x1211;
+
+  /// Returns the 1952-byte public key of a 32-byte `secret` seed.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the secret key is invalid.
+  pub fn public_key(secret: &[u8]) -> Result<Vec<u8>> {
+    ml_dsa::public_key(secret)
+  }
+}
+
+impl SuiteName for MlDsa65Signature2024 {
+  fn name(&self) -> String {
+    Self::NAME.to_string()
+  }
+}
+
+impl Encode for MlDsa65Signature2024 {
+  fn encode_input<T>(&self, data: &T) -> Result<Vec<u8>>
+  where
+    T: Serialize,
+  {
+    canonicalize(data)
+  }
+
+  fn encode_signature(&self, signature: &[u8]) -> Result<SignatureData> {
+    Ok(SignatureData::Proof(URL_SAFE_NO_PAD.encode(signature)))
+  }
+}
+
+impl Sign for MlDsa65Signature2024 {
+  fn sign<T>(&self, data: &T, secret: &[u8]) -> Result<SignatureData>
+  where
+    T: Serialize,
+  {
+    let signature: Vec<u8> = ml_dsa::sign(secret, &self.encode_input(data)?)?;
+
+    self.encode_signature(&signature)
+  }
+}
+
+impl Verify for MlDsa65Signature2024 {
+  const METHODS: &'static [MethodType] = &[MethodType::MlDsa65VerificationKey2024];
+
+  fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+  where
+    T: Serialize,
+  {
+    let signature: Vec<u8> = signature
+      .try_proof()
+      .ok_or_else(|| Error::message(ERR_MPV))
+      .and_then(|value| {
+        URL_SAFE_NO_PAD
+          .decode(value)
+          .map_err(|_| Error::message(ERR_IPV))
+      })?;
+
+    ml_dsa::verify(public, &self.encode_input(data)?, &signature)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use did_url::DID;
+
+  use super::*;
+  use crate::document::Document;
+  use crate::document::DocumentBuilder;
+  use crate::signature::LdSuite;
+  use crate::signature::SecretKey;
+  use crate::signature::SignatureOptions;
+  use crate::signature::VerificationPolicy;
+  use crate::suites::LocalSigner;
+  use crate::verifiable::VerifiableDocument;
+  use crate::verification::MethodBuilder;
+  use crate::verification::MethodData;
+
+  const SECRET: [u8; 32] = [7; 32];
+
+  fn document(key_type: MethodType) -> VerifiableDocument {
+    let public: Vec<u8> = MlDsa65Signature2024::public_key(&SECRET).unwrap();
+
+    let document: Document = DocumentBuilder::default()
+      .id("did:example:123".parse().unwrap())
+      .verification_method(
+        MethodBuilder::default()
+          .id("did:example:123#key-1".parse().unwrap())
+          .controller("did:example:123".parse().unwrap())
+          .key_type(key_type)
+          .key_data(MethodData::new_multibase(
+            MlDsa65Signature2024::CODEC,
+            public,
+          ))
+          .build()
+          .unwrap(),
+      )
+      .assertion_method("did:example:123#key-1".parse::<DID>().unwrap())
+      .build()
+      .unwrap();
+
+    VerifiableDocument::new(document)
+  }
+
+  fn options() -> SignatureOptions {
+    let mut options: SignatureOptions =
+      SignatureOptions::with_purpose("#key-1".into(), "assertionMethod".into());
+
+    options.created = Some("2021-01-01T00:00:00Z".parse().unwrap());
+    options
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    let suite: LdSuite<MlDsa65Signature2024> = LdSuite::new(MlDsa65Signature2024);
+    let policy = VerificationPolicy::new(|| 1609459200);
+    let mut document: VerifiableDocument = document(MethodType::MlDsa65VerificationKey2024);
+
+    suite.sign(&mut document, options(), &SECRET).unwrap();
+
+    assert!(suite.verify(&document, &policy).is_ok());
+
+    document
+      .also_known_as_mut()
+      .push("https://example.com".parse().unwrap());
+
+    assert!(suite.verify(&document, &policy).is_err());
+  }
+
+  #[test]
+  fn test_sign_signer() {
+    let suite: LdSuite<MlDsa65Signature2024> = LdSuite::new(MlDsa65Signature2024);
+    let policy = VerificationPolicy::new(|| 1609459200);
+    let secret: SecretKey = SecretKey::from_bytes(MethodType::MlDsa65VerificationKey2024, SECRET);
+    let mut document: VerifiableDocument = document(MethodType::MlDsa65VerificationKey2024);
+
+    suite
+      .sign_with(&mut document, options(), &LocalSigner::new(secret))
+      .unwrap();
+
+    assert!(suite.verify(&document, &policy).is_ok());
+
+    let secret: SecretKey = SecretKey::from_bytes(MethodType::MlDsa65VerificationKey2024, [8; 32]);
+    let mut document: VerifiableDocument = self::document(MethodType::MlDsa65VerificationKey2024);
+
+    assert!(suite
+      .sign_with(&mut document, options(), &LocalSigner::new(secret))
+      .is_err());
+  }
+
+  #[test]
+  fn test_unsupported_method() {
+    let suite: LdSuite<MlDsa65Signature2024> = LdSuite::new(MlDsa65Signature2024);
+    let policy = VerificationPolicy::new(|| 1609459200);
+    let mut document: VerifiableDocument = document(MethodType::Ed25519VerificationKey2018);
+
+    suite.sign(&mut document, options(), &SECRET).unwrap();
+
+    // Only `MlDsa65VerificationKey2024` methods are accepted
+    assert!(suite.verify(&document, &policy).is_err());
+  }
+}
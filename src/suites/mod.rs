@@ -0,0 +1,51 @@
+#[cfg(feature = "bbs")]
+mod bbs;
+#[cfg(feature = "bbs")]
+mod bbs_bls_signature;
+#[cfg(feature = "bbs")]
+mod bbs_bls_signature_proof;
+#[cfg(feature = "ecdsa")]
+pub(crate) mod ecdsa;
+#[cfg(feature = "ecdsa")]
+mod ecdsa_secp256k1;
+#[cfg(feature = "ecdsa")]
+mod ecdsa_secp256k1_recovery;
+#[cfg(feature = "ed25519")]
+mod ed25519;
+#[cfg(feature = "ed25519")]
+mod jcs_ed25519;
+#[cfg(feature = "ecdsa")]
+mod json_web_signature;
+#[cfg(any(feature = "ed25519", feature = "ecdsa", feature = "pq"))]
+mod local_signer;
+#[cfg(feature = "merkle")]
+mod merkle_key_signature;
+#[cfg(feature = "pq")]
+mod ml_dsa;
+#[cfg(feature = "pq")]
+mod ml_dsa_signature;
+#[cfg(feature = "pq")]
+mod slh_dsa;
+#[cfg(feature = "pq")]
+mod slh_dsa_signature;
+
+#[cfg(feature = "bbs")]
+pub use self::bbs_bls_signature::*;
+#[cfg(feature = "bbs")]
+pub use self::bbs_bls_signature_proof::*;
+#[cfg(feature = "ecdsa")]
+pub use self::ecdsa_secp256k1::*;
+#[cfg(feature = "ecdsa")]
+pub use self::ecdsa_secp256k1_recovery::*;
+#[cfg(feature = "ed25519")]
+pub use self::jcs_ed25519::*;
+#[cfg(feature = "ecdsa")]
+pub use self::json_web_signature::*;
+#[cfg(any(feature = "ed25519", feature = "ecdsa", feature = "pq"))]
+pub use self::local_signer::*;
+#[cfg(feature = "merkle")]
+pub use self::merkle_key_signature::*;
+#[cfg(feature = "pq")]
+pub use self::ml_dsa_signature::*;
+#[cfg(feature = "pq")]
+pub use self::slh_dsa_signature::*;
@@ -0,0 +1,101 @@
+// SLH-DSA-SHA2-128s (FIPS 205) with deterministic signing and an empty
+// context string.
+//
+// Secret keys are the 64-byte `SK.seed || SK.prf || PK.seed || PK.root`
+// encoding; public keys are the trailing `PK.seed || PK.root`.
+use core::convert::TryFrom;
+use slh_dsa::signature::Signer as _;
+use slh_dsa::signature::Verifier as _;
+use slh_dsa::Sha2_128s;
+use slh_dsa::Signature;
+use slh_dsa::SigningKey;
+use slh_dsa::VerifyingKey;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+
+const ERR_ISK: &str = "Invalid SLH-DSA Secret Key";
+const ERR_IPK: &str = "Invalid SLH-DSA Public Key";
+const ERR_IS: &str = "Invalid SLH-DSA Signature";
+
+const N: usize = 16;
+
+pub(crate) const SEED_LEN: usize = 3 * N;
+
+fn signing_key(secret: &[u8]) -> Result<SigningKey<Sha2_128s>> {
+  SigningKey::try_from(secret).map_err(|_| Error::message(ERR_ISK))
+}
+
+/// Returns the secret key of `SK.seed || SK.prf || PK.seed`.
+pub(crate) fn secret_key(seed: &[u8]) -> Result<Vec<u8>> {
+  if seed.len() != SEED_LEN {
+    return Err(Error::message(ERR_ISK));
+  }
+
+  let key: SigningKey<Sha2_128s> =
+    SigningKey::slh_keygen_internal(&seed[..N], &seed[N..2 * N], &seed[2 * N..]);
+
+  Ok(key.to_bytes().to_vec())
+}
+
+pub(crate) fn public_key(secret: &[u8]) -> Result<Vec<u8>> {
+  signing_key(secret).map(|key| key.as_ref().to_bytes().to_vec())
+}
+
+pub(crate) fn sign(secret: &[u8], message: &[u8]) -> Result<Vec<u8>> {
+  signing_key(secret)?
+    .try_sign(message)
+    .map(|signature: Signature<Sha2_128s>| signature.to_bytes().to_vec())
+    .map_err(|_| Error::message(ERR_ISK))
+}
+
+pub(crate) fn verify(public: &[u8], message: &[u8], signature: &[u8]) -> Result<()> {
+  let public: VerifyingKey<Sha2_128s> =
+    VerifyingKey::try_from(public).map_err(|_| Error::message(ERR_IPK))?;
+
+  let signature: Signature<Sha2_128s> =
+    Signature::try_from(signature).map_err(|_| Error::message(ERR_IS))?;
+
+  public
+    .verify(message, &signature)
+    .map_err(|_| Error::message(ERR_IS))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_sign_verify() {
+    let seed: Vec<u8> = (0..SEED_LEN as u8).collect();
+    let secret: Vec<u8> = secret_key(&seed).unwrap();
+    let public: Vec<u8> = public_key(&secret).unwrap();
+    let signature: Vec<u8> = sign(&secret, b"message").unwrap();
+
+    assert_eq!(secret.len(), 4 * N);
+    assert_eq!(public, &secret[2 * N..]);
+    assert_eq!(signature.len(), 7856);
+
+    // Signing is deterministic
+    assert_eq!(sign(&secret, b"message").unwrap(), signature);
+
+    assert!(verify(&public, b"message", &signature).is_ok());
+    assert!(verify(&public, b"massage", &signature).is_err());
+    assert!(verify(&public, b"message", &signature[1..]).is_err());
+    assert!(verify(&public[1..], b"message", &signature).is_err());
+
+    let mut invalid: Vec<u8> = signature;
+
+    invalid[7855] ^= 1;
+
+    assert!(verify(&public, b"message", &invalid).is_err());
+  }
+
+  #[test]
+  fn test_invalid_keys() {
+    assert!(secret_key(&[0; SEED_LEN - 1]).is_err());
+    assert!(public_key(&[0; 4 * N + 1]).is_err());
+    assert!(sign(&[0; SEED_LEN], b"message").is_err());
+  }
+}
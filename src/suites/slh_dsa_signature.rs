@@ -0,0 +1,201 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::signature::Encode;
+use crate::signature::Sign;
+use crate::signature::SignatureData;
+use crate::signature::SuiteName;
+use crate::signature::Verify;
+use crate::suites::slh_dsa;
+use crate::utils::canonicalize;
+use crate::verification::MethodType;
+
+const ERR_MPV: &str = "Missing Proof Value";
+const ERR_IPV: &str = "Invalid Proof Value";
+
+/// An implementation of the `SlhDsaSha2128sSignature2024` signature suite.
+///
+/// Messages are canonicalized with the JSON Canonicalization Scheme and signed
+/// with deterministic SLH-DSA-SHA2-128s (FIPS 205); signatures are
+/// base64url-encoded `proofValue`s.
+///
+/// Secret keys are 64-byte `SK.seed || SK.prf || PK.seed || PK.root` values;
+/// see `SlhDsaSha2128sSignature2024::secret_key`.
+///
+/// Experimental: this suite is only available with the `pq` feature, which
+/// is not covered by semver guarantees.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SlhDsaSha2128sSignature2024;
+
+impl SlhDsaSha2128sSignature2024 {
+  /// The name of the signature suite.
+  pub const NAME: &'static str = "SlhDsaSha2128sSignature2024";
+
+  /// The multicodec identifier of SLH-DSA-SHA2-128s public keys
+  /// (`slhdsa-sha2-128s-pub`).
+  pub const CODEC: u64 = 0x1220;
+
+  /// Returns the 64-byte secret key of a 48-byte `SK.seed || SK.prf || PK.seed`
+  /// seed.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the seed is invalid.
+  pub fn secret_key(seed: &[u8]) -> Result<Vec<u8>> {
+    slh_dsa::secret_key(seed)
+  }
+
+  /// Returns the 32-byte public key of a 64-byte `secret` key.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the secret key is invalid.
+  pub fn public_key(secret: &[u8]) -> Result<Vec<u8>> {
+    slh_dsa::public_key(secret)
+  }
+}
+
+impl SuiteName for SlhDsaSha2128sSignature2024 {
+  fn name(&self) -> String {
+    Self::NAME.to_string()
+  }
+}
+
+impl Encode for SlhDsaSha2128sSignature2024 {
+  fn encode_input<T>(&self, data: &T) -> Result<Vec<u8>>
+  where
+    T: Serialize,
+  {
+    canonicalize(data)
+  }
+
+  fn encode_signature(&self, signature: &[u8]) -> Result<SignatureData> {
+    Ok(SignatureData::Proof(URL_SAFE_NO_PAD.encode(signature)))
+  }
+}
+
+impl Sign for SlhDsaSha2128sSignature2024 {
+  fn sign<T>(&self, data: &T, secret: &[u8]) -> Result<SignatureData>
+  where
+    T: Serialize,
+  {
+    let signature: Vec<u8> = slh_dsa::sign(secret, &self.encode_input(data)?)?;
+
+    self.encode_signature(&signature)
+  }
+}
+
+impl Verify for SlhDsaSha2128sSignature2024 {
+  const METHODS: &'static [MethodType] = &[MethodType::SlhDsaSha2128sVerificationKey2024];
+
+  fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+  where
+    T: Serialize,
+  {
+    let signature: Vec<u8> = signature
+      .try_proof()
+      .ok_or_else(|| Error::message(ERR_MPV))
+      .and_then(|value| {
+        URL_SAFE_NO_PAD
+          .decode(value)
+          .map_err(|_| Error::message(ERR_IPV))
+      })?;
+
+    slh_dsa::verify(public, &self.encode_input(data)?, &signature)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use did_url::DID;
+
+  use super::*;
+  use crate::document::Document;
+  use crate::document::DocumentBuilder;
+  use crate::signature::LdSuite;
+  use crate::signature::SecretKey;
+  use crate::signature::SignatureOptions;
+  use crate::signature::VerificationPolicy;
+  use crate::suites::LocalSigner;
+  use crate::verifiable::VerifiableDocument;
+  use crate::verification::MethodBuilder;
+  use crate::verification::MethodData;
+
+  fn secret(seed: u8) -> Vec<u8> {
+    SlhDsaSha2128sSignature2024::secret_key(&[seed; 48]).unwrap()
+  }
+
+  fn document(secret: &[u8]) -> VerifiableDocument {
+    let public: Vec<u8> = SlhDsaSha2128sSignature2024::public_key(secret).unwrap();
+
+    let document: Document = DocumentBuilder::default()
+      .id("did:example:123".parse().unwrap())
+      .verification_method(
+        MethodBuilder::default()
+          .id("did:example:123#key-1".parse().unwrap())
+          .controller("did:example:123".parse().unwrap())
+          .key_type(MethodType::SlhDsaSha2128sVerificationKey2024)
+          .key_data(MethodData::new_multibase(
+            SlhDsaSha2128sSignature2024::CODEC,
+            public,
+          ))
+          .build()
+          .unwrap(),
+      )
+      .assertion_method("did:example:123#key-1".parse::<DID>().unwrap())
+      .build()
+      .unwrap();
+
+    VerifiableDocument::new(document)
+  }
+
+  fn options() -> SignatureOptions {
+    let mut options: SignatureOptions =
+      SignatureOptions::with_purpose("#key-1".into(), "assertionMethod".into());
+
+    options.created = Some("2021-01-01T00:00:00Z".parse().unwrap());
+    options
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    let suite: LdSuite<SlhDsaSha2128sSignature2024> = LdSuite::new(SlhDsaSha2128sSignature2024);
+    let policy = VerificationPolicy::new(|| 1609459200);
+    let secret: Vec<u8> = secret(7);
+    let mut document: VerifiableDocument = document(&secret);
+
+    suite.sign(&mut document, options(), &secret).unwrap();
+
+    assert!(suite.verify(&document, &policy).is_ok());
+
+    document
+      .also_known_as_mut()
+      .push("https://example.com".parse().unwrap());
+
+    assert!(suite.verify(&document, &policy).is_err());
+  }
+
+  #[test]
+  fn test_sign_signer() {
+    let suite: LdSuite<SlhDsaSha2128sSignature2024> = LdSuite::new(SlhDsaSha2128sSignature2024);
+    let policy = VerificationPolicy::new(|| 1609459200);
+    let secret: Vec<u8> = secret(7);
+    let mut document: VerifiableDocument = document(&secret);
+    let signer: LocalSigner = LocalSigner::new(SecretKey::from_bytes(
+      MethodType::SlhDsaSha2128sVerificationKey2024,
+      secret,
+    ));
+
+    suite.sign_with(&mut document, options(), &signer).unwrap();
+
+    assert!(suite.verify(&document, &policy).is_ok());
+
+    let mut document: VerifiableDocument = self::document(&self::secret(8));
+
+    assert!(suite.sign_with(&mut document, options(), &signer).is_err());
+  }
+}
@@ -0,0 +1,272 @@
+use core::cmp::Ordering;
+use serde::Serialize;
+use serde_json::to_value;
+use serde_json::Number;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::utils::Value;
+
+const ERR_CF: &str = "Canonicalization Failed";
+const ERR_IN: &str = "Invalid Number";
+
+// The largest integer magnitude exactly representable as an IEEE 754 double
+const MAX_SAFE: u64 = 1 << 53;
+
+/// Serializes `data` with the JSON Canonicalization Scheme (RFC 8785).
+///
+/// Object members are sorted by their UTF-16 code units, numbers are
+/// formatted as ECMAScript doubles, and insignificant whitespace is removed.
+///
+/// # Errors
+///
+/// Fails if `data` cannot be represented as JSON, or contains an integer that
+/// cannot be represented as a double without loss of precision.
+pub fn canonicalize<T>(data: &T) -> Result<Vec<u8>>
+where
+  T: Serialize + ?Sized,
+{
+  let value: Value = to_value(data).map_err(|_| Error::message(ERR_CF))?;
+  let mut output: String = String::new();
+
+  write_value(&mut output, &value)?;
+
+  Ok(output.into_bytes())
+}
+
+fn write_value(output: &mut String, value: &Value) -> Result<()> {
+  match value {
+    Value::Null => output.push_str("null"),
+    Value::Bool(true) => output.push_str("true"),
+    Value::Bool(false) => output.push_str("false"),
+    Value::Number(number) => write_number(output, number)?,
+    Value::String(string) => write_string(output, string)?,
+    Value::Array(array) => {
+      output.push('[');
+
+      for (index, item) in array.iter().enumerate() {
+        if index > 0 {
+          output.push(',');
+        }
+
+        write_value(output, item)?;
+      }
+
+      output.push(']');
+    }
+    Value::Object(object) => {
+      // Sort by UTF-16 code units, independent of the map ordering
+      let mut members: Vec<(&String, &Value)> = object.iter().collect();
+
+      members.sort_by(|a, b| cmp_utf16(a.0, b.0));
+
+      output.push('{');
+
+      for (index, (key, item)) in members.into_iter().enumerate() {
+        if index > 0 {
+          output.push(',');
+        }
+
+        write_string(output, key)?;
+        output.push(':');
+        write_value(output, item)?;
+      }
+
+      output.push('}');
+    }
+  }
+
+  Ok(())
+}
+
+fn write_string(output: &mut String, string: &str) -> Result<()> {
+  // `serde_json` escapes strings exactly as ECMAScript `JSON.stringify`
+  let escaped: String = serde_json::to_string(string).map_err(|_| Error::message(ERR_CF))?;
+
+  output.push_str(&escaped);
+
+  Ok(())
+}
+
+fn write_number(output: &mut String, number: &Number) -> Result<()> {
+  if let Some(value) = number.as_u64() {
+    if value > MAX_SAFE {
+      return Err(Error::message(ERR_IN));
+    }
+  } else if let Some(value) = number.as_i64() {
+    if value.unsigned_abs() > MAX_SAFE {
+      return Err(Error::message(ERR_IN));
+    }
+  }
+
+  let value: f64 = number.as_f64().ok_or_else(|| Error::message(ERR_IN))?;
+
+  if !value.is_finite() {
+    return Err(Error::message(ERR_IN));
+  }
+
+  output.push_str(&format_number(value));
+
+  Ok(())
+}
+
+fn cmp_utf16(lhs: &str, rhs: &str) -> Ordering {
+  lhs.encode_utf16().cmp(rhs.encode_utf16())
+}
+
+/// Formats a finite `f64` with the ECMAScript `Number.prototype.toString`
+/// algorithm (ECMA-262, 7.1.12.1).
+fn format_number(value: f64) -> String {
+  if value == 0.0 {
+    // Negative zero is serialized as "0"
+    return "0".to_string();
+  }
+
+  // The length of the shortest round-trip representation, e.g. "1.2345e-7"
+  let shortest: String = alloc::format!("{:e}", value.abs());
+  let k: usize = shortest.split('e').next().map_or(0, |mantissa| {
+    mantissa.chars().filter(char::is_ascii_digit).count()
+  });
+
+  // The closest `k` digits, with ties rounded to even as required by ECMA-262
+  let scientific: String = alloc::format!("{:.*e}", k - 1, value.abs());
+  let (mantissa, exponent): (&str, &str) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+  let digits: String = mantissa.chars().filter(char::is_ascii_digit).collect();
+  let exponent: i32 = exponent.parse().unwrap_or_default();
+
+  // The value is `0.digits * 10^n`
+  let k: i32 = digits.len() as i32;
+  let n: i32 = exponent + 1;
+
+  let mut output: String = String::new();
+
+  if value.is_sign_negative() {
+    output.push('-');
+  }
+
+  if k <= n && n <= 21 {
+    output.push_str(&digits);
+    output.extend((0..n - k).map(|_| '0'));
+  } else if 0 < n && n <= 21 {
+    output.push_str(&digits[..n as usize]);
+    output.push('.');
+    output.push_str(&digits[n as usize..]);
+  } else if -6 < n && n <= 0 {
+    output.push_str("0.");
+    output.extend((0..-n).map(|_| '0'));
+    output.push_str(&digits);
+  } else {
+    output.push_str(&digits[..1]);
+
+    if k > 1 {
+      output.push('.');
+      output.push_str(&digits[1..]);
+    }
+
+    output.push('e');
+    output.push(if n > 0 { '+' } else { '-' });
+    output.push_str(&(n - 1).abs().to_string());
+  }
+
+  output
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  fn canonical(value: &Value) -> String {
+    String::from_utf8(canonicalize(value).unwrap()).unwrap()
+  }
+
+  #[test]
+  fn test_numbers() {
+    // RFC 8785, Appendix B
+    let vectors: &[(u64, &str)] = &[
+      (0x0000000000000000, "0"),
+      (0x8000000000000000, "0"),
+      (0x0000000000000001, "5e-324"),
+      (0x8000000000000001, "-5e-324"),
+      (0x7fefffffffffffff, "1.7976931348623157e+308"),
+      (0xffefffffffffffff, "-1.7976931348623157e+308"),
+      (0x4340000000000000, "9007199254740992"),
+      (0xc340000000000000, "-9007199254740992"),
+      (0x4430000000000000, "295147905179352830000"),
+      (0x44b52d02c7e14af5, "9.999999999999997e+22"),
+      (0x44b52d02c7e14af6, "1e+23"),
+      (0x44b52d02c7e14af7, "1.0000000000000001e+23"),
+      (0x444b1ae4d6e2ef4e, "999999999999999700000"),
+      (0x444b1ae4d6e2ef4f, "999999999999999900000"),
+      (0x444b1ae4d6e2ef50, "1e+21"),
+      (0x3eb0c6f7a0b5ed8c, "9.999999999999997e-7"),
+      (0x3eb0c6f7a0b5ed8d, "0.000001"),
+      (0x41b3de4355555553, "333333333.3333332"),
+      (0x41b3de4355555554, "333333333.33333325"),
+      (0x41b3de4355555555, "333333333.3333333"),
+      (0x41b3de4355555556, "333333333.3333334"),
+      (0x41b3de4355555557, "333333333.33333343"),
+      (0xbecbf647612f3696, "-0.0000033333333333333333"),
+      (0x43143ff3c1cb0959, "1424953923781206.2"),
+    ];
+
+    for (bits, expected) in vectors {
+      assert_eq!(format_number(f64::from_bits(*bits)), *expected);
+    }
+  }
+
+  #[test]
+  fn test_sample() {
+    // RFC 8785, Section 3.2.2
+    let input: Value = serde_json::from_str(
+      r#"{
+        "numbers": [333333333.33333329, 1E30, 4.50, 2e-3, 0.000000000000000000000000001],
+        "string": "\u20ac$\u000F\u000aA'\u0042\u0022\u005c\\\"\/",
+        "literals": [null, true, false]
+      }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+      canonical(&input),
+      r#"{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"string":"€$\u000f\nA'B\"\\\\\"/"}"#,
+    );
+  }
+
+  #[test]
+  fn test_sorting() {
+    // RFC 8785, Section 3.2.3
+    let input: Value = serde_json::from_str(
+      r#"{
+        "€": "Euro Sign",
+        "\r": "Carriage Return",
+        "דּ": "Hebrew Letter Dalet With Dagesh",
+        "1": "One",
+        "😀": "Emoji: Grinning Face",
+        "\u0080": "Control",
+        "ö": "Latin Small Letter O With Diaeresis"
+      }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+      canonical(&input),
+      concat!(
+        r#"{"\r":"Carriage Return","1":"One","#,
+        "\"\u{80}\":\"Control\",",
+        "\"\u{f6}\":\"Latin Small Letter O With Diaeresis\",",
+        "\"\u{20ac}\":\"Euro Sign\",",
+        "\"\u{1f600}\":\"Emoji: Grinning Face\",",
+        "\"\u{fb33}\":\"Hebrew Letter Dalet With Dagesh\"}",
+      ),
+    );
+  }
+
+  #[test]
+  fn test_unsafe_integer() {
+    assert!(canonicalize(&json!(9007199254740992_u64)).is_ok());
+    assert!(canonicalize(&json!(9007199254740993_u64)).is_err());
+    assert!(canonicalize(&json!(-9007199254740993_i64)).is_err());
+  }
+}
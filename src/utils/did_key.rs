@@ -63,7 +63,7 @@ where
 {
   #[inline]
   fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-    self.as_did().partial_cmp(other.as_did())
+    Some(self.cmp(other))
   }
 }
 
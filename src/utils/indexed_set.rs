@@ -0,0 +1,574 @@
+use core::convert::TryFrom;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::iter::FromIterator;
+use core::ops::Deref;
+use core::ops::DerefMut;
+use core::slice::Iter;
+use did_url::DID;
+use serde::Deserialize;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::utils::DIDKey;
+
+/// An ordered set of `DIDKey<T>` items with an index by `DID`.
+///
+/// Items keep their insertion order; lookups, insertions, and in-place
+/// updates by `DID` do not scan the set. The set is serialized as a sequence,
+/// the same as an `OrderedSet`.
+#[derive(Clone, Deserialize)]
+#[serde(
+  bound(deserialize = "T: AsRef<DID> + Deserialize<'de>"),
+  try_from = "Vec<DIDKey<T>>"
+)]
+pub struct IndexedSet<T> {
+  items: Vec<DIDKey<T>>,
+  index: BTreeMap<DID, usize>,
+}
+
+impl<T> IndexedSet<T> {
+  /// Creates a new `IndexedSet`.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      items: Vec::new(),
+      index: BTreeMap::new(),
+    }
+  }
+
+  /// Creates a new `IndexedSet` with the specified capacity.
+  #[inline]
+  pub fn with_capacity(capacity: usize) -> Self {
+    Self {
+      items: Vec::with_capacity(capacity),
+      index: BTreeMap::new(),
+    }
+  }
+
+  /// Returns the number of elements in the `IndexedSet`.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.items.len()
+  }
+
+  /// Returns `true` if the `IndexedSet` contains no elements.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.items.is_empty()
+  }
+
+  /// Returns an iterator over the slice of elements.
+  #[inline]
+  pub fn iter(&self) -> Iter<'_, DIDKey<T>> {
+    self.items.iter()
+  }
+
+  /// Returns the first element in the set, or `None` if the set is empty.
+  #[inline]
+  pub fn head(&self) -> Option<&DIDKey<T>> {
+    self.items.first()
+  }
+
+  /// Returns the last element in the set, or `None` if the set is empty.
+  #[inline]
+  pub fn tail(&self) -> Option<&DIDKey<T>> {
+    self.items.last()
+  }
+
+  /// Returns a slice containing all elements in the `IndexedSet`.
+  #[inline]
+  pub fn as_slice(&self) -> &[DIDKey<T>] {
+    &self.items
+  }
+
+  /// Consumes the `IndexedSet` and returns the elements as a `Vec`.
+  #[inline]
+  pub fn into_vec(self) -> Vec<DIDKey<T>> {
+    self.items
+  }
+
+  /// Clears the `IndexedSet`, removing all values.
+  #[inline]
+  pub fn clear(&mut self) {
+    self.items.clear();
+    self.index.clear();
+  }
+
+  /// Returns the position of the element with the given `DID`.
+  #[inline]
+  pub fn position(&self, id: &DID) -> Option<usize> {
+    self.index.get(id).copied()
+  }
+
+  /// Returns the element with the given `DID`.
+  #[inline]
+  pub fn find(&self, id: &DID) -> Option<&DIDKey<T>> {
+    self.position(id).map(|index| &self.items[index])
+  }
+
+  /// Returns `true` if the `IndexedSet` contains an element with the given
+  /// `DID`.
+  #[inline]
+  pub fn contains(&self, id: &DID) -> bool {
+    self.index.contains_key(id)
+  }
+}
+
+impl<T> IndexedSet<T>
+where
+  T: AsRef<DID>,
+{
+  /// Returns a mutable reference to the first element in the set, or `None` if
+  /// the set is empty.
+  #[inline]
+  pub fn head_mut(&mut self) -> Option<ItemMut<'_, T>> {
+    match self.items.len() {
+      0 => None,
+      _ => Some(ItemMut::new(self, 0)),
+    }
+  }
+
+  /// Returns a mutable reference to the last element in the set, or `None` if
+  /// the set is empty.
+  #[inline]
+  pub fn tail_mut(&mut self) -> Option<ItemMut<'_, T>> {
+    match self.items.len() {
+      0 => None,
+      size => Some(ItemMut::new(self, size - 1)),
+    }
+  }
+
+  /// Adds a new value to the end of the `IndexedSet`; returns `true` if the
+  /// value was successfully added.
+  pub fn append(&mut self, item: DIDKey<T>) -> bool {
+    if self.contains(item.as_did()) {
+      false
+    } else {
+      self.index.insert(item.as_did().clone(), self.items.len());
+      self.items.push(item);
+      true
+    }
+  }
+
+  /// Adds a new value to the start of the `IndexedSet`; returns `true` if the
+  /// value was successfully added.
+  pub fn prepend(&mut self, item: DIDKey<T>) -> bool {
+    if self.contains(item.as_did()) {
+      false
+    } else {
+      self.items.insert(0, item);
+      self.reindex(0);
+      true
+    }
+  }
+
+  /// Replaces the value with the `current` DID with the given `update` value;
+  /// returns `true` if the value was successfully replaced.
+  ///
+  /// An existing value with the same DID as `update` is removed.
+  pub fn replace(&mut self, current: &DID, update: DIDKey<T>) -> bool {
+    let index: Option<usize> = match (self.position(current), self.position(update.as_did())) {
+      (Some(current), Some(update)) => {
+        if current != update {
+          self.items.remove(current.max(update));
+        }
+
+        Some(current.min(update))
+      }
+      (Some(index), None) | (None, Some(index)) => Some(index),
+      (None, None) => None,
+    };
+
+    if let Some(index) = index {
+      self.index.remove(current);
+      self.items[index] = update;
+      self.reindex(index);
+    }
+
+    index.is_some()
+  }
+
+  /// Updates an existing value in the `IndexedSet`; returns `true` if the value
+  /// was successfully updated.
+  pub fn update(&mut self, update: DIDKey<T>) -> bool {
+    match self.position(update.as_did()) {
+      Some(index) => {
+        self.items[index] = update;
+        true
+      }
+      None => false,
+    }
+  }
+
+  /// Removes the value with the given `DID` from the `IndexedSet`; returns the
+  /// removed value, if any.
+  pub fn remove(&mut self, id: &DID) -> Option<DIDKey<T>> {
+    let index: usize = self.position(id)?;
+    let item: DIDKey<T> = self.items.remove(index);
+
+    self.index.remove(id);
+    self.reindex(index);
+
+    Some(item)
+  }
+
+  /// Removes the first value matching the predicate `f`; returns the removed
+  /// value, if any.
+  pub fn remove_by<F>(&mut self, f: F) -> Option<DIDKey<T>>
+  where
+    F: FnMut(&DIDKey<T>) -> bool,
+  {
+    let index: usize = self.items.iter().position(f)?;
+    let item: DIDKey<T> = self.items.remove(index);
+
+    self.index.remove(item.as_did());
+    self.reindex(index);
+
+    Some(item)
+  }
+
+  /// Retains only the values matching the predicate `f`.
+  pub fn retain<F>(&mut self, f: F)
+  where
+    F: FnMut(&DIDKey<T>) -> bool,
+  {
+    self.items.retain(f);
+    self.index.clear();
+    self.reindex(0);
+  }
+
+  fn reindex(&mut self, start: usize) {
+    for (index, item) in self.items.iter().enumerate().skip(start) {
+      self.index.insert(item.as_did().clone(), index);
+    }
+  }
+}
+
+impl<T> Debug for IndexedSet<T>
+where
+  T: Debug,
+{
+  #[inline]
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    f.debug_set().entries(self.iter()).finish()
+  }
+}
+
+impl<T> Deref for IndexedSet<T> {
+  type Target = [DIDKey<T>];
+
+  #[inline]
+  fn deref(&self) -> &Self::Target {
+    &self.items
+  }
+}
+
+impl<T> Default for IndexedSet<T> {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Sets are compared by the `DID` of their elements, the same as an
+/// `OrderedSet` of `DIDKey`s.
+impl<T> PartialEq for IndexedSet<T>
+where
+  T: AsRef<DID>,
+{
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.items == other.items
+  }
+}
+
+impl<T> Eq for IndexedSet<T> where T: AsRef<DID> {}
+
+impl<T> Serialize for IndexedSet<T>
+where
+  T: Serialize,
+{
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_seq(self.iter())
+  }
+}
+
+impl<T> FromIterator<DIDKey<T>> for IndexedSet<T>
+where
+  T: AsRef<DID>,
+{
+  fn from_iter<I>(iter: I) -> Self
+  where
+    I: IntoIterator<Item = DIDKey<T>>,
+  {
+    let iter: I::IntoIter = iter.into_iter();
+    let size: usize = iter.size_hint().1.unwrap_or(0);
+
+    let mut this: Self = Self::with_capacity(size);
+
+    for item in iter {
+      this.append(item);
+    }
+
+    this
+  }
+}
+
+impl<T> TryFrom<Vec<DIDKey<T>>> for IndexedSet<T>
+where
+  T: AsRef<DID>,
+{
+  type Error = Error;
+
+  fn try_from(other: Vec<DIDKey<T>>) -> Result<Self, Self::Error> {
+    let mut this: Self = Self::with_capacity(other.len());
+
+    for (index, item) in other.into_iter().enumerate() {
+      if !this.append(item) {
+        return Err(Error::duplicate_item(index));
+      }
+    }
+
+    Ok(this)
+  }
+}
+
+/// A mutable reference to an element of an `IndexedSet`.
+///
+/// The index of the set is updated when the reference is dropped. If the
+/// `DID` of the element was changed to that of another element, the other
+/// element is removed, the same as with `IndexedSet::replace`.
+pub struct ItemMut<'a, T>
+where
+  T: AsRef<DID>,
+{
+  set: &'a mut IndexedSet<T>,
+  index: usize,
+}
+
+impl<'a, T> ItemMut<'a, T>
+where
+  T: AsRef<DID>,
+{
+  fn new(set: &'a mut IndexedSet<T>, index: usize) -> Self {
+    Self { set, index }
+  }
+}
+
+impl<T> Debug for ItemMut<'_, T>
+where
+  T: AsRef<DID> + Debug,
+{
+  #[inline]
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    Debug::fmt(&**self, f)
+  }
+}
+
+impl<T> Deref for ItemMut<'_, T>
+where
+  T: AsRef<DID>,
+{
+  type Target = DIDKey<T>;
+
+  #[inline]
+  fn deref(&self) -> &Self::Target {
+    &self.set.items[self.index]
+  }
+}
+
+impl<T> DerefMut for ItemMut<'_, T>
+where
+  T: AsRef<DID>,
+{
+  #[inline]
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.set.items[self.index]
+  }
+}
+
+impl<T> Drop for ItemMut<'_, T>
+where
+  T: AsRef<DID>,
+{
+  fn drop(&mut self) {
+    let set: &mut IndexedSet<T> = &mut *self.set;
+    let position: Option<usize> = set.position(set.items[self.index].as_did());
+
+    if position == Some(self.index) {
+      return;
+    }
+
+    if let Some(other) = position {
+      set.items.remove(other);
+    }
+
+    set.index.clear();
+    set.reindex(0);
+  }
+}
+
+impl<'a, T> IntoIterator for &'a IndexedSet<T> {
+  type Item = &'a DIDKey<T>;
+  type IntoIter = Iter<'a, DIDKey<T>>;
+
+  #[inline]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use alloc::vec;
+  use serde_json::json;
+
+  use crate::verification::MethodBuilder;
+  use crate::verification::MethodData;
+  use crate::verification::MethodRef;
+  use crate::verification::MethodType;
+
+  fn did(fragment: &str) -> DID {
+    alloc::format!("did:example:123#{}", fragment)
+      .parse()
+      .unwrap()
+  }
+
+  fn refer(fragment: &str) -> DIDKey<MethodRef> {
+    DIDKey::new(MethodRef::Refer(did(fragment)))
+  }
+
+  fn ids(set: &IndexedSet<MethodRef>) -> Vec<&str> {
+    set
+      .iter()
+      .map(|item| item.id().fragment().unwrap())
+      .collect()
+  }
+
+  fn assert_indexed(set: &IndexedSet<MethodRef>) {
+    assert_eq!(set.index.len(), set.len());
+
+    for (index, item) in set.iter().enumerate() {
+      assert_eq!(set.position(item.id()), Some(index));
+    }
+  }
+
+  #[test]
+  fn test_works() {
+    let mut set: IndexedSet<MethodRef> = IndexedSet::new();
+
+    assert!(set.append(refer("a")));
+    assert!(set.append(refer("b")));
+    assert!(!set.append(refer("a")));
+    assert!(set.prepend(refer("c")));
+
+    assert_eq!(ids(&set), ["c", "a", "b"]);
+    assert_eq!(set.find(&did("a")), Some(&refer("a")));
+    assert!(set.contains(&did("b")));
+    assert!(!set.contains(&did("d")));
+    assert_indexed(&set);
+
+    // Replacing a value with the id of a later value removes the later value
+    assert!(set.replace(&did("a"), refer("b")));
+    assert_eq!(ids(&set), ["c", "b"]);
+    assert_indexed(&set);
+
+    assert!(set.replace(&did("c"), refer("d")));
+    assert!(!set.replace(&did("c"), refer("e")));
+    assert_eq!(ids(&set), ["d", "b"]);
+    assert_indexed(&set);
+
+    assert_eq!(set.remove(&did("d")), Some(refer("d")));
+    assert_eq!(set.remove(&did("d")), None);
+    assert_eq!(ids(&set), ["b"]);
+    assert_indexed(&set);
+  }
+
+  #[test]
+  fn test_remove_by_retain() {
+    let mut set: IndexedSet<MethodRef> = vec![refer("a"), refer("b"), refer("c"), refer("d")]
+      .into_iter()
+      .collect();
+
+    assert_eq!(
+      set.remove_by(|item| item.id() == &did("b")),
+      Some(refer("b"))
+    );
+    assert_indexed(&set);
+
+    set.retain(|item| item.id() != &did("a"));
+
+    assert_eq!(ids(&set), ["c", "d"]);
+    assert_indexed(&set);
+  }
+
+  #[test]
+  fn test_serde() {
+    let value = json!(["did:example:123#a", "did:example:123#b"]);
+    let set: IndexedSet<MethodRef> = serde_json::from_value(value.clone()).unwrap();
+
+    assert_eq!(ids(&set), ["a", "b"]);
+    assert_indexed(&set);
+    assert_eq!(serde_json::to_value(&set).unwrap(), value);
+
+    let value = json!(["did:example:123#a", "did:example:123#a"]);
+
+    assert!(serde_json::from_value::<IndexedSet<MethodRef>>(value).is_err());
+  }
+
+  #[test]
+  fn test_head_tail_mut() {
+    let mut set: IndexedSet<MethodRef> = vec![refer("a"), refer("b"), refer("c")]
+      .into_iter()
+      .collect();
+
+    *set.head_mut().unwrap() = refer("d");
+    assert_eq!(ids(&set), ["d", "b", "c"]);
+    assert_indexed(&set);
+
+    // Taking the id of another value removes the other value
+    *set.tail_mut().unwrap() = refer("b");
+    assert_eq!(ids(&set), ["d", "b"]);
+    assert_indexed(&set);
+
+    assert!(IndexedSet::<MethodRef>::new().head_mut().is_none());
+    assert!(IndexedSet::<MethodRef>::new().tail_mut().is_none());
+  }
+
+  #[test]
+  fn test_eq_by_did() {
+    let embed: MethodRef = MethodRef::Embed(
+      MethodBuilder::default()
+        .id(did("a"))
+        .controller("did:example:123".parse().unwrap())
+        .key_type(MethodType::Ed25519VerificationKey2018)
+        .key_data(MethodData::new_b58(b"key"))
+        .build()
+        .unwrap(),
+    );
+
+    let a: IndexedSet<MethodRef> = vec![refer("a"), refer("b")].into_iter().collect();
+    let b: IndexedSet<MethodRef> = vec![DIDKey::new(embed), refer("b")].into_iter().collect();
+    let c: IndexedSet<MethodRef> = vec![refer("b"), refer("a")].into_iter().collect();
+
+    // Elements are compared by DID only, in order
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+  }
+
+  #[test]
+  #[should_panic = "DuplicateItem { index: 2"]
+  fn test_from_vec_invalid() {
+    let source: Vec<DIDKey<MethodRef>> = vec![refer("a"), refer("b"), refer("a")];
+    let _: IndexedSet<MethodRef> = IndexedSet::try_from(source).unwrap();
+  }
+}
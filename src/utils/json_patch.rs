@@ -0,0 +1,482 @@
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+use crate::utils::Value;
+
+const ERR_IJP: &str = "Invalid JSON Pointer";
+const ERR_PNF: &str = "JSON Patch Path Not Found";
+const ERR_IMP: &str = "Invalid JSON Patch Move";
+const ERR_TF: &str = "JSON Patch Test Failed";
+
+/// An operation of a JSON Patch.
+///
+/// [More Info](https://tools.ietf.org/html/rfc6902#section-4)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOperation {
+  Add { path: String, value: Value },
+  Remove { path: String },
+  Replace { path: String, value: Value },
+  Move { from: String, path: String },
+  Copy { from: String, path: String },
+  Test { path: String, value: Value },
+}
+
+/// A JSON Patch: a sequence of operations to apply to a JSON document.
+///
+/// [More Info](https://tools.ietf.org/html/rfc6902)
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[repr(transparent)]
+#[serde(transparent)]
+pub struct JsonPatch(Vec<PatchOperation>);
+
+impl JsonPatch {
+  /// Creates a new, empty `JsonPatch`.
+  pub const fn new() -> Self {
+    Self(Vec::new())
+  }
+
+  /// Creates a `JsonPatch` transforming `old` into `new`.
+  ///
+  /// Arrays of items with unique identifiers (strings, or objects with a
+  /// string `id`) are patched item by item; other arrays are replaced.
+  pub fn diff(old: &Value, new: &Value) -> Self {
+    let mut this: Self = Self::new();
+
+    diff_value(&mut this.0, String::new(), old, new);
+
+    this
+  }
+
+  /// Returns the operations of the patch.
+  pub fn operations(&self) -> &[PatchOperation] {
+    &self.0
+  }
+
+  /// Appends an operation to the patch.
+  pub fn push(&mut self, operation: PatchOperation) {
+    self.0.push(operation);
+  }
+
+  /// Returns the number of operations in the patch.
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  /// Returns `true` if the patch contains no operations.
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Applies the patch to `value`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if any operation can not be applied; `value` is left unchanged.
+  pub fn apply(&self, value: &mut Value) -> Result<()> {
+    let mut patched: Value = value.clone();
+
+    for operation in self.0.iter() {
+      apply_operation(&mut patched, operation)?;
+    }
+
+    *value = patched;
+
+    Ok(())
+  }
+}
+
+impl From<Vec<PatchOperation>> for JsonPatch {
+  fn from(other: Vec<PatchOperation>) -> Self {
+    Self(other)
+  }
+}
+
+impl From<JsonPatch> for Vec<PatchOperation> {
+  fn from(other: JsonPatch) -> Self {
+    other.0
+  }
+}
+
+// =============================================================================
+// Diff
+// =============================================================================
+
+fn diff_value(operations: &mut Vec<PatchOperation>, path: String, old: &Value, new: &Value) {
+  match (old, new) {
+    _ if old == new => {}
+    (Value::Object(old), Value::Object(new)) => {
+      for key in old.keys().filter(|key| !new.contains_key(*key)) {
+        operations.push(PatchOperation::Remove {
+          path: join(&path, key),
+        });
+      }
+
+      for (key, value) in new.iter() {
+        match old.get(key) {
+          Some(current) => diff_value(operations, join(&path, key), current, value),
+          None => operations.push(PatchOperation::Add {
+            path: join(&path, key),
+            value: value.clone(),
+          }),
+        }
+      }
+    }
+    (Value::Array(old), Value::Array(new)) if is_keyed(old) && is_keyed(new) => {
+      diff_keyed(operations, path, old, new);
+    }
+    _ => operations.push(PatchOperation::Replace {
+      path,
+      value: new.clone(),
+    }),
+  }
+}
+
+fn diff_keyed(operations: &mut Vec<PatchOperation>, path: String, old: &[Value], new: &[Value]) {
+  let mut current: Vec<&Value> = old.iter().collect();
+
+  // Remove items from the back to keep the indices of the others valid
+  for index in (0..old.len()).rev() {
+    if !new
+      .iter()
+      .any(|item| item_key(item) == item_key(&old[index]))
+    {
+      operations.push(PatchOperation::Remove {
+        path: join(&path, &index.to_string()),
+      });
+
+      current.remove(index);
+    }
+  }
+
+  // Items before `index` are already in their final position
+  for (index, item) in new.iter().enumerate() {
+    match current[index..]
+      .iter()
+      .position(|value| item_key(value) == item_key(item))
+    {
+      Some(position) => {
+        if position != 0 {
+          operations.push(PatchOperation::Move {
+            from: join(&path, &(index + position).to_string()),
+            path: join(&path, &index.to_string()),
+          });
+
+          let value: &Value = current.remove(index + position);
+          current.insert(index, value);
+        }
+
+        diff_value(
+          operations,
+          join(&path, &index.to_string()),
+          current[index],
+          item,
+        );
+      }
+      None => {
+        operations.push(PatchOperation::Add {
+          path: join(&path, &index.to_string()),
+          value: item.clone(),
+        });
+
+        current.insert(index, item);
+      }
+    }
+  }
+}
+
+fn item_key(value: &Value) -> Option<&str> {
+  match value {
+    Value::String(value) => Some(value),
+    Value::Object(object) => object.get("id").and_then(Value::as_str),
+    _ => None,
+  }
+}
+
+fn is_keyed(values: &[Value]) -> bool {
+  values.iter().enumerate().all(|(index, value)| {
+    item_key(value).is_some_and(|key| {
+      !values[..index]
+        .iter()
+        .any(|other| item_key(other) == Some(key))
+    })
+  })
+}
+
+fn join(path: &str, token: &str) -> String {
+  let mut output: String = String::with_capacity(path.len() + token.len() + 1);
+
+  output.push_str(path);
+  output.push('/');
+
+  for char in token.chars() {
+    match char {
+      '~' => output.push_str("~0"),
+      '/' => output.push_str("~1"),
+      _ => output.push(char),
+    }
+  }
+
+  output
+}
+
+// =============================================================================
+// Apply
+// =============================================================================
+
+fn apply_operation(value: &mut Value, operation: &PatchOperation) -> Result<()> {
+  match operation {
+    PatchOperation::Add { path, value: item } => add(value, path, item.clone()),
+    PatchOperation::Remove { path } => remove(value, path).map(|_| ()),
+    PatchOperation::Replace { path, value: item } => {
+      *get_mut(value, &parse(path)?)? = item.clone();
+      Ok(())
+    }
+    PatchOperation::Move { from, path } => {
+      if path.starts_with(from.as_str()) && path[from.len()..].starts_with('/') {
+        return Err(Error::message(ERR_IMP));
+      }
+
+      let item: Value = remove(value, from)?;
+
+      add(value, path, item)
+    }
+    PatchOperation::Copy { from, path } => {
+      let item: Value = get_mut(value, &parse(from)?)?.clone();
+
+      add(value, path, item)
+    }
+    PatchOperation::Test { path, value: item } => {
+      if get_mut(value, &parse(path)?)? == item {
+        Ok(())
+      } else {
+        Err(Error::message(ERR_TF))
+      }
+    }
+  }
+}
+
+fn add(value: &mut Value, path: &str, item: Value) -> Result<()> {
+  let mut tokens: Vec<String> = parse(path)?;
+
+  let token: String = match tokens.pop() {
+    Some(token) => token,
+    None => {
+      *value = item;
+      return Ok(());
+    }
+  };
+
+  match get_mut(value, &tokens)? {
+    Value::Object(object) => {
+      object.insert(token, item);
+    }
+    Value::Array(array) => {
+      let index: usize = if token == "-" {
+        array.len()
+      } else {
+        index(&token, array.len() + 1)?
+      };
+
+      array.insert(index, item);
+    }
+    _ => return Err(Error::message(ERR_PNF)),
+  }
+
+  Ok(())
+}
+
+fn remove(value: &mut Value, path: &str) -> Result<Value> {
+  let mut tokens: Vec<String> = parse(path)?;
+  let token: String = tokens.pop().ok_or_else(|| Error::message(ERR_PNF))?;
+
+  match get_mut(value, &tokens)? {
+    Value::Object(object) => object.remove(&token).ok_or_else(|| Error::message(ERR_PNF)),
+    Value::Array(array) => Ok(array.remove(index(&token, array.len())?)),
+    _ => Err(Error::message(ERR_PNF)),
+  }
+}
+
+fn get_mut<'a>(value: &'a mut Value, tokens: &[String]) -> Result<&'a mut Value> {
+  tokens.iter().try_fold(value, |value, token| match value {
+    Value::Object(object) => object.get_mut(token).ok_or_else(|| Error::message(ERR_PNF)),
+    Value::Array(array) => {
+      let index: usize = index(token, array.len())?;
+      Ok(&mut array[index])
+    }
+    _ => Err(Error::message(ERR_PNF)),
+  })
+}
+
+/// Parses an array index lower than `len`.
+fn index(token: &str, len: usize) -> Result<usize> {
+  if token.is_empty()
+    || (token.len() > 1 && token.starts_with('0'))
+    || !token.bytes().all(|byte| byte.is_ascii_digit())
+  {
+    return Err(Error::message(ERR_IJP));
+  }
+
+  token
+    .parse()
+    .ok()
+    .filter(|index| *index < len)
+    .ok_or_else(|| Error::message(ERR_PNF))
+}
+
+/// Parses a JSON Pointer into its unescaped reference tokens.
+///
+/// [More Info](https://tools.ietf.org/html/rfc6901)
+fn parse(pointer: &str) -> Result<Vec<String>> {
+  if pointer.is_empty() {
+    return Ok(Vec::new());
+  }
+
+  if !pointer.starts_with('/') {
+    return Err(Error::message(ERR_IJP));
+  }
+
+  pointer[1..]
+    .split('/')
+    .map(|token| {
+      let mut output: String = String::with_capacity(token.len());
+      let mut chars = token.chars();
+
+      while let Some(char) = chars.next() {
+        match char {
+          '~' => match chars.next() {
+            Some('0') => output.push('~'),
+            Some('1') => output.push('/'),
+            _ => return Err(Error::message(ERR_IJP)),
+          },
+          _ => output.push(char),
+        }
+      }
+
+      Ok(output)
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::from_value;
+  use serde_json::json;
+
+  use super::*;
+
+  fn patch(value: Value) -> JsonPatch {
+    from_value(value).unwrap()
+  }
+
+  #[test]
+  fn test_apply() {
+    let mut value: Value = json!({ "foo": ["bar", "baz"], "a/b": { "m~n": 1 } });
+
+    patch(json!([
+      { "op": "add", "path": "/foo/1", "value": "qux" },
+      { "op": "add", "path": "/foo/-", "value": "end" },
+      { "op": "remove", "path": "/foo/0" },
+      { "op": "replace", "path": "/a~1b/m~0n", "value": 2 },
+      { "op": "copy", "from": "/a~1b", "path": "/copy" },
+      { "op": "move", "from": "/foo/2", "path": "/moved" },
+      { "op": "test", "path": "/foo", "value": ["qux", "baz"] },
+    ]))
+    .apply(&mut value)
+    .unwrap();
+
+    assert_eq!(
+      value,
+      json!({
+        "foo": ["qux", "baz"],
+        "a/b": { "m~n": 2 },
+        "copy": { "m~n": 2 },
+        "moved": "end",
+      })
+    );
+  }
+
+  #[test]
+  fn test_apply_atomic() {
+    let mut value: Value = json!({ "foo": 1 });
+
+    let result: Result<()> = patch(json!([
+      { "op": "replace", "path": "/foo", "value": 2 },
+      { "op": "test", "path": "/foo", "value": 1 },
+    ]))
+    .apply(&mut value);
+
+    assert_eq!(result, Err(Error::message(ERR_TF)));
+    assert_eq!(value, json!({ "foo": 1 }));
+  }
+
+  #[test]
+  fn test_apply_invalid() {
+    let mut value: Value = json!({ "foo": [1, 2] });
+
+    for (operation, error) in [
+      (json!({ "op": "remove", "path": "/bar" }), ERR_PNF),
+      (json!({ "op": "remove", "path": "/foo/2" }), ERR_PNF),
+      (json!({ "op": "remove", "path": "/foo/01" }), ERR_IJP),
+      (json!({ "op": "add", "path": "foo", "value": 1 }), ERR_IJP),
+      (
+        json!({ "op": "move", "from": "/foo", "path": "/foo/0" }),
+        ERR_IMP,
+      ),
+    ] {
+      assert_eq!(
+        patch(json!([operation])).apply(&mut value),
+        Err(Error::message(error))
+      );
+    }
+  }
+
+  #[test]
+  fn test_diff() {
+    let old: Value = json!({
+      "id": "did:example:123",
+      "items": [
+        { "id": "#a", "value": 1 },
+        { "id": "#b", "value": 2 },
+        "#c",
+        { "id": "#d", "value": 4 },
+      ],
+      "list": [1, 2, 3],
+      "removed": true,
+    });
+
+    let new: Value = json!({
+      "id": "did:example:123",
+      "items": [
+        { "id": "#d", "value": 4 },
+        { "id": "#e", "value": 5 },
+        { "id": "#a", "value": 10 },
+      ],
+      "list": [1, 2],
+      "added": true,
+    });
+
+    let diff: JsonPatch = JsonPatch::diff(&old, &new);
+
+    assert_eq!(
+      diff,
+      patch(json!([
+        { "op": "remove", "path": "/removed" },
+        { "op": "add", "path": "/added", "value": true },
+        { "op": "remove", "path": "/items/2" },
+        { "op": "remove", "path": "/items/1" },
+        { "op": "move", "from": "/items/1", "path": "/items/0" },
+        { "op": "add", "path": "/items/1", "value": { "id": "#e", "value": 5 } },
+        { "op": "replace", "path": "/items/2/value", "value": 10 },
+        { "op": "replace", "path": "/list", "value": [1, 2] },
+      ]))
+    );
+
+    let mut value: Value = old.clone();
+
+    diff.apply(&mut value).unwrap();
+
+    assert_eq!(value, new);
+    assert!(JsonPatch::diff(&new, &new).is_empty());
+  }
+}
@@ -1,7 +1,17 @@
+mod canonical;
 mod did_key;
+mod indexed_set;
+mod json_patch;
+mod multibase;
 mod object;
 mod ordered_set;
+mod timestamp;
 
+pub use self::canonical::*;
 pub use self::did_key::*;
+pub use self::indexed_set::*;
+pub use self::json_patch::*;
+pub(crate) use self::multibase::*;
 pub use self::object::*;
 pub use self::ordered_set::*;
+pub use self::timestamp::*;
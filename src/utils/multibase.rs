@@ -0,0 +1,94 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+
+const ERR_IMB: &str = "Invalid Multibase Data";
+const ERR_UMB: &str = "Unsupported Multibase Encoding";
+const ERR_IMC: &str = "Invalid Multicodec Data";
+
+/// Decodes a multibase string; supports base58btc (`z`), base64url (`u`), and
+/// base16 (`f`) encodings.
+pub(crate) fn decode_multibase(input: &str) -> Result<Vec<u8>> {
+  let mut chars: core::str::Chars<'_> = input.chars();
+
+  match chars.next() {
+    Some('z') => bs58::decode(chars.as_str())
+      .into_vec()
+      .map_err(|_| Error::message(ERR_IMB)),
+    Some('u') => URL_SAFE_NO_PAD
+      .decode(chars.as_str())
+      .map_err(|_| Error::message(ERR_IMB)),
+    Some('f') => hex::decode(chars.as_str()).map_err(|_| Error::message(ERR_IMB)),
+    Some(_) => Err(Error::message(ERR_UMB)),
+    None => Err(Error::message(ERR_IMB)),
+  }
+}
+
+/// Splits multicodec data into the codec identifier and the content.
+pub(crate) fn decode_multicodec(input: &[u8]) -> Result<(u64, &[u8])> {
+  let mut codec: u64 = 0;
+
+  // Multicodec identifiers are unsigned varints of at most 9 bytes
+  for (index, byte) in input.iter().take(9).enumerate() {
+    codec |= u64::from(byte & 0x7F) << (index * 7);
+
+    if byte & 0x80 == 0 {
+      return Ok((codec, &input[index + 1..]));
+    }
+  }
+
+  Err(Error::message(ERR_IMC))
+}
+
+/// Prefixes `data` with the unsigned varint of a multicodec identifier.
+pub(crate) fn encode_multicodec(codec: u64, data: &[u8]) -> Vec<u8> {
+  let mut output: Vec<u8> = Vec::with_capacity(data.len() + 9);
+  let mut codec: u64 = codec;
+
+  while codec >= 0x80 {
+    output.push(codec as u8 | 0x80);
+    codec >>= 7;
+  }
+
+  output.push(codec as u8);
+  output.extend_from_slice(data);
+  output
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_multibase() {
+    let data: &[u8] = b"multibase";
+
+    assert_eq!(decode_multibase("z2PowuorPY2Bbr").unwrap(), data);
+    assert_eq!(decode_multibase("f6d756c746962617365").unwrap(), data);
+    assert_eq!(decode_multibase("ubXVsdGliYXNl").unwrap(), data);
+    assert!(decode_multibase("Mbase64").is_err());
+  }
+
+  #[test]
+  fn test_multicodec() {
+    assert_eq!(
+      decode_multicodec(&[0xED, 0x01, 0x01]).unwrap(),
+      (0xED, &[0x01][..])
+    );
+    assert_eq!(decode_multicodec(&[0x80, 0x26]).unwrap(), (0x1300, &[][..]));
+    assert!(decode_multicodec(&[0x80, 0x80]).is_err());
+  }
+
+  #[test]
+  fn test_encode_multicodec() {
+    let data: Vec<u8> = alloc::vec![0x01; 2048];
+    let encoded: Vec<u8> = encode_multicodec(0x1211, &data);
+
+    assert_eq!(encoded[..2], [0x91, 0x24]);
+    assert_eq!(decode_multicodec(&encoded).unwrap(), (0x1211, &data[..]));
+    assert_eq!(encode_multicodec(0xED, &[]), [0xED, 0x01]);
+  }
+}
@@ -12,8 +12,6 @@ use crate::error::Error;
 use crate::error::Result;
 use crate::lib::*;
 
-const ERR_DUP: &str = "Duplicate Item in Ordered Set";
-
 /// An ordered set based on a `Vec<T>`.
 #[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
 #[repr(transparent)]
@@ -50,7 +48,7 @@ impl<T> OrderedSet<T> {
 
   /// Returns an iterator over the slice of elements.
   #[inline]
-  pub fn iter(&self) -> Iter<T> {
+  pub fn iter(&self) -> Iter<'_, T> {
     self.0.iter()
   }
 
@@ -158,6 +156,35 @@ impl<T> OrderedSet<T> {
     self.change(update, |item, update| item == update)
   }
 
+  /// Removes the value equal to `item` from the `OrderedSet`; returns the
+  /// removed value, if any.
+  #[inline]
+  pub fn remove<U>(&mut self, item: &U) -> Option<T>
+  where
+    T: Borrow<U>,
+    U: PartialEq + ?Sized,
+  {
+    self.remove_by(|other| other.borrow() == item)
+  }
+
+  /// Removes the first value matching the predicate `f`; returns the removed
+  /// value, if any.
+  pub fn remove_by<F>(&mut self, f: F) -> Option<T>
+  where
+    F: FnMut(&T) -> bool,
+  {
+    self.0.iter().position(f).map(|index| self.0.remove(index))
+  }
+
+  /// Retains only the values matching the predicate `f`.
+  #[inline]
+  pub fn retain<F>(&mut self, f: F)
+  where
+    F: FnMut(&T) -> bool,
+  {
+    self.0.retain(f);
+  }
+
   fn change<F>(&mut self, data: T, f: F) -> bool
   where
     F: Fn(&T, &T) -> bool,
@@ -213,7 +240,7 @@ where
   where
     I: IntoIterator<Item = T>,
   {
-    let iter: _ = iter.into_iter();
+    let iter: I::IntoIter = iter.into_iter();
     let size: usize = iter.size_hint().1.unwrap_or(0);
 
     let mut this: Self = Self::with_capacity(size);
@@ -235,9 +262,9 @@ where
   fn try_from(other: Vec<T>) -> Result<Self, Self::Error> {
     let mut this: Self = Self::with_capacity(other.len());
 
-    for item in other {
+    for (index, item) in other.into_iter().enumerate() {
       if !this.append(item) {
-        return Err(Error::message(ERR_DUP));
+        return Err(Error::duplicate_item(index));
       }
     }
 
@@ -285,6 +312,34 @@ mod tests {
     assert_eq!(set.as_slice(), &["c", "b"]);
   }
 
+  #[test]
+  fn test_remove() {
+    let did1: DID = DID::parse("did:example:123#key-1").unwrap();
+    let did2: DID = DID::parse("did:example:123#key-2").unwrap();
+    let did3: DID = DID::parse("did:example:123#key-3").unwrap();
+
+    let mut set: OrderedSet<DIDKey<MethodRef>> = vec![
+      DIDKey::new(MethodRef::Refer(did1.clone())),
+      DIDKey::new(MethodRef::Refer(did2.clone())),
+      DIDKey::new(MethodRef::Refer(did3.clone())),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(set.remove(&did2).unwrap().id(), &did2);
+    assert_eq!(set.remove(&did2), None);
+    assert_eq!(set.len(), 2);
+
+    assert_eq!(
+      set.remove_by(|item| item.id() == &did3).unwrap().id(),
+      &did3
+    );
+
+    set.retain(|item| item.id() != &did1);
+
+    assert!(set.is_empty());
+  }
+
   #[test]
   fn test_from_vec_valid() {
     let source: Vec<u8> = vec![3, 1, 2, 0];
@@ -294,7 +349,7 @@ mod tests {
   }
 
   #[test]
-  #[should_panic = "Duplicate Item"]
+  #[should_panic = "DuplicateItem { index: 2"]
   fn test_from_vec_invalid() {
     let source: Vec<u8> = vec![1, 2, 2, 5];
     let _: OrderedSet<u8> = OrderedSet::try_from(source).unwrap();
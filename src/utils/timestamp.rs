@@ -0,0 +1,212 @@
+use core::convert::TryFrom;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::str::FromStr;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+use time::UtcOffset;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::lib::*;
+
+const ERR_IT: &str = "Invalid Timestamp";
+
+/// A timestamp in the RFC 3339 (XML Schema `dateTime`) format.
+///
+/// Timestamps created from Unix time or the system clock are formatted in UTC
+/// with second precision. Parsed timestamps keep their original lexical form,
+/// including the offset and fractional seconds, since signatures are computed
+/// over the serialized value.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Timestamp {
+  datetime: OffsetDateTime,
+  lexical: String,
+}
+
+impl Timestamp {
+  /// Parses a `Timestamp` from an RFC 3339 string.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the input is not a valid RFC 3339 timestamp.
+  pub fn parse(input: &str) -> Result<Self> {
+    // XML Schema `dateTime` requires an upper-case date/time separator
+    if input.as_bytes().get(10) != Some(&b'T') {
+      return Err(Error::message(ERR_IT));
+    }
+
+    let datetime: OffsetDateTime =
+      OffsetDateTime::parse(input, &Rfc3339).map_err(|_| Error::message(ERR_IT))?;
+
+    Ok(Self {
+      datetime: check_datetime(datetime)?,
+      lexical: input.to_string(),
+    })
+  }
+
+  /// Creates a `Timestamp` from the given number of seconds since the Unix
+  /// epoch.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the timestamp cannot be represented in the RFC 3339 format.
+  pub fn from_unix(seconds: i64) -> Result<Self> {
+    let datetime: OffsetDateTime =
+      OffsetDateTime::from_unix_timestamp(seconds).map_err(|_| Error::message(ERR_IT))?;
+    let datetime: OffsetDateTime = check_datetime(datetime)?;
+
+    Ok(Self {
+      datetime,
+      lexical: format_datetime(datetime),
+    })
+  }
+
+  /// Returns a `Timestamp` of the current system time, with second precision.
+  #[cfg(feature = "std")]
+  pub fn now() -> Self {
+    use crate::signature::Clock as _;
+
+    Self::from_unix(crate::signature::SystemClock.now()).expect("Invalid System Time")
+  }
+
+  /// Returns the number of seconds since the Unix epoch.
+  pub fn to_unix(&self) -> i64 {
+    self.datetime.unix_timestamp()
+  }
+
+  /// Returns the `Timestamp` formatted as an RFC 3339 string.
+  pub fn to_rfc3339(&self) -> String {
+    self.lexical.clone()
+  }
+
+  /// Returns the RFC 3339 string of the `Timestamp` in its original form.
+  pub fn as_str(&self) -> &str {
+    &self.lexical
+  }
+}
+
+fn check_datetime(datetime: OffsetDateTime) -> Result<OffsetDateTime> {
+  let datetime: OffsetDateTime = datetime.to_offset(UtcOffset::UTC);
+
+  // RFC 3339 only allows four-digit years
+  if (0..=9999).contains(&datetime.year()) {
+    Ok(datetime)
+  } else {
+    Err(Error::message(ERR_IT))
+  }
+}
+
+fn format_datetime(datetime: OffsetDateTime) -> String {
+  alloc::format!(
+    "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+    datetime.year(),
+    datetime.month() as u8,
+    datetime.day(),
+    datetime.hour(),
+    datetime.minute(),
+    datetime.second(),
+  )
+}
+
+impl Debug for Timestamp {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    f.write_fmt(format_args!("Timestamp({})", self))
+  }
+}
+
+impl Display for Timestamp {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    f.write_str(&self.lexical)
+  }
+}
+
+impl FromStr for Timestamp {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    Self::parse(string)
+  }
+}
+
+impl TryFrom<&'_ str> for Timestamp {
+  type Error = Error;
+
+  fn try_from(other: &str) -> Result<Self, Self::Error> {
+    Self::parse(other)
+  }
+}
+
+impl TryFrom<String> for Timestamp {
+  type Error = Error;
+
+  fn try_from(other: String) -> Result<Self, Self::Error> {
+    Self::parse(&other)
+  }
+}
+
+impl From<Timestamp> for String {
+  fn from(other: Timestamp) -> Self {
+    other.lexical
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_format() {
+    let timestamp: Timestamp = Timestamp::parse("2021-01-01T00:00:00Z").unwrap();
+
+    assert_eq!(timestamp.to_unix(), 1609459200);
+    assert_eq!(timestamp.to_string(), "2021-01-01T00:00:00Z");
+    assert_eq!(Timestamp::from_unix(1609459200).unwrap(), timestamp);
+  }
+
+  #[test]
+  fn test_preserve_offset() {
+    let timestamp: Timestamp = Timestamp::parse("2021-01-01T02:30:00+02:30").unwrap();
+
+    assert_eq!(timestamp.to_unix(), 1609459200);
+    assert_eq!(timestamp.to_string(), "2021-01-01T02:30:00+02:30");
+    assert!(timestamp > Timestamp::from_unix(1609459199).unwrap());
+    assert!(timestamp < Timestamp::from_unix(1609459201).unwrap());
+  }
+
+  #[test]
+  fn test_fractional_seconds() {
+    let timestamp: Timestamp = Timestamp::parse("2021-01-01T00:00:00.250Z").unwrap();
+
+    assert_eq!(timestamp.to_unix(), 1609459200);
+    assert_eq!(timestamp.to_string(), "2021-01-01T00:00:00.250Z");
+  }
+
+  #[test]
+  fn test_invalid() {
+    assert!(Timestamp::parse("2021-01-01").is_err());
+    assert!(Timestamp::parse("2021-01-01 00:00:00Z").is_err());
+    assert!(Timestamp::parse("2021-13-01T00:00:00Z").is_err());
+    assert!(Timestamp::parse("2021-01-01T00:00:00").is_err());
+  }
+
+  #[test]
+  fn test_serde() {
+    let timestamp: Timestamp = serde_json::from_str("\"2021-01-01T00:00:00Z\"").unwrap();
+
+    assert_eq!(
+      serde_json::to_string(&timestamp).unwrap(),
+      "\"2021-01-01T00:00:00Z\""
+    );
+    assert!(serde_json::from_str::<Timestamp>("\"01/01/2021\"").is_err());
+
+    // Parsed timestamps are serialized as they were received
+    let input: &str = "\"2021-01-01T02:30:00.250+02:30\"";
+    let timestamp: Timestamp = serde_json::from_str(input).unwrap();
+
+    assert_eq!(serde_json::to_string(&timestamp).unwrap(), input);
+  }
+}
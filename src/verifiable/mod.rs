@@ -1,7 +1,11 @@
+#![allow(clippy::module_inception)]
+
 mod traits;
+mod verifiable;
 mod verifiable_document;
 mod verifiable_properties;
 
 pub use self::traits::*;
+pub use self::verifiable::*;
 pub use self::verifiable_document::*;
 pub use self::verifiable_properties::*;
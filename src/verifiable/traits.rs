@@ -1,10 +1,13 @@
+use did_url::DID;
+
+use crate::document::Document;
 use crate::error::Error;
 use crate::error::Result;
 use crate::signature::Signature;
+use crate::utils::Object;
 use crate::verification::MethodQuery;
 use crate::verification::MethodWrap;
 
-const ERR_VMNF: &str = "Verification Method Not Found";
 const ERR_SNF: &str = "Signature Not Found";
 
 pub trait TrySignature {
@@ -15,7 +18,7 @@ pub trait TrySignature {
   }
 }
 
-impl<'a, T> TrySignature for &'a T
+impl<T> TrySignature for &T
 where
   T: TrySignature,
 {
@@ -24,7 +27,7 @@ where
   }
 }
 
-impl<'a, T> TrySignature for &'a mut T
+impl<T> TrySignature for &mut T
 where
   T: TrySignature,
 {
@@ -44,7 +47,7 @@ pub trait TrySignatureMut: TrySignature {
   }
 }
 
-impl<'a, T> TrySignatureMut for &'a mut T
+impl<T> TrySignatureMut for &mut T
 where
   T: TrySignatureMut,
 {
@@ -60,7 +63,7 @@ pub trait SetSignature: TrySignatureMut {
   fn set_signature(&mut self, signature: Signature);
 }
 
-impl<'a, T> SetSignature for &'a mut T
+impl<T> SetSignature for &mut T
 where
   T: SetSignature,
 {
@@ -76,17 +79,66 @@ pub trait ResolveMethod<M> {
   fn resolve_method(&self, query: MethodQuery<'_>) -> Option<MethodWrap<'_, M>>;
 
   fn try_resolve_method(&self, query: MethodQuery<'_>) -> Result<MethodWrap<'_, M>> {
-    self
-      .resolve_method(query)
-      .ok_or_else(|| Error::message(ERR_VMNF))
+    self.resolve_method(query).ok_or_else(|| query.not_found())
   }
 }
 
-impl<'a, T, M> ResolveMethod<M> for &'a T
+impl<T, M> ResolveMethod<M> for &T
 where
   T: ResolveMethod<M>,
 {
   fn resolve_method(&self, query: MethodQuery<'_>) -> Option<MethodWrap<'_, M>> {
     (**self).resolve_method(query)
   }
+
+  fn try_resolve_method(&self, query: MethodQuery<'_>) -> Result<MethodWrap<'_, M>> {
+    (**self).try_resolve_method(query)
+  }
+}
+
+// =============================================================================
+// =============================================================================
+
+/// A type that resolves DIDs to their DID Documents, e.g. to verify signatures
+/// made by the controller of a document.
+pub trait ResolveDocument<T = Object, U = Object, V = Object> {
+  fn resolve_document(&self, did: &DID) -> Option<&Document<T, U, V>>;
+
+  /// Resolves `did` to its DID Document.
+  ///
+  /// # Errors
+  ///
+  /// Fails with `Error::DocumentNotFound` by default; resolvers may override
+  /// this to report e.g. `Error::UnsupportedDIDMethod` or
+  /// `Error::ResolutionFailed`.
+  fn try_resolve_document(&self, did: &DID) -> Result<&Document<T, U, V>> {
+    self
+      .resolve_document(did)
+      .ok_or_else(|| Error::document_not_found(did))
+  }
+}
+
+impl<R, T, U, V> ResolveDocument<T, U, V> for &R
+where
+  R: ResolveDocument<T, U, V> + ?Sized,
+{
+  fn resolve_document(&self, did: &DID) -> Option<&Document<T, U, V>> {
+    (**self).resolve_document(did)
+  }
+
+  fn try_resolve_document(&self, did: &DID) -> Result<&Document<T, U, V>> {
+    (**self).try_resolve_document(did)
+  }
+}
+
+impl<T, U, V> ResolveDocument<T, U, V> for Document<T, U, V> {
+  fn resolve_document(&self, did: &DID) -> Option<&Document<T, U, V>> {
+    Some(self).filter(|document| document.id() == did)
+  }
+}
+
+impl<T, U, V> ResolveDocument<T, U, V> for [Document<T, U, V>] {
+  fn resolve_document(&self, did: &DID) -> Option<&Document<T, U, V>> {
+    self.iter().find(|document| document.id() == did)
+  }
 }
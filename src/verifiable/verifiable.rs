@@ -0,0 +1,249 @@
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Error as FmtError;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::ops::Deref;
+use core::ops::DerefMut;
+use serde::Serialize;
+use serde_json::to_string;
+use serde_json::to_string_pretty;
+
+use crate::service::Service;
+use crate::signature::Signature;
+use crate::utils::Object;
+use crate::verifiable::SetSignature;
+use crate::verifiable::TrySignature;
+use crate::verifiable::TrySignatureMut;
+use crate::verifiable::VerifiableProperties;
+use crate::verification::Method;
+
+/// A verifiable `Service`, e.g. a signed service announcement.
+pub type VerifiableService<T = Object> = Verifiable<Service<T>>;
+
+/// A verifiable verification `Method`, e.g. a signed key rotation statement.
+pub type VerifiableMethod<T = Object> = Verifiable<Method<T>>;
+
+/// A signable and verifiable wrapper around any `Serialize` payload.
+///
+/// The signature is serialized as a `proof` property next to the properties of
+/// the payload, as with `VerifiableProperties`; the payload must therefore
+/// serialize as a JSON object.
+///
+/// Methods are resolved with `LdSuite::verify_data`, typically from the DID
+/// Document of the signer.
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+#[repr(transparent)]
+#[serde(transparent)]
+pub struct Verifiable<T> {
+  data: VerifiableProperties<T>,
+}
+
+impl<T> Verifiable<T> {
+  /// Creates a new, unsigned `Verifiable` payload.
+  pub const fn new(data: T) -> Self {
+    Self {
+      data: VerifiableProperties::new(data),
+    }
+  }
+
+  /// Creates a new `Verifiable` payload with the given `proof`.
+  pub const fn with_proof(data: T, proof: Signature) -> Self {
+    Self {
+      data: VerifiableProperties::with_proof(data, proof),
+    }
+  }
+
+  /// Returns a reference to the `proof` of the payload, if signed.
+  pub fn proof(&self) -> Option<&Signature> {
+    self.data.proof()
+  }
+
+  /// Returns a mutable reference to the `proof` of the payload, if signed.
+  pub fn proof_mut(&mut self) -> Option<&mut Signature> {
+    self.data.proof_mut()
+  }
+
+  /// Sets the `proof` of the payload, replacing any existing proof.
+  pub fn set_proof(&mut self, signature: Signature) {
+    self.data.proof = Some(signature);
+  }
+
+  /// Consumes the `Verifiable` and returns the payload, dropping the proof.
+  pub fn into_inner(self) -> T {
+    self.data.properties
+  }
+}
+
+impl<T> Deref for Verifiable<T> {
+  type Target = T;
+
+  fn deref(&self) -> &Self::Target {
+    &self.data
+  }
+}
+
+impl<T> DerefMut for Verifiable<T> {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.data
+  }
+}
+
+impl<T> Debug for Verifiable<T>
+where
+  T: Debug,
+{
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    Debug::fmt(&self.data, f)
+  }
+}
+
+impl<T> Display for Verifiable<T>
+where
+  T: Serialize,
+{
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    if f.alternate() {
+      f.write_str(&to_string_pretty(self).map_err(|_| FmtError)?)
+    } else {
+      f.write_str(&to_string(self).map_err(|_| FmtError)?)
+    }
+  }
+}
+
+impl<T> From<T> for Verifiable<T> {
+  fn from(other: T) -> Self {
+    Self::new(other)
+  }
+}
+
+impl<T> TrySignature for Verifiable<T> {
+  fn signature(&self) -> Option<&Signature> {
+    self.proof()
+  }
+}
+
+impl<T> TrySignatureMut for Verifiable<T> {
+  fn signature_mut(&mut self) -> Option<&mut Signature> {
+    self.proof_mut()
+  }
+}
+
+impl<T> SetSignature for Verifiable<T> {
+  fn set_signature(&mut self, signature: Signature) {
+    self.set_proof(signature)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::error::Result;
+  use crate::lib::*;
+  use crate::signature::ld_suite::tests::document;
+  use crate::signature::ld_suite::tests::policy;
+  use crate::signature::ld_suite::tests::TestSuite;
+  use crate::signature::LdSuite;
+  use crate::signature::SignatureOptions;
+  use crate::verifiable::VerifiableDocument;
+  use crate::verification::MethodBuilder;
+  use crate::verification::MethodData;
+  use crate::verification::MethodType;
+
+  fn sign<T>(payload: &mut Verifiable<T>)
+  where
+    T: Serialize,
+  {
+    let mut options: SignatureOptions =
+      SignatureOptions::with_purpose("#key-1".into(), "assertionMethod".into());
+
+    options.created = Some("2021-01-01T00:00:00Z".parse().unwrap());
+
+    LdSuite::new(TestSuite)
+      .sign(payload, options, b"secret")
+      .unwrap();
+  }
+
+  fn verify<T>(payload: &Verifiable<T>) -> Result<()>
+  where
+    T: Serialize,
+  {
+    let document: VerifiableDocument = document();
+
+    LdSuite::new(TestSuite).verify_data(payload, &document, &policy())
+  }
+
+  #[test]
+  fn test_service() {
+    let mut service: VerifiableService = Service::builder(Object::new())
+      .id("did:example:123#service".parse().unwrap())
+      .type_("LinkedDomains")
+      .service_endpoint("https://example.com".parse().unwrap())
+      .build()
+      .unwrap()
+      .into();
+
+    assert!(verify(&service).is_err());
+
+    sign(&mut service);
+
+    assert!(verify(&service).is_ok());
+
+    // The proof is flattened into the service
+    let value: serde_json::Value = serde_json::to_value(&service).unwrap();
+
+    assert_eq!(value["serviceEndpoint"], "https://example.com/");
+    assert_eq!(value["proof"]["type"], "TestSuite");
+
+    let service: VerifiableService = serde_json::from_value(value).unwrap();
+
+    assert!(verify(&service).is_ok());
+  }
+
+  #[test]
+  fn test_method() {
+    let mut method: VerifiableMethod = Verifiable::new(
+      MethodBuilder::default()
+        .id("did:example:123#key-2".parse().unwrap())
+        .controller("did:example:123".parse().unwrap())
+        .key_type(MethodType::Ed25519VerificationKey2018)
+        .key_data(MethodData::new_b58(b"rotated"))
+        .build()
+        .unwrap(),
+    );
+
+    sign(&mut method);
+
+    assert!(verify(&method).is_ok());
+
+    *method.key_data_mut() = MethodData::new_b58(b"tampered");
+
+    assert!(verify(&method).is_err());
+  }
+
+  #[test]
+  fn test_payload() {
+    let mut payload: Verifiable<Object> = Verifiable::new(Object::new());
+
+    payload.insert(
+      "statement".into(),
+      "did:example:123 controls example.com".into(),
+    );
+
+    sign(&mut payload);
+
+    assert!(verify(&payload).is_ok());
+    assert_eq!(
+      payload.to_string(),
+      alloc::format!(
+        r#"{{"statement":"did:example:123 controls example.com","proof":{}}}"#,
+        serde_json::to_string(payload.proof().unwrap()).unwrap()
+      )
+    );
+
+    payload.insert("statement".into(), "tampered".into());
+
+    assert!(verify(&payload).is_err());
+    assert!(payload.into_inner().contains_key("statement"));
+  }
+}
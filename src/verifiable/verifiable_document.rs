@@ -7,6 +7,7 @@ use core::ops::DerefMut;
 use serde::Serialize;
 
 use crate::document::Document;
+use crate::error::Result;
 use crate::signature::Signature;
 use crate::utils::Object;
 use crate::verifiable::ResolveMethod;
@@ -108,4 +109,8 @@ impl<T, U, V> ResolveMethod<U> for VerifiableDocument<T, U, V> {
   fn resolve_method(&self, query: MethodQuery<'_>) -> Option<MethodWrap<'_, U>> {
     self.document.resolve(query)
   }
+
+  fn try_resolve_method(&self, query: MethodQuery<'_>) -> Result<MethodWrap<'_, U>> {
+    self.document.try_resolve(query)
+  }
 }
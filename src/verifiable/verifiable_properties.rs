@@ -3,6 +3,9 @@ use core::ops::DerefMut;
 
 use crate::signature::Signature;
 use crate::utils::Object;
+use crate::verifiable::SetSignature;
+use crate::verifiable::TrySignature;
+use crate::verifiable::TrySignatureMut;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
 pub struct VerifiableProperties<T = Object> {
@@ -50,3 +53,21 @@ impl<T> DerefMut for VerifiableProperties<T> {
     &mut self.properties
   }
 }
+
+impl<T> TrySignature for VerifiableProperties<T> {
+  fn signature(&self) -> Option<&Signature> {
+    self.proof()
+  }
+}
+
+impl<T> TrySignatureMut for VerifiableProperties<T> {
+  fn signature_mut(&mut self) -> Option<&mut Signature> {
+    self.proof_mut()
+  }
+}
+
+impl<T> SetSignature for VerifiableProperties<T> {
+  fn set_signature(&mut self, signature: Signature) {
+    self.proof = Some(signature);
+  }
+}
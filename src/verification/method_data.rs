@@ -1,11 +1,33 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+
 use crate::error::Error;
 use crate::error::Result;
 use crate::lib::*;
+use crate::utils::decode_multibase;
+use crate::utils::decode_multicodec;
+use crate::utils::encode_multicodec;
 use crate::utils::Object;
+use crate::utils::Value;
+use crate::verification::MethodType;
 
 const ERR_IKD: &str = "Invalid Key Data";
+const ERR_UJWK: &str = "Unsupported JWK Key Type";
 const ERR_IB16: &str = "Invalid Base16 Key Data";
 const ERR_IB58: &str = "Invalid Base58 Key Data";
+const ERR_IBA: &str = "Invalid Blockchain Account Id";
+const ERR_UBA: &str = "Unsupported Blockchain Account Id";
+const ERR_IEA: &str = "Invalid Ethereum Address";
+const ERR_UMC: &str = "Unexpected Multicodec";
+
+const CODEC_ED25519: u64 = 0xED;
+const CODEC_SECP256K1: u64 = 0xE7;
+const CODEC_BLS12381_G2: u64 = 0xEB;
+const CODEC_P256: u64 = 0x1200;
+#[cfg(feature = "pq")]
+const CODEC_ML_DSA_65: u64 = 0x1211;
+#[cfg(feature = "pq")]
+const CODEC_SLH_DSA_SHA2_128S: u64 = 0x1220;
 
 /// Supported verification method data formats.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -15,6 +37,9 @@ pub enum MethodData {
   PublicKeyBase58(String),
   PublicKeyHex(String),
   PublicKeyJwk(Object),
+  PublicKeyMultibase(String),
+  BlockchainAccountId(String),
+  EthereumAddress(String),
 }
 
 impl MethodData {
@@ -28,6 +53,17 @@ impl MethodData {
     Self::PublicKeyBase58(bs58::encode(data.as_ref()).into_string())
   }
 
+  /// Creates a new `MethodData` variant with base58btc-encoded,
+  /// multicodec-prefixed content.
+  pub fn new_multibase(codec: u64, data: impl AsRef<[u8]>) -> Self {
+    let data: Vec<u8> = encode_multicodec(codec, data.as_ref());
+    let mut output: String = String::from("z");
+
+    output.push_str(&bs58::encode(data).into_string());
+
+    Self::PublicKeyMultibase(output)
+  }
+
   /// Returns a `Vec<u8>` containing the decoded bytes of the `MethodData`.
   ///
   /// This is generally a public key identified by a `MethodType` value.
@@ -36,11 +72,63 @@ impl MethodData {
   ///
   /// Decoding can fail if `MethodData` has invalid content or cannot be
   /// represented as a vector of bytes.
+  ///
+  /// Note: Elliptic curve JWKs are decoded as uncompressed SEC1 points.
+  ///
+  /// Note: Multibase content is decoded without the multicodec prefix, which
+  /// is not checked; see [`MethodData::try_decode_key`].
+  ///
+  /// Note: `BlockchainAccountId` and `EthereumAddress` do not contain a public
+  /// key and are decoded as 20-byte account addresses; see
+  /// [`MethodData::is_address`].
   pub fn try_decode(&self) -> Result<Vec<u8>> {
     match self {
       Self::PublicKeyBase58(input) => decode_b58(input),
       Self::PublicKeyHex(input) => decode_hex(input),
-      Self::PublicKeyJwk(_) => Err(Error::message(ERR_IKD)),
+      Self::PublicKeyJwk(input) => decode_jwk(input),
+      Self::PublicKeyMultibase(input) => decode_mb(input, None),
+      Self::BlockchainAccountId(input) => decode_account(input),
+      Self::EthereumAddress(input) => decode_address(input),
+    }
+  }
+
+  /// Returns a `Vec<u8>` containing the decoded public key of a method of the
+  /// given `MethodType`.
+  ///
+  /// # Errors
+  ///
+  /// Fails for the same reasons as [`MethodData::try_decode`], or if
+  /// multibase content is prefixed with a multicodec that does not identify a
+  /// public key of `key_type`.
+  pub fn try_decode_key(&self, key_type: MethodType) -> Result<Vec<u8>> {
+    match self {
+      Self::PublicKeyMultibase(input) => decode_mb(input, Some(codecs(key_type))),
+      _ => self.try_decode(),
+    }
+  }
+
+  /// Returns `true` if the `MethodData` identifies an account address rather
+  /// than a public key.
+  ///
+  /// Signatures of address-based methods can only be verified by recovering
+  /// the public key from the signature and comparing the derived address.
+  pub const fn is_address(&self) -> bool {
+    matches!(
+      self,
+      Self::BlockchainAccountId(_) | Self::EthereumAddress(_)
+    )
+  }
+
+  /// Returns the DID Core property name of the `MethodData` encoding, e.g.
+  /// `"publicKeyBase58"`.
+  pub const fn name(&self) -> &'static str {
+    match self {
+      Self::PublicKeyBase58(_) => "publicKeyBase58",
+      Self::PublicKeyHex(_) => "publicKeyHex",
+      Self::PublicKeyJwk(_) => "publicKeyJwk",
+      Self::PublicKeyMultibase(_) => "publicKeyMultibase",
+      Self::BlockchainAccountId(_) => "blockchainAccountId",
+      Self::EthereumAddress(_) => "ethereumAddress",
     }
   }
 }
@@ -54,3 +142,221 @@ fn decode_b58(input: &str) -> Result<Vec<u8>> {
     .into_vec()
     .map_err(|_| Error::message(ERR_IB58))
 }
+
+fn decode_mb(input: &str, codecs: Option<&[u64]>) -> Result<Vec<u8>> {
+  let data: Vec<u8> = decode_multibase(input)?;
+  let (codec, data): (u64, &[u8]) = decode_multicodec(&data)?;
+
+  match codecs {
+    Some(codecs) if !codecs.contains(&codec) => Err(Error::message(ERR_UMC)),
+    _ => Ok(data.to_vec()),
+  }
+}
+
+// The multicodec identifiers of public keys for the given method type.
+const fn codecs(key_type: MethodType) -> &'static [u64] {
+  match key_type {
+    MethodType::JcsEd25519Key2020 | MethodType::Ed25519VerificationKey2018 => &[CODEC_ED25519],
+    MethodType::EcdsaSecp256k1VerificationKey2019
+    | MethodType::EcdsaSecp256k1RecoveryMethod2020 => &[CODEC_SECP256K1],
+    // JSON Web Keys are not tied to a single curve
+    MethodType::JsonWebKey2020 | MethodType::JwsVerificationKey2020 => {
+      &[CODEC_ED25519, CODEC_SECP256K1, CODEC_P256]
+    }
+    MethodType::Bls12381G2Key2020 => &[CODEC_BLS12381_G2],
+    #[cfg(feature = "pq")]
+    MethodType::MlDsa65VerificationKey2024 => &[CODEC_ML_DSA_65],
+    #[cfg(feature = "pq")]
+    MethodType::SlhDsaSha2128sVerificationKey2024 => &[CODEC_SLH_DSA_SHA2_128S],
+    _ => &[],
+  }
+}
+
+fn decode_jwk(input: &Object) -> Result<Vec<u8>> {
+  let param = |name: &str| -> Result<&str> {
+    match input.get(name) {
+      Some(Value::String(value)) => Ok(value),
+      _ => Err(Error::message(ERR_IKD)),
+    }
+  };
+
+  let coordinate = |name: &str| -> Result<Vec<u8>> {
+    match URL_SAFE_NO_PAD.decode(param(name)?) {
+      Ok(data) if data.len() == 32 => Ok(data),
+      _ => Err(Error::message(ERR_IKD)),
+    }
+  };
+
+  // Algorithm Key Pairs (e.g. ML-DSA) have a single public key parameter
+  if param("kty")? == "AKP" {
+    return URL_SAFE_NO_PAD
+      .decode(param("pub")?)
+      .map_err(|_| Error::message(ERR_IKD));
+  }
+
+  match (param("kty")?, param("crv")?) {
+    ("EC", "secp256k1") | ("EC", "P-256") => {
+      let mut output: Vec<u8> = Vec::with_capacity(65);
+
+      output.push(0x04);
+      output.extend(coordinate("x")?);
+      output.extend(coordinate("y")?);
+
+      Ok(output)
+    }
+    ("OKP", "Ed25519") => coordinate("x"),
+    _ => Err(Error::message(ERR_UJWK)),
+  }
+}
+
+// Decodes a CAIP-10 account id (`<namespace>:<reference>:<address>`); only
+// `eip155` (Ethereum) accounts are supported.
+fn decode_account(input: &str) -> Result<Vec<u8>> {
+  let mut split: core::str::Split<'_, char> = input.split(':');
+
+  let (namespace, reference, address): (&str, &str, &str) =
+    match (split.next(), split.next(), split.next(), split.next()) {
+      (Some(namespace), Some(reference), Some(address), None) => (namespace, reference, address),
+      _ => return Err(Error::message(ERR_IBA)),
+    };
+
+  let valid_namespace: bool = (3..=8).contains(&namespace.len())
+    && namespace
+      .bytes()
+      .all(|byte| byte == b'-' || byte.is_ascii_lowercase() || byte.is_ascii_digit());
+
+  let valid_reference: bool = (1..=32).contains(&reference.len())
+    && reference
+      .bytes()
+      .all(|byte| byte == b'-' || byte == b'_' || byte.is_ascii_alphanumeric());
+
+  if !valid_namespace || !valid_reference {
+    return Err(Error::message(ERR_IBA));
+  }
+
+  if namespace != "eip155" {
+    return Err(Error::message(ERR_UBA));
+  }
+
+  decode_address(address)
+}
+
+// Decodes a `0x`-prefixed, 20-byte Ethereum address. Mixed-case (EIP-55)
+// checksums are not validated.
+fn decode_address(input: &str) -> Result<Vec<u8>> {
+  match input.strip_prefix("0x").map(hex::decode) {
+    Some(Ok(data)) if data.len() == 20 => Ok(data),
+    _ => Err(Error::message(ERR_IEA)),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const ADDRESS: &str = "0xab16a96d359ec26a11e2c2b3d8f8b8942d5bfcdb";
+
+  #[test]
+  fn test_decode_address() {
+    let expected: Vec<u8> = hex::decode(&ADDRESS[2..]).unwrap();
+    let address: MethodData = MethodData::EthereumAddress(ADDRESS.into());
+    let account: MethodData =
+      MethodData::BlockchainAccountId(alloc::format!("eip155:1:{}", ADDRESS));
+
+    assert!(address.is_address());
+    assert!(account.is_address());
+    assert!(!MethodData::new_b16([0; 20]).is_address());
+    assert_eq!(address.try_decode().unwrap(), expected);
+    assert_eq!(account.try_decode().unwrap(), expected);
+  }
+
+  #[test]
+  fn test_decode_invalid_address() {
+    let decode = |data: MethodData| data.try_decode();
+
+    assert!(decode(MethodData::EthereumAddress(ADDRESS[2..].into())).is_err());
+    assert!(decode(MethodData::EthereumAddress("0xab16".into())).is_err());
+    assert!(decode(MethodData::BlockchainAccountId(ADDRESS.into())).is_err());
+    assert!(decode(MethodData::BlockchainAccountId(alloc::format!(
+      "EIP155:1:{}",
+      ADDRESS
+    )))
+    .is_err());
+    assert!(decode(MethodData::BlockchainAccountId(alloc::format!(
+      "bip122:000000000019d6689c085ae165831e93:{}",
+      ADDRESS
+    )))
+    .is_err());
+  }
+
+  #[test]
+  fn test_decode_multibase() {
+    let data: Vec<u8> = alloc::vec![0xAB; 1952];
+    let multibase: MethodData = MethodData::new_multibase(0x1211, &data);
+
+    assert!(
+      matches!(multibase, MethodData::PublicKeyMultibase(ref input) if input.starts_with('z'))
+    );
+    assert_eq!(multibase.try_decode().unwrap(), data);
+
+    let json: String = serde_json::to_string(&multibase).unwrap();
+
+    assert!(json.starts_with(r#"{"publicKeyMultibase":"z"#));
+    assert!(MethodData::PublicKeyMultibase("xyz".into())
+      .try_decode()
+      .is_err());
+  }
+
+  #[test]
+  fn test_decode_multibase_codec() {
+    let data: Vec<u8> = alloc::vec![0xAB; 32];
+    let ed25519: MethodData = MethodData::new_multibase(CODEC_ED25519, &data);
+    let secp256k1: MethodData = MethodData::new_multibase(CODEC_SECP256K1, &data);
+
+    assert_eq!(
+      ed25519
+        .try_decode_key(MethodType::Ed25519VerificationKey2018)
+        .unwrap(),
+      data
+    );
+    assert_eq!(
+      ed25519.try_decode_key(MethodType::JsonWebKey2020).unwrap(),
+      data
+    );
+    assert!(secp256k1
+      .try_decode_key(MethodType::Ed25519VerificationKey2018)
+      .is_err());
+    assert!(ed25519
+      .try_decode_key(MethodType::MerkleKeyCollection2021)
+      .is_err());
+
+    // Other encodings carry no multicodec
+    assert_eq!(
+      MethodData::new_b58(&data)
+        .try_decode_key(MethodType::Ed25519VerificationKey2018)
+        .unwrap(),
+      data
+    );
+  }
+
+  #[test]
+  fn test_decode_akp_jwk() {
+    let jwk: Object =
+      serde_json::from_str(r#"{"kty":"AKP","alg":"ML-DSA-65","pub":"AQID"}"#).unwrap();
+
+    assert_eq!(
+      MethodData::PublicKeyJwk(jwk).try_decode().unwrap(),
+      [1, 2, 3]
+    );
+  }
+
+  #[test]
+  fn test_serde_address() {
+    let json: &str =
+      r#"{"blockchainAccountId":"eip155:1:0xab16a96d359ec26a11e2c2b3d8f8b8942d5bfcdb"}"#;
+    let data: MethodData = serde_json::from_str(json).unwrap();
+
+    assert!(matches!(data, MethodData::BlockchainAccountId(_)));
+    assert_eq!(serde_json::to_string(&data).unwrap(), json);
+  }
+}
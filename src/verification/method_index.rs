@@ -1,33 +1,55 @@
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 use did_url::DID;
 
+const PREFIX: &str = "did:";
+
 /// Index or identifier used to identify the target verification method of a
 /// `MethodQuery`.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum MethodIndex<'a> {
   Index(usize),
   Ident(&'a str),
+  /// Matches any method; see `MethodQuery::any`.
+  Any,
 }
 
 impl<'a> MethodIndex<'a> {
   /// Returns a `bool` indicating if the given `DID` matches the identifier.
+  ///
+  /// Identifiers starting with `did:` must match the full DID URL; other
+  /// identifiers match the fragment.
   pub fn matches(&self, did: &DID) -> bool {
     match self {
       Self::Index(_) => false,
-      Self::Ident(ident) if ident.starts_with(DID::SCHEME) && !ident.ends_with('#') => {
-        ident.rfind('#').map_or(false, |index| {
-          Self::matches_fragment(did, &ident[index + 1..])
-        })
-      }
+      Self::Any => true,
+      Self::Ident(ident) if Self::is_did_url(ident) => did.as_str() == *ident,
       Self::Ident(ident) if ident.starts_with('#') => Self::matches_fragment(did, &ident[1..]),
-      Self::Ident(ident) => Self::matches_fragment(did, *ident),
+      Self::Ident(ident) => Self::matches_fragment(did, ident),
     }
   }
 
+  /// Returns `true` if `ident` is a DID URL rather than a (relative) fragment.
+  pub(crate) fn is_did_url(ident: &str) -> bool {
+    ident.starts_with(PREFIX)
+  }
+
   fn matches_fragment(did: &DID, ident: &str) -> bool {
     matches!(did.fragment(), Some(fragment) if fragment == ident)
   }
 }
 
+impl Display for MethodIndex<'_> {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    match self {
+      Self::Index(index) => Display::fmt(index, f),
+      Self::Ident(ident) => f.write_str(ident),
+      Self::Any => f.write_str("*"),
+    }
+  }
+}
+
 impl<'a> From<&'a str> for MethodIndex<'a> {
   fn from(other: &'a str) -> Self {
     Self::Ident(other)
@@ -1,13 +1,25 @@
+use did_url::DID;
+
+use crate::error::Error;
+use crate::verification::Method;
 use crate::verification::MethodIndex;
 use crate::verification::MethodScope;
+use crate::verification::MethodType;
 
 /// Specifies the  conditions of a DID document method resolution query.
 ///
-/// See `Document::resolve`.
+/// A query identifies methods by index or identifier within a `MethodScope`,
+/// and can further be restricted to a `MethodType`, a controller, and a set of
+/// accepted `MethodData` encodings.
+///
+/// See `Document::resolve` and `Document::methods`.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct MethodQuery<'a> {
   pub(crate) ident: MethodIndex<'a>,
-  pub(crate) scope: MethodScope,
+  pub(crate) scope: Option<MethodScope>,
+  pub(crate) key_type: Option<MethodType>,
+  pub(crate) controller: Option<&'a DID>,
+  pub(crate) encodings: Option<&'a [&'a str]>,
 }
 
 impl<'a> MethodQuery<'a> {
@@ -26,9 +38,70 @@ impl<'a> MethodQuery<'a> {
   {
     Self {
       ident: ident.into(),
-      scope,
+      scope: Some(scope),
+      key_type: None,
+      controller: None,
+      encodings: None,
     }
   }
+
+  /// Creates a new `MethodQuery` matching all methods in all scopes.
+  pub const fn any() -> Self {
+    Self {
+      ident: MethodIndex::Any,
+      scope: None,
+      key_type: None,
+      controller: None,
+      encodings: None,
+    }
+  }
+
+  /// Restricts the query to methods in the given `MethodScope`.
+  #[must_use]
+  pub const fn scope(mut self, value: MethodScope) -> Self {
+    self.scope = Some(value);
+    self
+  }
+
+  /// Restricts the query to methods of the given `MethodType`.
+  #[must_use]
+  pub const fn key_type(mut self, value: MethodType) -> Self {
+    self.key_type = Some(value);
+    self
+  }
+
+  /// Restricts the query to methods with the given controller.
+  #[must_use]
+  pub const fn controller(mut self, value: &'a DID) -> Self {
+    self.controller = Some(value);
+    self
+  }
+
+  /// Restricts the query to methods with key data in one of the given
+  /// encodings, e.g. `"publicKeyMultibase"`.
+  ///
+  /// See `MethodData::name`.
+  #[must_use]
+  pub const fn encodings(mut self, value: &'a [&'a str]) -> Self {
+    self.encodings = Some(value);
+    self
+  }
+
+  /// Returns `true` if the given `Method` matches the type, controller, and
+  /// encoding conditions of the query.
+  pub fn matches<T>(&self, method: &Method<T>) -> bool {
+    self.key_type.is_none_or(|value| method.key_type() == value)
+      && self
+        .controller
+        .is_none_or(|value| method.controller() == value)
+      && self
+        .encodings
+        .is_none_or(|value| value.contains(&method.key_data().name()))
+  }
+
+  pub(crate) fn not_found(&self) -> Error {
+    Error::method_not_found(self.ident, self.scope.unwrap_or_default())
+  }
 }
 
 impl<'a> From<&'a str> for MethodQuery<'a> {
@@ -50,6 +50,7 @@ impl<T> MethodRef<T> {
   /// # Errors
   ///
   /// Fails if `MethodRef` is not an embedded method.
+  #[allow(clippy::result_large_err)]
   pub fn try_into_embedded(self) -> Result<Method<T>, Self> {
     match self {
       Self::Embed(inner) => Ok(inner),
@@ -64,6 +65,7 @@ impl<T> MethodRef<T> {
   /// # Errors
   ///
   /// Fails if `MethodRef` is not an referenced method.
+  #[allow(clippy::result_large_err)]
   pub fn try_into_referenced(self) -> Result<DID, Self> {
     match self {
       Self::Embed(_) => Err(self),
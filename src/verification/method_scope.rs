@@ -5,8 +5,12 @@ use crate::error::Error;
 const ERR_UMS: &str = "Unknown Method Scope";
 
 /// Verification method group used to refine the scope of a method query.
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(
+  Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
 pub enum MethodScope {
+  #[default]
   VerificationMethod,
   Authentication,
   AssertionMethod,
@@ -16,21 +20,22 @@ pub enum MethodScope {
 }
 
 impl MethodScope {
+  /// Returns the DID Core property name of the scope.
   pub const fn as_str(&self) -> &'static str {
     match self {
-      Self::VerificationMethod => "VerificationMethod",
-      Self::Authentication => "Authentication",
-      Self::AssertionMethod => "AssertionMethod",
-      Self::KeyAgreement => "KeyAgreement",
-      Self::CapabilityDelegation => "CapabilityDelegation",
-      Self::CapabilityInvocation => "CapabilityInvocation",
+      Self::VerificationMethod => "verificationMethod",
+      Self::Authentication => "authentication",
+      Self::AssertionMethod => "assertionMethod",
+      Self::KeyAgreement => "keyAgreement",
+      Self::CapabilityDelegation => "capabilityDelegation",
+      Self::CapabilityInvocation => "capabilityInvocation",
     }
   }
-}
 
-impl Default for MethodScope {
-  fn default() -> Self {
-    Self::VerificationMethod
+  /// Returns `true` if the scope is a verification relationship, and can be
+  /// used as a proof purpose.
+  pub const fn is_relationship(&self) -> bool {
+    !matches!(self, Self::VerificationMethod)
   }
 }
 
@@ -39,12 +44,12 @@ impl FromStr for MethodScope {
 
   fn from_str(string: &str) -> Result<Self, Self::Err> {
     match string {
-      "VerificationMethod" => Ok(Self::VerificationMethod),
-      "Authentication" => Ok(Self::Authentication),
-      "AssertionMethod" => Ok(Self::AssertionMethod),
-      "KeyAgreement" => Ok(Self::KeyAgreement),
-      "CapabilityDelegation" => Ok(Self::CapabilityDelegation),
-      "CapabilityInvocation" => Ok(Self::CapabilityInvocation),
+      "verificationMethod" => Ok(Self::VerificationMethod),
+      "authentication" => Ok(Self::Authentication),
+      "assertionMethod" => Ok(Self::AssertionMethod),
+      "keyAgreement" => Ok(Self::KeyAgreement),
+      "capabilityDelegation" => Ok(Self::CapabilityDelegation),
+      "capabilityInvocation" => Ok(Self::CapabilityInvocation),
       _ => Err(Error::message(ERR_UMS)),
     }
   }
@@ -11,8 +11,20 @@ const ERR_UMT: &str = "Unknown Method Type";
 pub enum MethodType {
   JcsEd25519Key2020,
   JwsVerificationKey2020,
+  JsonWebKey2020,
   Ed25519VerificationKey2018,
+  EcdsaSecp256k1VerificationKey2019,
+  EcdsaSecp256k1RecoveryMethod2020,
+  Bls12381G2Key2020,
   MerkleKeyCollection2021,
+  /// An ML-DSA-65 public key; this type is not registered in the DID
+  /// Specification Registries and may change.
+  #[cfg(feature = "pq")]
+  MlDsa65VerificationKey2024,
+  /// An SLH-DSA-SHA2-128s public key; this type is not registered in the DID
+  /// Specification Registries and may change.
+  #[cfg(feature = "pq")]
+  SlhDsaSha2128sVerificationKey2024,
 }
 
 impl MethodType {
@@ -20,8 +32,16 @@ impl MethodType {
     match self {
       Self::JcsEd25519Key2020 => "JcsEd25519Key2020",
       Self::JwsVerificationKey2020 => "JwsVerificationKey2020",
+      Self::JsonWebKey2020 => "JsonWebKey2020",
       Self::Ed25519VerificationKey2018 => "Ed25519VerificationKey2018",
+      Self::EcdsaSecp256k1VerificationKey2019 => "EcdsaSecp256k1VerificationKey2019",
+      Self::EcdsaSecp256k1RecoveryMethod2020 => "EcdsaSecp256k1RecoveryMethod2020",
+      Self::Bls12381G2Key2020 => "Bls12381G2Key2020",
       Self::MerkleKeyCollection2021 => "MerkleKeyCollection2021",
+      #[cfg(feature = "pq")]
+      Self::MlDsa65VerificationKey2024 => "MlDsa65VerificationKey2024",
+      #[cfg(feature = "pq")]
+      Self::SlhDsaSha2128sVerificationKey2024 => "SlhDsaSha2128sVerificationKey2024",
     }
   }
 }
@@ -33,8 +53,16 @@ impl FromStr for MethodType {
     match string {
       "JcsEd25519Key2020" => Ok(Self::JcsEd25519Key2020),
       "JwsVerificationKey2020" => Ok(Self::JwsVerificationKey2020),
+      "JsonWebKey2020" => Ok(Self::JsonWebKey2020),
       "Ed25519VerificationKey2018" => Ok(Self::Ed25519VerificationKey2018),
+      "EcdsaSecp256k1VerificationKey2019" => Ok(Self::EcdsaSecp256k1VerificationKey2019),
+      "EcdsaSecp256k1RecoveryMethod2020" => Ok(Self::EcdsaSecp256k1RecoveryMethod2020),
+      "Bls12381G2Key2020" => Ok(Self::Bls12381G2Key2020),
       "MerkleKeyCollection2021" => Ok(Self::MerkleKeyCollection2021),
+      #[cfg(feature = "pq")]
+      "MlDsa65VerificationKey2024" => Ok(Self::MlDsa65VerificationKey2024),
+      #[cfg(feature = "pq")]
+      "SlhDsaSha2128sVerificationKey2024" => Ok(Self::SlhDsaSha2128sVerificationKey2024),
       _ => Err(Error::message(ERR_UMT)),
     }
   }
@@ -7,6 +7,7 @@ mod method_ref;
 mod method_scope;
 mod method_type;
 mod method_wrap;
+mod scoped_method;
 
 pub use self::method::*;
 pub use self::method_builder::*;
@@ -17,3 +18,4 @@ pub use self::method_ref::*;
 pub use self::method_scope::*;
 pub use self::method_type::*;
 pub use self::method_wrap::*;
+pub use self::scoped_method::*;
@@ -0,0 +1,54 @@
+use core::ops::Deref;
+
+use crate::lib::*;
+use crate::utils::Object;
+use crate::verification::Method;
+use crate::verification::MethodScope;
+
+/// A verification `Method` with the scopes that authorize it.
+///
+/// See `Document::scoped_methods`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScopedMethod<'a, T = Object> {
+  pub(crate) method: &'a Method<T>,
+  pub(crate) scopes: Vec<MethodScope>,
+}
+
+impl<'a, T> ScopedMethod<'a, T> {
+  /// Creates a new `ScopedMethod` without scopes.
+  pub(crate) const fn new(method: &'a Method<T>) -> Self {
+    Self {
+      method,
+      scopes: Vec::new(),
+    }
+  }
+
+  /// Returns the scopes of the method, in `Document` order.
+  pub fn scopes(&self) -> &[MethodScope] {
+    &self.scopes
+  }
+
+  /// Returns `true` if the method is authorized for the given `MethodScope`.
+  pub fn authorizes(&self, scope: MethodScope) -> bool {
+    self.scopes.contains(&scope)
+  }
+
+  /// Consumes the `ScopedMethod` and returns a reference to the `Method`.
+  pub fn into_method(self) -> &'a Method<T> {
+    self.method
+  }
+
+  pub(crate) fn insert(&mut self, scope: MethodScope) {
+    if !self.authorizes(scope) {
+      self.scopes.push(scope);
+    }
+  }
+}
+
+impl<T> Deref for ScopedMethod<'_, T> {
+  type Target = Method<T>;
+
+  fn deref(&self) -> &Self::Target {
+    self.method
+  }
+}